@@ -68,6 +68,24 @@ fn main() {
             #[used]
             static _F7: unsafe extern "C" fn() = _rjem_je_zone_register;
         }
+
+        // Per-pass allocation volume for `-Z time-passes-format=json-tree`.
+        // This is only available if jemalloc was built with statistics.
+        fn thread_allocated_bytes() -> Option<u64> {
+            let mut allocated: u64 = 0;
+            let mut len = std::mem::size_of::<u64>();
+            let ret = unsafe {
+                jemalloc_sys::mallctl(
+                    b"thread.allocated\0".as_ptr().cast(),
+                    (&mut allocated as *mut u64).cast(),
+                    &mut len,
+                    std::ptr::null_mut(),
+                    0,
+                )
+            };
+            (ret == 0).then_some(allocated)
+        }
+        rustc_driver::set_thread_allocated_bytes_fn(thread_allocated_bytes);
     }
 
     rustc_driver::main()
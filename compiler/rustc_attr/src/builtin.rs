@@ -5,7 +5,7 @@
 use rustc_ast_pretty::pprust;
 use rustc_feature::{find_gated_cfg, is_builtin_attr_name, Features, GatedCfg};
 use rustc_macros::HashStable_Generic;
-use rustc_session::config::ExpectedValues;
+use rustc_session::config::{ExpectedValues, OptLevel};
 use rustc_session::lint::builtin::UNEXPECTED_CFGS;
 use rustc_session::lint::BuiltinLintDiagnostics;
 use rustc_session::parse::{feature_err, ParseSess};
@@ -98,6 +98,51 @@ pub enum OptimizeAttr {
     None,
     Speed,
     Size,
+    /// `#[optimize(level = "...")]`
+    Level(OptimizeLevel),
+}
+
+/// An optimization level requested with `#[optimize(level = "...")]`, mirroring `-Copt-level`.
+#[derive(Copy, Clone, Encodable, Decodable, Debug, PartialEq, Eq, HashStable_Generic)]
+pub enum OptimizeLevel {
+    /// `level = "0"`
+    No,
+    /// `level = "1"`
+    Less,
+    /// `level = "2"`
+    Default,
+    /// `level = "3"`
+    Aggressive,
+    /// `level = "s"`
+    Size,
+    /// `level = "z"`
+    SizeMin,
+}
+
+impl OptimizeLevel {
+    pub fn from_str(s: &str) -> Option<Self> {
+        Some(match s {
+            "0" => OptimizeLevel::No,
+            "1" => OptimizeLevel::Less,
+            "2" => OptimizeLevel::Default,
+            "3" => OptimizeLevel::Aggressive,
+            "s" => OptimizeLevel::Size,
+            "z" => OptimizeLevel::SizeMin,
+            _ => return None,
+        })
+    }
+
+    /// The `-Copt-level` this level corresponds to.
+    pub fn opt_level(self) -> OptLevel {
+        match self {
+            OptimizeLevel::No => OptLevel::No,
+            OptimizeLevel::Less => OptLevel::Less,
+            OptimizeLevel::Default => OptLevel::Default,
+            OptimizeLevel::Aggressive => OptLevel::Aggressive,
+            OptimizeLevel::Size => OptLevel::Size,
+            OptimizeLevel::SizeMin => OptLevel::SizeMin,
+        }
+    }
 }
 
 /// Represents the following attributes:
@@ -207,6 +207,17 @@ builtin_macros_should_panic = functions using `#[should_panic]` must return `()`
 
 builtin_macros_sugg = consider using a positional formatting argument instead
 
+builtin_macros_target_clones_bad_fn = {$kind} functions cannot have multiple versions
+    .label = `{$kind}` because of this
+
+builtin_macros_target_clones_default = `#[target_clones]` requires exactly one `"default"` version
+
+builtin_macros_target_clones_expected_str = expected a string literal of comma-separated target features, or `"default"`
+
+builtin_macros_target_clones_must_be_fn = `#[target_clones]` can only be applied to free functions
+
+builtin_macros_target_clones_unsupported_arch = `#[target_clones]` is not supported on `{$arch}` targets
+
 builtin_macros_test_arg_non_lifetime = functions used as tests can not have any non-lifetime generic parameters
 
 builtin_macros_test_args = functions used as tests can not have any arguments
@@ -6,6 +6,7 @@
 use rustc_ast::tokenstream::TokenStream;
 use rustc_ast::{self as ast, GenericArg};
 use rustc_expand::base::{self, *};
+use rustc_session::errors::NondeterminismSource;
 use rustc_span::symbol::{kw, sym, Ident, Symbol};
 use rustc_span::Span;
 use std::env;
@@ -22,6 +23,7 @@ pub fn expand_option_env<'cx>(
         return DummyResult::any(sp);
     };
 
+    record_volatile_env_var(cx, sp, "option_env", var);
     let sp = cx.with_def_site_ctxt(sp);
     let value = env::var(var.as_str()).ok().as_deref().map(Symbol::intern);
     cx.sess.parse_sess.env_depinfo.borrow_mut().insert((var, value));
@@ -76,6 +78,7 @@ pub fn expand_env<'cx>(
         },
     };
 
+    record_volatile_env_var(cx, sp, "env", var);
     let sp = cx.with_def_site_ctxt(sp);
     let value = env::var(var.as_str()).ok().as_deref().map(Symbol::intern);
     cx.sess.parse_sess.env_depinfo.borrow_mut().insert((var, value));
@@ -109,3 +112,28 @@ fn help_for_missing_env_var(var: &str) -> errors::EnvNotDefinedHelp {
         errors::EnvNotDefinedHelp::Other
     }
 }
+
+/// Environment variables whose value differs between machines, users or sessions.
+const VOLATILE_ENV_VARS: &[&str] = &[
+    "COMPUTERNAME",
+    "HOME",
+    "HOSTNAME",
+    "LOGNAME",
+    "OLDPWD",
+    "PWD",
+    "TEMP",
+    "TMP",
+    "TMPDIR",
+    "USER",
+    "USERNAME",
+    "USERPROFILE",
+];
+
+/// Under `-Zdeterministic`, records that `var` is embedded in the output if its value is volatile.
+/// Whether it is set or not is just as volatile, so this also applies to `option_env!`.
+fn record_volatile_env_var(cx: &ExtCtxt<'_>, span: Span, macro_name: &'static str, var: Symbol) {
+    if cx.sess.opts.unstable_opts.deterministic && VOLATILE_ENV_VARS.contains(&var.as_str()) {
+        let source = NondeterminismSource::EnvVar { span, macro_name, var };
+        cx.sess.parse_sess.nondeterminism_sources.borrow_mut().push(source);
+    }
+}
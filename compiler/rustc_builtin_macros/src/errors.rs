@@ -642,6 +642,45 @@ pub(crate) struct TestCaseNonItem {
     pub(crate) span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(builtin_macros_target_clones_must_be_fn)]
+pub(crate) struct TargetClonesMustBeFn {
+    #[primary_span]
+    pub(crate) span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(builtin_macros_target_clones_bad_fn)]
+pub(crate) struct TargetClonesBadFn {
+    #[primary_span]
+    pub(crate) span: Span,
+    #[label]
+    pub(crate) cause: Span,
+    pub(crate) kind: &'static str,
+}
+
+#[derive(Diagnostic)]
+#[diag(builtin_macros_target_clones_expected_str)]
+pub(crate) struct TargetClonesExpectedStr {
+    #[primary_span]
+    pub(crate) span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(builtin_macros_target_clones_default)]
+pub(crate) struct TargetClonesDefault {
+    #[primary_span]
+    pub(crate) span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(builtin_macros_target_clones_unsupported_arch)]
+pub(crate) struct TargetClonesUnsupportedArch<'a> {
+    #[primary_span]
+    pub(crate) span: Span,
+    pub(crate) arch: &'a str,
+}
+
 #[derive(Diagnostic)]
 #[diag(builtin_macros_test_bad_fn)]
 pub(crate) struct TestBadFn {
@@ -45,6 +45,7 @@
 mod global_allocator;
 mod log_syntax;
 mod source_util;
+mod target_clones;
 mod test;
 mod trace_macros;
 mod type_ascribe;
@@ -108,6 +109,7 @@ pub fn register_builtin_macros(resolver: &mut dyn ResolverExpand) {
         derive: derive::Expander(false),
         derive_const: derive::Expander(true),
         global_allocator: global_allocator::expand,
+        target_clones: target_clones::expand,
         test: test::expand_test,
         test_case: test::expand_test_case,
     }
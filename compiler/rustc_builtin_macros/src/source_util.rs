@@ -7,9 +7,10 @@
 use rustc_expand::module::DirOwnership;
 use rustc_parse::parser::{ForceCollect, Parser};
 use rustc_parse::{self, new_parser_from_file};
+use rustc_session::errors::NondeterminismSource;
 use rustc_session::lint::builtin::INCOMPLETE_INCLUDE;
 use rustc_span::symbol::Symbol;
-use rustc_span::{self, Pos, Span};
+use rustc_span::{self, FileName, Pos, RealFileName, Span};
 
 use smallvec::SmallVec;
 use std::rc::Rc;
@@ -61,6 +62,14 @@ pub fn expand_file(
 
     let topmost = cx.expansion_cause().unwrap_or(sp);
     let loc = cx.source_map().lookup_char_pos(topmost.lo());
+    if cx.sess.opts.unstable_opts.deterministic
+        && let FileName::Real(RealFileName::LocalPath(path)) = &loc.file.name
+        && path.is_absolute()
+    {
+        cx.sess.parse_sess.nondeterminism_sources.borrow_mut().push(
+            NondeterminismSource::FileMacro { span: topmost, path: path.display().to_string() },
+        );
+    }
     base::MacEager::expr(
         cx.expr_str(topmost, Symbol::intern(&loc.file.name.prefer_remapped().to_string_lossy())),
     )
@@ -0,0 +1,352 @@
+//! Function multiversioning: `#[target_clones("avx2", "sse4.2,popcnt", "default")]` compiles the
+//! function once for each set of target features, and dispatches to the first listed version
+//! whose features are all detected at runtime, or to the default version.
+//!
+//! ```ignore (illustrative)
+//! fn sum(values: &[f32]) -> f32 {
+//!     #[target_feature(enable = "avx2")]
+//!     #[deny(unsafe_op_in_unsafe_fn)]
+//!     unsafe fn sum_avx2(values: &[f32]) -> f32 { /* body */ }
+//!     fn sum_default(values: &[f32]) -> f32 { /* body */ }
+//!
+//!     static SELECTED: OnceLock<unsafe fn(&[f32]) -> f32> = OnceLock::new();
+//!     let selected = *OnceLock::get_or_init(&SELECTED, || {
+//!         if is_x86_feature_detected!("avx2") { sum_avx2 } else { sum_default }
+//!     });
+//!     unsafe { selected(arg0) }
+//! }
+//! ```
+
+use crate::errors;
+
+use rustc_ast::ptr::P;
+use rustc_ast::token::{self, Lit, LitKind};
+use rustc_ast::tokenstream::{DelimSpan, TokenStream, TokenTree};
+use rustc_ast::visit::{self, LifetimeCtxt, Visitor};
+use rustc_ast::{self as ast, attr, AttrArgs, AttrStyle, BareFnTy, BinOpKind, BlockCheckMode};
+use rustc_ast::{DelimArgs, Expr, ExprKind, Extern, FnSig, GenericArg, ItemKind, MacCall};
+use rustc_ast::{MacDelimiter, Mutability, Param, Path, TyKind, Unsafe, UnsafeSource};
+use rustc_expand::base::{Annotatable, ExtCtxt};
+use rustc_feature::AttributeTemplate;
+use rustc_parse::validate_attr;
+use rustc_span::symbol::{sym, Ident, Symbol};
+use rustc_span::Span;
+use thin_vec::{thin_vec, ThinVec};
+
+pub fn expand(
+    ecx: &mut ExtCtxt<'_>,
+    _span: Span,
+    meta_item: &ast::MetaItem,
+    item: Annotatable,
+) -> Vec<Annotatable> {
+    let template = AttributeTemplate {
+        list: Some(r#""feature1,feature2", ..., "default""#),
+        ..Default::default()
+    };
+    validate_attr::check_builtin_meta_item(
+        &ecx.sess.parse_sess,
+        meta_item,
+        AttrStyle::Outer,
+        sym::target_clones,
+        template,
+    );
+
+    let orig_item = item.clone();
+
+    let (item, f) = if let Annotatable::Item(item) = &item
+        && let ItemKind::Fn(f) = &item.kind
+        && f.body.is_some()
+    {
+        (item, f)
+    } else {
+        ecx.sess.parse_sess.span_diagnostic.emit_err(errors::TargetClonesMustBeFn {
+            span: item.span(),
+        });
+        return vec![orig_item];
+    };
+
+    let Some(clones) = parse_clones(ecx, meta_item) else {
+        return vec![orig_item];
+    };
+    if check_signature(ecx, item, f).is_err() {
+        return vec![orig_item];
+    }
+    let detection_macro = match &*ecx.sess.target.arch {
+        "x86" | "x86_64" => sym::is_x86_feature_detected,
+        "aarch64" => sym::is_aarch64_feature_detected,
+        arch => {
+            ecx.sess.parse_sess.span_diagnostic.emit_err(errors::TargetClonesUnsupportedArch {
+                span: meta_item.span,
+                arch,
+            });
+            return vec![orig_item];
+        }
+    };
+
+    let span = ecx.with_def_site_ctxt(item.span);
+    let mut stmts = ThinVec::new();
+
+    // The versions of the function, nested in the dispatcher so that they don't pollute the
+    // namespace of the module.
+    let mut versions = Vec::with_capacity(clones.len());
+    for &(lit_span, features) in &clones {
+        let ident = version_ident(item.ident, features, span);
+        let mut header = f.sig.header;
+        header.ext = Extern::None;
+        let mut attrs = ThinVec::new();
+        if let Some(features) = features {
+            // `#[target_feature]` functions must be unsafe: keep requiring `unsafe` blocks for
+            // the unsafe operations of a safe function.
+            if let Unsafe::No = header.unsafety {
+                header.unsafety = Unsafe::Yes(span);
+                attrs.push(ecx.attr_nested_word(sym::deny, sym::unsafe_op_in_unsafe_fn, span));
+            }
+            attrs.push(target_feature_attr(ecx, features, lit_span));
+        }
+        let sig = FnSig { header, decl: f.sig.decl.clone(), span: f.sig.span };
+        let kind = ItemKind::Fn(Box::new(ast::Fn {
+            defaultness: ast::Defaultness::Final,
+            generics: f.generics.clone(),
+            sig,
+            body: f.body.clone(),
+        }));
+        stmts.push(ecx.stmt_item(span, ecx.item(span, ident, attrs, kind)));
+        versions.push((features, ident));
+    }
+
+    // The dispatcher takes the arguments by name, and forwards them to the selected version
+    // through a function pointer, which binds the lifetime parameters with `for<..>`.
+    let mut params = ThinVec::new();
+    let mut fn_ptr_params = ThinVec::new();
+    let mut args = ThinVec::new();
+    for (i, param) in f.sig.decl.inputs.iter().enumerate() {
+        let ident = Ident::from_str_and_span(&format!("arg{i}"), span);
+        params.push(ecx.param(span, ident, param.ty.clone()));
+        fn_ptr_params.push(Param {
+            attrs: ast::AttrVec::new(),
+            ty: param.ty.clone(),
+            pat: ecx.pat_wild(span),
+            id: ast::DUMMY_NODE_ID,
+            span,
+            is_placeholder: false,
+        });
+        args.push(ecx.expr_ident(span, ident));
+    }
+    let fn_ptr_ty = ecx.ty(
+        span,
+        TyKind::BareFn(P(BareFnTy {
+            unsafety: Unsafe::Yes(span),
+            ext: Extern::None,
+            generic_params: f.generics.params.clone(),
+            decl: ecx.fn_decl(fn_ptr_params, f.sig.decl.output.clone()),
+            decl_span: span,
+        })),
+    );
+
+    // static SELECTED: OnceLock<unsafe fn(..) -> ..> = OnceLock::new();
+    let selected_static = Ident::from_str_and_span("SELECTED", span);
+    let once_lock = ecx.std_path(&[sym::sync, sym::OnceLock]);
+    let static_ty =
+        ecx.ty_path(ecx.path_all(span, true, once_lock, vec![GenericArg::Type(fn_ptr_ty)]));
+    let once_lock_new = ecx.std_path(&[sym::sync, sym::OnceLock, sym::new]);
+    let static_init = ecx.expr_call_global(span, once_lock_new, thin_vec![]);
+    let static_item =
+        ecx.item_static(span, selected_static, static_ty, Mutability::Not, static_init);
+    stmts.push(ecx.stmt_item(span, static_item));
+
+    // The first listed version whose target features are all detected, or the default one.
+    let (_, default) = versions.iter().find(|(features, _)| features.is_none()).unwrap();
+    let mut selection = ecx.expr_ident(span, *default);
+    for &(features, ident) in versions.iter().rev() {
+        let Some(features) = features else { continue };
+        let detected = features
+            .as_str()
+            .split(',')
+            .map(|feature| detect_feature(ecx, span, detection_macro, feature.trim()))
+            .reduce(|lhs, rhs| ecx.expr_binary(span, BinOpKind::And, lhs, rhs))
+            .unwrap();
+        selection = ecx.expr_if(span, detected, ecx.expr_ident(span, ident), Some(selection));
+    }
+
+    // let selected = *OnceLock::get_or_init(&SELECTED, || ..);
+    // The type of `selected` is inferred, as `for<..>` would shadow the lifetime parameters.
+    let selected = Ident::from_str_and_span("selected", span);
+    let get_or_init = ecx.std_path(&[sym::sync, sym::OnceLock, sym::get_or_init]);
+    let get_or_init = ecx.expr_call_global(
+        span,
+        get_or_init,
+        thin_vec![
+            ecx.expr_addr_of(span, ecx.expr_ident(span, selected_static)),
+            ecx.lambda0(span, selection),
+        ],
+    );
+    let get_or_init = ecx.expr_deref(span, get_or_init);
+    stmts.push(ecx.stmt_let(span, false, selected, get_or_init));
+
+    // unsafe { selected(arg0, ..) }
+    let call = ecx.expr_call(span, ecx.expr_ident(span, selected), args);
+    let call = ecx.expr_block(P(ast::Block {
+        stmts: thin_vec![ecx.stmt_expr(call)],
+        id: ast::DUMMY_NODE_ID,
+        rules: BlockCheckMode::Unsafe(UnsafeSource::CompilerGenerated),
+        span,
+        tokens: None,
+        could_be_bare_literal: false,
+    }));
+    stmts.push(ecx.stmt_expr(call));
+
+    let mut dispatcher = item.clone();
+    if let ItemKind::Fn(f) = &mut dispatcher.kind {
+        f.sig.decl = ecx.fn_decl(params, f.sig.decl.output.clone());
+        f.body = Some(ecx.block(span, stmts));
+    }
+    vec![Annotatable::Item(dispatcher)]
+}
+
+/// Returns the target features of each version, in order, with `None` for the default version.
+fn parse_clones(
+    ecx: &ExtCtxt<'_>,
+    meta_item: &ast::MetaItem,
+) -> Option<Vec<(Span, Option<Symbol>)>> {
+    let list = meta_item.meta_item_list()?;
+    let sd = &ecx.sess.parse_sess.span_diagnostic;
+
+    let mut clones = Vec::with_capacity(list.len());
+    for nested in list {
+        let Some(ast::MetaItemLit { kind: ast::LitKind::Str(features, _), span, .. }) =
+            nested.lit()
+        else {
+            sd.emit_err(errors::TargetClonesExpectedStr { span: nested.span() });
+            return None;
+        };
+        let features = (features.as_str() != "default").then_some(*features);
+        if let Some(features) = features
+            && features.as_str().split(',').any(|feature| feature.trim().is_empty())
+        {
+            sd.emit_err(errors::TargetClonesExpectedStr { span: *span });
+            return None;
+        }
+        clones.push((*span, features));
+    }
+
+    if clones.iter().filter(|(_, features)| features.is_none()).count() != 1 {
+        sd.emit_err(errors::TargetClonesDefault { span: meta_item.span });
+        return None;
+    }
+    Some(clones)
+}
+
+/// The versions of a function are called through a function pointer, which can't be `async`,
+/// `const`, use `impl Trait` or be generic over anything but late-bound lifetimes.
+fn check_signature(ecx: &ExtCtxt<'_>, item: &ast::Item, f: &ast::Fn) -> Result<(), ()> {
+    let sd = &ecx.sess.parse_sess.span_diagnostic;
+    let bad_fn = |cause, kind| {
+        sd.emit_err(errors::TargetClonesBadFn { span: item.span, cause, kind });
+        Err(())
+    };
+
+    if let ast::Async::Yes { span, .. } = f.sig.header.asyncness {
+        return bad_fn(span, "async");
+    }
+    if let ast::Const::Yes(span) = f.sig.header.constness {
+        return bad_fn(span, "const");
+    }
+
+    let mut inputs = SignatureVisitor::default();
+    for param in &f.sig.decl.inputs {
+        inputs.visit_ty(&param.ty);
+    }
+    let mut output = SignatureVisitor::default();
+    output.visit_fn_ret_ty(&f.sig.decl.output);
+
+    // `for<..>` can only bind lifetimes which are late-bound: not bounded, and used by the
+    // arguments.
+    if f.generics.where_clause.has_where_token {
+        return bad_fn(f.generics.where_clause.span, "generic");
+    }
+    for param in &f.generics.params {
+        if !matches!(param.kind, ast::GenericParamKind::Lifetime)
+            || !param.bounds.is_empty()
+            || !inputs.lifetimes.contains(&param.ident.name)
+        {
+            return bad_fn(f.generics.span, "generic");
+        }
+    }
+    if let Some(span) = inputs.impl_trait.or(output.impl_trait) {
+        return bad_fn(span, "impl Trait");
+    }
+    if f.sig.decl.c_variadic() {
+        return bad_fn(f.sig.decl.inputs.last().unwrap().span, "C-variadic");
+    }
+    Ok(())
+}
+
+/// Finds the named lifetimes and `impl Trait` types in a function signature.
+#[derive(Default)]
+struct SignatureVisitor {
+    lifetimes: Vec<Symbol>,
+    impl_trait: Option<Span>,
+}
+
+impl<'ast> Visitor<'ast> for SignatureVisitor {
+    fn visit_ty(&mut self, ty: &'ast ast::Ty) {
+        if let TyKind::ImplTrait(..) = ty.kind {
+            self.impl_trait.get_or_insert(ty.span);
+        }
+        visit::walk_ty(self, ty);
+    }
+
+    fn visit_lifetime(&mut self, lifetime: &'ast ast::Lifetime, _: LifetimeCtxt) {
+        self.lifetimes.push(lifetime.ident.name);
+    }
+}
+
+/// `sum` compiled with `sse4.2,popcnt` is named `sum_sse4_2_popcnt`.
+fn version_ident(ident: Ident, features: Option<Symbol>, span: Span) -> Ident {
+    let suffix = match features {
+        Some(features) => features.as_str().replace(|c: char| !c.is_ascii_alphanumeric(), "_"),
+        None => "default".to_string(),
+    };
+    Ident::from_str_and_span(&format!("{ident}_{suffix}"), span)
+}
+
+/// Builds `#[target_feature(enable = "features")]`.
+fn target_feature_attr(ecx: &ExtCtxt<'_>, features: Symbol, span: Span) -> ast::Attribute {
+    let tokens = TokenStream::new(vec![
+        TokenTree::token_alone(token::Ident(sym::enable, false), span),
+        TokenTree::token_alone(token::Eq, span),
+        TokenTree::token_alone(token::Literal(Lit::new(LitKind::Str, features, None)), span),
+    ]);
+    let args = AttrArgs::Delimited(DelimArgs {
+        dspan: DelimSpan::from_single(span),
+        delim: MacDelimiter::Parenthesis,
+        tokens,
+    });
+    let path = Path::from_ident(Ident::new(sym::target_feature, span));
+    attr::mk_attr(&ecx.sess.parse_sess.attr_id_generator, AttrStyle::Outer, path, args, span)
+}
+
+/// Builds `std::arch::is_x86_feature_detected!("feature")`.
+fn detect_feature(
+    ecx: &ExtCtxt<'_>,
+    span: Span,
+    detection_macro: Symbol,
+    feature: &str,
+) -> P<Expr> {
+    let path = ecx.path_global(span, ecx.std_path(&[sym::arch, detection_macro]));
+    let tokens = TokenStream::token_alone(
+        token::Literal(Lit::new(LitKind::Str, Symbol::intern(feature), None)),
+        span,
+    );
+    ecx.expr(
+        span,
+        ExprKind::MacCall(P(MacCall {
+            path,
+            args: P(DelimArgs {
+                dspan: DelimSpan::from_single(span),
+                delim: MacDelimiter::Parenthesis,
+                tokens,
+            }),
+        })),
+    )
+}
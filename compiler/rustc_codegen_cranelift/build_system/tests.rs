@@ -77,6 +77,12 @@ const fn jit_bin(config: &'static str, source: &'static str, args: &'static str)
     TestCase::build_lib("build.alloc_system", "example/alloc_system.rs", "lib"),
     TestCase::build_bin_and_run("aot.alloc_example", "example/alloc_example.rs", &[]),
     TestCase::jit_bin("jit.std_example", "example/std_example.rs", ""),
+    TestCase::custom("jit.rustc-clif", &|runner| {
+        // Arguments containing whitespace must reach the script unchanged.
+        let mut jit_cmd = Command::new(&runner.target_compiler.rustc);
+        jit_cmd.args(["--jit", "example/jit_args.rs", "a b", "", "c"]);
+        spawn_and_wait(jit_cmd);
+    }),
     TestCase::build_bin_and_run("aot.std_example", "example/std_example.rs", &["arg"]),
     TestCase::build_bin_and_run("aot.dst_field_align", "example/dst-field-align.rs", &[]),
     TestCase::build_bin_and_run(
@@ -92,6 +98,43 @@ const fn jit_bin(config: &'static str, source: &'static str, args: &'static str)
     TestCase::build_bin_and_run("aot.float-minmax-pass", "example/float-minmax-pass.rs", &[]),
     TestCase::build_bin_and_run("aot.mod_bench", "example/mod_bench.rs", &[]),
     TestCase::build_bin_and_run("aot.issue-72793", "example/issue-72793.rs", &[]),
+    TestCase::custom("aot.debuginfo", &|runner| {
+        runner.run_rustc(["example/debuginfo.rs"]);
+        runner.run_out_command("debuginfo", &[]);
+
+        // Check the DWARF of the variables and types. Only ELF targets keep it in the executable.
+        if !runner.is_native || !runner.target_compiler.triple.contains("linux") {
+            return;
+        }
+        if Command::new("llvm-dwarfdump").arg("--version").output().is_err() {
+            eprintln!("[SKIP] llvm-dwarfdump not found, not checking the debuginfo");
+            return;
+        }
+        let executable = BUILD_EXAMPLE_OUT_DIR.to_path(&runner.dirs).join("debuginfo");
+        for (name, expected) in [
+            // Stack slots
+            ("value", &["DW_TAG_variable", "DW_AT_location"][..]),
+            ("with_fields", &["DW_TAG_variable", "DW_AT_location"]),
+            ("list", &["DW_TAG_variable", "DW_AT_location"]),
+            // SSA variables
+            ("doubled", &["DW_TAG_variable", "DW_AT_location"]),
+            ("x", &["DW_TAG_formal_parameter", "DW_AT_location"]),
+            // Types
+            ("debuginfo::Signed", &["DW_TAG_enumeration_type", "\"Minus\"", "(-1)"]),
+            ("debuginfo::WithFields", &["DW_TAG_variant_part", "\"Tuple\"", "\"Struct\""]),
+        ] {
+            let mut dwarfdump_cmd = Command::new("llvm-dwarfdump");
+            dwarfdump_cmd.args(["--debug-info", "--show-children", "--name", name]);
+            dwarfdump_cmd.arg(&executable);
+            let output = spawn_and_wait_with_input(dwarfdump_cmd, String::new());
+            for expected in expected {
+                assert!(
+                    output.contains(expected),
+                    "debuginfo of `{name}` doesn't contain `{expected}`:\n{output}",
+                );
+            }
+        }
+    }),
 ];
 
 // FIXME(rust-random/rand#1293): Newer rand versions fail to test on Windows. Update once this is
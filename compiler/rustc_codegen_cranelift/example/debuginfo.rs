@@ -0,0 +1,88 @@
+// Exercises the DWARF emitted for types and local variables. The test runner compiles all examples
+// with `-Cdebuginfo=2`.
+
+use std::hint::black_box;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(i8)]
+enum Signed {
+    Minus = -1,
+    Zero = 0,
+    Plus = 1,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Unsigned {
+    A,
+    B = 200,
+}
+
+#[derive(Debug, PartialEq)]
+enum WithFields {
+    Unit,
+    Tuple(u8, u64),
+    Struct { a: i16, b: Signed },
+}
+
+#[derive(Debug, PartialEq)]
+enum Niche<'a> {
+    Ref(&'a u32),
+    Empty,
+    Other,
+}
+
+#[derive(Debug)]
+enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+#[derive(Debug, PartialEq)]
+enum Single {
+    Only { value: u32 },
+}
+
+fn sum(list: &List) -> i32 {
+    match list {
+        List::Cons(value, rest) => value + sum(rest),
+        List::Nil => 0,
+    }
+}
+
+fn locals(x: u32, s: &str) -> usize {
+    let doubled = x * 2;
+    let pair = (doubled, s);
+    let len = black_box(pair.1).len();
+    black_box(doubled) as usize + len
+}
+
+fn main() {
+    let signed = black_box(Signed::Minus);
+    assert_eq!(signed as i8, -1);
+    assert_ne!(black_box(Signed::Zero), Signed::Plus);
+
+    let unsigned = black_box(Unsigned::B);
+    assert_eq!(unsigned as u8, 200);
+    assert_ne!(black_box(Unsigned::A), unsigned);
+
+    let with_fields = black_box([
+        WithFields::Unit,
+        WithFields::Tuple(1, 2),
+        WithFields::Struct { a: -3, b: Signed::Plus },
+    ]);
+    assert_eq!(with_fields[1], WithFields::Tuple(1, 2));
+
+    let value = 5;
+    let niche = black_box([Niche::Ref(&value), Niche::Empty, Niche::Other]);
+    assert_eq!(niche[0], Niche::Ref(&5));
+    let option = black_box(Some(&value));
+    assert_eq!(option, Some(&5));
+
+    let list = List::Cons(1, Box::new(List::Cons(2, Box::new(List::Nil))));
+    assert_eq!(sum(black_box(&list)), 3);
+
+    let single = black_box(Single::Only { value: 7 });
+    assert_eq!(single, Single::Only { value: 7 });
+
+    assert_eq!(locals(black_box(4), "abc"), 11);
+}
@@ -0,0 +1,4 @@
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    assert_eq!(args, ["a b", "", "c"]);
+}
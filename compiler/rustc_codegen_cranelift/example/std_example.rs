@@ -202,6 +202,12 @@ unsafe fn test_simd() {
     test_mm256_shuffle_epi8();
     test_mm256_permute2x128_si256();
 
+    test_mm_srai_epi16();
+    test_mm_madd_epi16();
+    test_mm_packs_epi16();
+    test_mm256_packus_epi16();
+    test_mm_crc32();
+
     #[rustfmt::skip]
     let mask1 = _mm_movemask_epi8(dbg!(_mm_setr_epi8(255u8 as i8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0)));
     assert_eq!(mask1, 1);
@@ -404,6 +410,61 @@ unsafe fn test_mm256_permute2x128_si256() {
     assert_eq_m256i(r, e);
 }
 
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
+unsafe fn test_mm_srai_epi16() {
+    let a = _mm_setr_epi16(-32768, -8, -1, 0, 1, 8, 32767, 256);
+    assert_eq_m128i(_mm_srai_epi16::<2>(a), _mm_setr_epi16(-8192, -2, -1, 0, 0, 2, 8191, 64));
+    assert_eq_m128i(_mm_srai_epi16::<20>(a), _mm_setr_epi16(-1, -1, -1, 0, 0, 0, 0, 0));
+    assert_eq_m128i(_mm_srli_epi16::<20>(a), _mm_setzero_si128());
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
+unsafe fn test_mm_madd_epi16() {
+    let a = _mm_setr_epi16(1, 2, 3, 4, 5, 6, -32768, -32768);
+    let b = _mm_setr_epi16(9, 10, 11, 12, 13, 14, -32768, -32768);
+    let e = _mm_setr_epi32(29, 81, 149, i32::MIN);
+    assert_eq_m128i(_mm_madd_epi16(a, b), e);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
+unsafe fn test_mm_packs_epi16() {
+    let a = _mm_setr_epi16(0x80, -0x81, 0, 0, 0, 0, 0, 0);
+    let b = _mm_setr_epi16(0, 0, 0, 0, 0, 0, -0x81, 0x80);
+    #[rustfmt::skip]
+    let e = _mm_setr_epi8(
+        0x7F, -0x80, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -0x80, 0x7F,
+    );
+    assert_eq_m128i(_mm_packs_epi16(a, b), e);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn test_mm256_packus_epi16() {
+    let a = _mm256_set1_epi16(2);
+    let b = _mm256_set1_epi16(4);
+    #[rustfmt::skip]
+    let e = _mm256_setr_epi8(
+        2, 2, 2, 2, 2, 2, 2, 2,
+        4, 4, 4, 4, 4, 4, 4, 4,
+        2, 2, 2, 2, 2, 2, 2, 2,
+        4, 4, 4, 4, 4, 4, 4, 4,
+    );
+    assert_eq_m256i(_mm256_packus_epi16(a, b), e);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse4.2")]
+unsafe fn test_mm_crc32() {
+    assert_eq!(_mm_crc32_u8(0x2aa1e72b, 0x2a), 0xf24122e4);
+    assert_eq!(_mm_crc32_u16(0x8ecec3b5, 0x022b), 0x013bb2fb);
+    assert_eq!(_mm_crc32_u32(0xae2912c8, 0x00845fed), 0xffae2ed1);
+    assert_eq!(_mm_crc32_u64(0x7819dccd3e824, 0x2a22b845fed), 0x048070bb);
+}
+
 fn test_checked_mul() {
     let u: Option<u8> = u8::from_str_radix("1000", 10).ok();
     assert_eq!(u, None);
@@ -1,8 +1,12 @@
 use std::env;
 use std::ffi::OsString;
+use std::fs;
 #[cfg(unix)]
 use std::os::unix::process::CommandExt;
-use std::process::Command;
+use std::path::Path;
+use std::process::{self, Command};
+use std::thread;
+use std::time::Duration;
 
 fn main() {
     let current_exe = env::current_exe().unwrap();
@@ -15,7 +19,43 @@ fn main() {
         env::consts::DLL_PREFIX.to_string() + "rustc_codegen_cranelift" + env::consts::DLL_SUFFIX,
     );
 
-    let passed_args = std::env::args_os().skip(1).collect::<Vec<_>>();
+    let mut passed_args = std::env::args_os().skip(1).collect::<Vec<_>>();
+
+    // `rustc-clif [rustc args] --jit [--watch] <script> [script args]` runs a single file in lazy
+    // JIT mode.
+    if let Some(jit_pos) = passed_args.iter().position(|arg| arg == "--jit") {
+        let mut script_and_args = passed_args.split_off(jit_pos).into_iter().skip(1).peekable();
+        if script_and_args.next_if(|arg| arg == "--watch").is_some() {
+            watch(&current_exe, &passed_args, script_and_args.collect());
+        }
+        let Some(script) = script_and_args.next() else {
+            eprintln!("rustc-clif: `--jit` requires the path of the file to run");
+            std::process::exit(1);
+        };
+
+        // Every argument is passed in its own env var, so that they can contain whitespace.
+        let script_args = script_and_args.collect::<Vec<_>>();
+        env::set_var("CG_CLIF_JIT_ARGC", script_args.len().to_string());
+        for (i, arg) in script_args.into_iter().enumerate() {
+            if arg.to_str().is_none() {
+                eprintln!("rustc-clif: script argument {arg:?} is not valid unicode");
+                std::process::exit(1);
+            }
+            env::set_var(format!("CG_CLIF_JIT_ARG_{i}"), arg);
+        }
+
+        if !passed_args
+            .iter()
+            .any(|arg| arg.to_str().map(|s| s.starts_with("--edition")) == Some(true))
+        {
+            passed_args.push(OsString::from("--edition=2021"));
+        }
+        passed_args.push(OsString::from("-Zunstable-options"));
+        passed_args.push(OsString::from("-Cllvm-args=mode=jit-lazy"));
+        passed_args.push(OsString::from("-Cprefer-dynamic"));
+        passed_args.push(script);
+    }
+
     let mut args = vec![];
     args.push(OsString::from("-Cpanic=abort"));
     args.push(OsString::from("-Zpanic-abort-tests"));
@@ -50,3 +90,29 @@ fn main() {
         Command::new(rustc).args(args).spawn().unwrap().wait().unwrap().code().unwrap_or(1),
     );
 }
+
+/// Runs `rustc-clif --jit <script> [script args]` again every time the script is modified.
+fn watch(current_exe: &Path, rustc_args: &[OsString], script_and_args: Vec<OsString>) -> ! {
+    let Some(script) = script_and_args.first() else {
+        eprintln!("rustc-clif: `--jit --watch` requires the path of the file to run");
+        process::exit(1);
+    };
+    let modified = || fs::metadata(script).and_then(|metadata| metadata.modified()).ok();
+
+    loop {
+        let last_modified = modified();
+        let status = Command::new(current_exe)
+            .args(rustc_args)
+            .arg("--jit")
+            .args(&script_and_args)
+            .status()
+            .expect("Failed to spawn rustc-clif");
+        eprintln!(
+            "rustc-clif: {} finished with {status}, waiting for changes",
+            Path::new(script).display(),
+        );
+        while modified() == last_modified {
+            thread::sleep(Duration::from_millis(200));
+        }
+    }
+}
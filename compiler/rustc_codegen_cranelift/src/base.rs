@@ -1,15 +1,19 @@
 //! Codegen of a single function
 
 use rustc_ast::InlineAsmOptions;
+use rustc_attr::OptimizeAttr;
 use rustc_index::IndexVec;
 use rustc_middle::ty::adjustment::PointerCast;
 use rustc_middle::ty::layout::FnAbiOf;
 use rustc_middle::ty::print::with_no_trimmed_paths;
 
 use cranelift_codegen::ir::UserFuncName;
+use cranelift_codegen::isa::TargetIsa;
+
+use std::sync::Arc;
 
 use crate::constant::ConstantCx;
-use crate::debuginfo::FunctionDebugContext;
+use crate::debuginfo::{FunctionDebugContext, TypeDebugContext};
 use crate::prelude::*;
 use crate::pretty_clif::CommentWriter;
 
@@ -19,11 +23,15 @@ pub(crate) struct CodegenedFunction {
     func: Function,
     clif_comments: CommentWriter,
     func_debug_cx: Option<FunctionDebugContext>,
+    /// The isa to compile this function with if it differs from the isa of the module due to
+    /// `#[optimize(level = "...")]`.
+    isa_override: Option<Arc<dyn TargetIsa>>,
 }
 
 pub(crate) fn codegen_fn<'tcx>(
     tcx: TyCtxt<'tcx>,
     cx: &mut crate::CodegenCx,
+    type_dbg: &mut TypeDebugContext<'tcx>,
     cached_func: Function,
     module: &mut dyn Module,
     instance: Instance<'tcx>,
@@ -101,6 +109,10 @@ pub(crate) fn codegen_fn<'tcx>(
     fx.bcx.seal_all_blocks();
     fx.bcx.finalize();
 
+    if fx.func_debug_cx.is_some() {
+        define_debug_variables(&mut fx, type_dbg);
+    }
+
     // Recover all necessary data from fx, before accessing func will prevent future access to it.
     let symbol_name = fx.symbol_name;
     let clif_comments = fx.clif_comments;
@@ -122,7 +134,46 @@ pub(crate) fn codegen_fn<'tcx>(
     // Verify function
     verify_func(tcx, &clif_comments, &func);
 
-    CodegenedFunction { symbol_name, func_id, func, clif_comments, func_debug_cx }
+    let isa_override = match tcx.codegen_fn_attrs(instance.def_id()).optimize {
+        OptimizeAttr::Level(level) => cx.isa_for_opt_level(tcx.sess, level.opt_level()),
+        OptimizeAttr::None | OptimizeAttr::Speed | OptimizeAttr::Size => None,
+    };
+
+    CodegenedFunction { symbol_name, func_id, func, clif_comments, func_debug_cx, isa_override }
+}
+
+fn define_debug_variables<'tcx>(
+    fx: &mut FunctionCx<'_, '_, 'tcx>,
+    type_dbg: &mut TypeDebugContext<'tcx>,
+) {
+    let variables = fx
+        .mir
+        .var_debug_info
+        .iter()
+        .filter_map(|var| {
+            // FIXME support variables behind references or projections and composite variables
+            let VarDebugInfoContents::Place(place) = var.value else { return None };
+            if var.references != 0 || !place.projection.is_empty() {
+                return None;
+            }
+            let ty = fx.monomorphize(fx.mir.local_decls[place.local].ty);
+            Some((var, ty, fx.local_map[place.local].debug_location(fx.tcx)))
+        })
+        .collect::<Vec<_>>();
+
+    let func_debug_cx = fx.func_debug_cx.as_mut().unwrap();
+    let debug_context = fx.cx.debug_context.as_mut().unwrap();
+    for (var, ty, location) in variables {
+        func_debug_cx.define_variable(
+            debug_context,
+            fx.tcx,
+            type_dbg,
+            fx.mir.span,
+            var,
+            ty,
+            location,
+        );
+    }
 }
 
 pub(crate) fn compile_fn(
@@ -172,7 +223,21 @@ pub(crate) fn compile_fn(
     // Define function
     cx.profiler.generic_activity("define function").run(|| {
         context.want_disasm = cx.should_write_ir;
-        module.define_function(codegened_func.func_id, context).unwrap();
+        if let Some(isa) = &codegened_func.isa_override {
+            context.compile(&**isa).unwrap();
+            let compiled_code = context.compiled_code().unwrap();
+            module
+                .define_function_bytes(
+                    codegened_func.func_id,
+                    &context.func,
+                    compiled_code.alignment as u64,
+                    compiled_code.code_buffer(),
+                    compiled_code.buffer.relocs(),
+                )
+                .unwrap();
+        } else {
+            module.define_function(codegened_func.func_id, context).unwrap();
+        }
     });
 
     if cx.should_write_ir {
@@ -196,7 +261,7 @@ pub(crate) fn compile_fn(
     }
 
     // Define debuginfo for function
-    let isa = module.isa();
+    let isa = codegened_func.isa_override.as_deref().unwrap_or(module.isa());
     let debug_context = &mut cx.debug_context;
     let unwind_context = &mut cx.unwind_context;
     cx.profiler.generic_activity("generate debug info").run(|| {
@@ -205,6 +270,7 @@ pub(crate) fn compile_fn(
                 debug_context,
                 codegened_func.func_id,
                 context,
+                isa,
             );
         }
         unwind_context.add_function(codegened_func.func_id, &context, isa);
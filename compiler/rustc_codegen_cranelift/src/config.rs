@@ -39,7 +39,9 @@ pub struct BackendConfig {
 
     /// When JIT mode is enable pass these arguments to the program.
     ///
-    /// Defaults to the value of `CG_CLIF_JIT_ARGS`.
+    /// Defaults to the value of `CG_CLIF_JIT_ARGS` split at spaces. Arguments containing spaces
+    /// can be passed as `CG_CLIF_JIT_ARG_0` up to `CG_CLIF_JIT_ARG_<n - 1>` with `CG_CLIF_JIT_ARGC`
+    /// set to `n` instead.
     pub jit_args: Vec<String>,
 
     /// Enable the Cranelift ir verifier for all compilation passes. If not set it will only run
@@ -63,9 +65,17 @@ impl Default for BackendConfig {
     fn default() -> Self {
         BackendConfig {
             codegen_mode: CodegenMode::Aot,
-            jit_args: {
+            jit_args: if let Ok(argc) = std::env::var("CG_CLIF_JIT_ARGC") {
+                let argc = argc.parse::<usize>().expect("CG_CLIF_JIT_ARGC is not a number");
+                (0..argc)
+                    .map(|i| {
+                        std::env::var(format!("CG_CLIF_JIT_ARG_{i}"))
+                            .unwrap_or_else(|_| panic!("CG_CLIF_JIT_ARG_{i} is not set"))
+                    })
+                    .collect()
+            } else {
                 let args = std::env::var("CG_CLIF_JIT_ARGS").unwrap_or_else(|_| String::new());
-                args.split(' ').map(|arg| arg.to_string()).collect()
+                args.split(' ').filter(|arg| !arg.is_empty()).map(|arg| arg.to_string()).collect()
             },
             enable_verifier: cfg!(debug_assertions) || bool_env_var("CG_CLIF_ENABLE_VERIFIER"),
             disable_incr_cache: bool_env_var("CG_CLIF_DISABLE_INCR_CACHE"),
@@ -3,21 +3,24 @@
 mod emit;
 mod line_info;
 mod object;
+mod types;
 mod unwind;
 
 use crate::prelude::*;
 
-use cranelift_codegen::ir::Endianness;
+use cranelift_codegen::LabelValueLoc;
+use cranelift_codegen::ir::{Endianness, ValueLabel};
 use cranelift_codegen::isa::TargetIsa;
 
 use gimli::write::{
-    Address, AttributeValue, DwarfUnit, FileId, LineProgram, LineString, Range, RangeList,
-    UnitEntryId,
+    Address, AttributeValue, DwarfUnit, Expression, FileId, LineProgram, LineString, Location,
+    LocationList, Range, RangeList, UnitEntryId,
 };
-use gimli::{Encoding, Format, LineEncoding, RunTimeEndian};
+use gimli::{Encoding, Format, LineEncoding, Register, RunTimeEndian};
 use indexmap::IndexSet;
 
 pub(crate) use emit::{DebugReloc, DebugRelocName};
+pub(crate) use types::TypeDebugContext;
 pub(crate) use unwind::UnwindContext;
 
 pub(crate) fn producer() -> String {
@@ -33,12 +36,27 @@ pub(crate) struct DebugContext {
 
     dwarf: DwarfUnit,
     unit_range_list: RangeList,
+
+    /// The DWARF register number of the stack pointer, if known for the target. Variable
+    /// locations are described relative to it.
+    stack_pointer: Option<Register>,
 }
 
 pub(crate) struct FunctionDebugContext {
     entry_id: UnitEntryId,
     function_source_loc: (FileId, u64, u64),
     source_loc_set: indexmap::IndexSet<(FileId, u64, u64)>,
+    /// Variables whose location is only known once the function has been compiled.
+    variables: Vec<(UnitEntryId, VariableLocation)>,
+}
+
+/// Where a user variable is stored.
+pub(crate) enum VariableLocation {
+    /// In a stack slot, at an offset within that slot.
+    Stack(StackSlot, i64),
+    /// In SSA variables, identified by their value label. Each of them holds the piece of the
+    /// variable with the given offset and size.
+    Ssa(Vec<(ValueLabel, Size, Size)>),
 }
 
 impl DebugContext {
@@ -104,7 +122,13 @@ pub(crate) fn new(tcx: TyCtxt<'_>, isa: &dyn TargetIsa) -> Self {
             root.set(gimli::DW_AT_low_pc, AttributeValue::Address(Address::Constant(0)));
         }
 
-        DebugContext { endian, dwarf, unit_range_list: RangeList(Vec::new()) }
+        let stack_pointer = match isa.triple().architecture {
+            target_lexicon::Architecture::X86_64 => Some(gimli::X86_64::RSP),
+            target_lexicon::Architecture::Aarch64(_) => Some(gimli::AArch64::SP),
+            _ => None,
+        };
+
+        DebugContext { endian, dwarf, unit_range_list: RangeList(Vec::new()), stack_pointer }
     }
 
     pub(crate) fn define_function(
@@ -135,16 +159,55 @@ pub(crate) fn define_function(
             entry_id,
             function_source_loc: (file_id, line, column),
             source_loc_set: IndexSet::new(),
+            variables: Vec::new(),
         }
     }
 }
 
 impl FunctionDebugContext {
+    /// Adds a `DW_TAG_variable` or `DW_TAG_formal_parameter` for a user variable. Its location is
+    /// filled in by [`finalize`](Self::finalize).
+    pub(crate) fn define_variable<'tcx>(
+        &mut self,
+        debug_context: &mut DebugContext,
+        tcx: TyCtxt<'tcx>,
+        type_dbg: &mut TypeDebugContext<'tcx>,
+        function_span: Span,
+        var: &VarDebugInfo<'tcx>,
+        ty: Ty<'tcx>,
+        location: Option<VariableLocation>,
+    ) {
+        let type_id = debug_context.debug_type(tcx, type_dbg, ty);
+        let (file, line, column) =
+            DebugContext::get_span_loc(tcx, function_span, var.source_info.span);
+        let file_id = debug_context.add_source_file(&file);
+
+        // FIXME add variables to a DW_TAG_lexical_block for their scope
+        let tag = if var.argument_index.is_some() {
+            gimli::DW_TAG_formal_parameter
+        } else {
+            gimli::DW_TAG_variable
+        };
+        let var_id = debug_context.dwarf.unit.add(self.entry_id, tag);
+        let name_id = debug_context.dwarf.strings.add(var.name.as_str());
+        let var_entry = debug_context.dwarf.unit.get_mut(var_id);
+        var_entry.set(gimli::DW_AT_name, AttributeValue::StringRef(name_id));
+        var_entry.set(gimli::DW_AT_type, AttributeValue::UnitRef(type_id));
+        var_entry.set(gimli::DW_AT_decl_file, AttributeValue::FileIndex(Some(file_id)));
+        var_entry.set(gimli::DW_AT_decl_line, AttributeValue::Udata(line));
+        var_entry.set(gimli::DW_AT_decl_column, AttributeValue::Udata(column));
+
+        if let Some(location) = location {
+            self.variables.push((var_id, location));
+        }
+    }
+
     pub(crate) fn finalize(
         mut self,
         debug_context: &mut DebugContext,
         func_id: FuncId,
         context: &Context,
+        isa: &dyn TargetIsa,
     ) {
         let symbol = func_id.as_u32() as usize;
 
@@ -163,5 +226,96 @@ pub(crate) fn finalize(
         );
         // Using Udata for DW_AT_high_pc requires at least DWARF4
         func_entry.set(gimli::DW_AT_high_pc, AttributeValue::Udata(u64::from(end)));
+
+        let compiled_code = context.compiled_code().unwrap();
+        for (var_id, location) in self.variables {
+            let location = match location {
+                VariableLocation::Stack(stack_slot, offset) => {
+                    let Some(stack_pointer) = debug_context.stack_pointer else { continue };
+                    // Stack slot offsets are relative to the stack pointer after the prologue.
+                    // FIXME this is wrong in the prologue and the epilogue
+                    let offset =
+                        i64::from(compiled_code.sized_stackslot_offsets[stack_slot]) + offset;
+                    let mut location = Expression::new();
+                    location.op_breg(stack_pointer, offset);
+                    AttributeValue::Exprloc(location)
+                }
+                VariableLocation::Ssa(pieces) => {
+                    let location_list = ssa_location_list(symbol, isa, compiled_code, &pieces);
+                    if location_list.0.is_empty() {
+                        continue;
+                    }
+                    AttributeValue::LocationListRef(
+                        debug_context.dwarf.unit.locations.add(location_list),
+                    )
+                }
+            };
+            let var_entry = debug_context.dwarf.unit.get_mut(var_id);
+            var_entry.set(gimli::DW_AT_location, location);
+        }
     }
 }
+
+/// Builds the location list of a variable stored in SSA variables from the ranges of code in which
+/// regalloc assigned a register or stack slot to their values. The variable only has a location
+/// where all of its pieces have one.
+fn ssa_location_list(
+    symbol: usize,
+    isa: &dyn TargetIsa,
+    compiled_code: &cranelift_codegen::CompiledCode,
+    pieces: &[(ValueLabel, Size, Size)],
+) -> LocationList {
+    let mut ranges: Vec<(u32, u32, Vec<LabelValueLoc>)> = vec![(0, u32::MAX, vec![])];
+    for &(label, _, _) in pieces {
+        let Some(piece_ranges) = compiled_code.value_labels_ranges.get(&label) else {
+            return LocationList(vec![]);
+        };
+        ranges = ranges
+            .iter()
+            .flat_map(|(start, end, locs)| {
+                piece_ranges.iter().filter_map(move |piece_range| {
+                    let start = piece_range.start.max(*start);
+                    let end = piece_range.end.min(*end);
+                    let mut locs = locs.clone();
+                    locs.push(piece_range.loc);
+                    (start < end).then_some((start, end, locs))
+                })
+            })
+            .collect();
+    }
+
+    let locations = ranges
+        .into_iter()
+        .filter_map(|(start, end, locs)| {
+            let mut expr = Expression::new();
+            let mut piece_end = Size::ZERO;
+            for (&(_, offset, size), loc) in pieces.iter().zip(locs) {
+                if pieces.len() > 1 && offset > piece_end {
+                    // Padding between the pieces.
+                    expr.op_piece((offset - piece_end).bytes());
+                }
+                match loc {
+                    LabelValueLoc::Reg(reg) => {
+                        let reg = isa.map_regalloc_reg_to_dwarf(reg).ok()?;
+                        expr.op_reg(gimli::Register(reg));
+                    }
+                    LabelValueLoc::CFAOffset(offset) => {
+                        expr.op(gimli::DW_OP_call_frame_cfa);
+                        expr.op_consts(offset);
+                        expr.op(gimli::DW_OP_plus);
+                    }
+                }
+                if pieces.len() > 1 {
+                    expr.op_piece(size.bytes());
+                }
+                piece_end = offset + size;
+            }
+            Some(Location::StartEnd {
+                begin: Address::Symbol { symbol, addend: i64::from(start) },
+                end: Address::Symbol { symbol, addend: i64::from(end) },
+                data: expr,
+            })
+        })
+        .collect();
+    LocationList(locations)
+}
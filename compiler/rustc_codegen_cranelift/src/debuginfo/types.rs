@@ -0,0 +1,412 @@
+//! Type debuginfo generation (`DW_TAG_*_type` entries in `.debug_info`)
+
+use crate::prelude::*;
+
+use rustc_codegen_ssa::debuginfo::type_names::compute_debuginfo_type_name;
+use rustc_middle::ty::layout::PrimitiveExt;
+use rustc_target::abi::{TagEncoding, Variants};
+
+use gimli::write::{AttributeValue, UnitEntryId};
+
+use super::DebugContext;
+
+/// Cache of the type entries already emitted for the current codegen unit.
+#[derive(Default)]
+pub(crate) struct TypeDebugContext<'tcx> {
+    type_map: FxHashMap<Ty<'tcx>, UnitEntryId>,
+}
+
+impl DebugContext {
+    pub(crate) fn debug_type<'tcx>(
+        &mut self,
+        tcx: TyCtxt<'tcx>,
+        type_dbg: &mut TypeDebugContext<'tcx>,
+        ty: Ty<'tcx>,
+    ) -> UnitEntryId {
+        if let Some(&type_id) = type_dbg.type_map.get(&ty) {
+            return type_id;
+        }
+
+        // Type parameters from polymorphized functions don't have a layout.
+        if let ty::Param(_) = ty.kind() {
+            let type_id = self.basic_type(tcx, ty, Size::ZERO);
+            type_dbg.type_map.insert(ty, type_id);
+            return type_id;
+        }
+
+        let layout = RevealAllLayoutCx(tcx).layout_of(ty);
+        if layout.is_unsized() {
+            let type_id = self.placeholder_for_type(tcx, ty, layout.size);
+            type_dbg.type_map.insert(ty, type_id);
+            return type_id;
+        }
+
+        match *ty.kind() {
+            ty::Never | ty::Bool | ty::Char | ty::Int(_) | ty::Uint(_) | ty::Float(_) => {
+                let type_id = self.basic_type(tcx, ty, layout.size);
+                type_dbg.type_map.insert(ty, type_id);
+                type_id
+            }
+            ty::Tuple(elems) if elems.is_empty() => {
+                let type_id = self.basic_type(tcx, ty, layout.size);
+                type_dbg.type_map.insert(ty, type_id);
+                type_id
+            }
+            ty::RawPtr(ty::TypeAndMut { ty: pointee_ty, .. }) | ty::Ref(_, pointee_ty, _)
+                if pointee_ty.is_sized(tcx, ParamEnv::reveal_all()) =>
+            {
+                self.pointer_type(tcx, type_dbg, ty, pointee_ty, layout.size)
+            }
+            ty::Array(elem_ty, len) => {
+                let len = len.eval_target_usize(tcx, ParamEnv::reveal_all());
+                self.array_type(tcx, type_dbg, ty, elem_ty, len, layout.size)
+            }
+            ty::Adt(adt_def, _) if adt_def.is_enum() => self.enum_type(tcx, type_dbg, ty, layout),
+            ty::Adt(adt_def, _) if adt_def.is_struct() || adt_def.is_union() => {
+                let variant = adt_def.non_enum_variant();
+                let field_names =
+                    variant.fields.iter().map(|field| field.name.to_string()).collect();
+                self.struct_type(tcx, type_dbg, ty, layout, field_names)
+            }
+            // Fat pointers
+            ty::RawPtr(ty::TypeAndMut { ty: pointee_ty, .. }) | ty::Ref(_, pointee_ty, _) => {
+                let metadata_name = match pointee_ty.kind() {
+                    ty::Dynamic(..) => "vtable",
+                    _ => "length",
+                };
+                let field_names = vec!["data_ptr".to_owned(), metadata_name.to_owned()];
+                self.struct_type(tcx, type_dbg, ty, layout, field_names)
+            }
+            ty::Tuple(_) | ty::Closure(..) | ty::Generator(..) => {
+                let field_names =
+                    (0..layout.fields.count()).map(|i| format!("__{i}")).collect::<Vec<_>>();
+                self.struct_type(tcx, type_dbg, ty, layout, field_names)
+            }
+            _ => {
+                let type_id = self.placeholder_for_type(tcx, ty, layout.size);
+                type_dbg.type_map.insert(ty, type_id);
+                type_id
+            }
+        }
+    }
+
+    fn type_name<'tcx>(&mut self, tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> AttributeValue {
+        AttributeValue::StringRef(
+            self.dwarf.strings.add(compute_debuginfo_type_name(tcx, ty, true)),
+        )
+    }
+
+    fn basic_type<'tcx>(&mut self, tcx: TyCtxt<'tcx>, ty: Ty<'tcx>, size: Size) -> UnitEntryId {
+        let encoding = match ty.kind() {
+            ty::Bool => gimli::DW_ATE_boolean,
+            ty::Char => gimli::DW_ATE_UTF,
+            ty::Int(_) => gimli::DW_ATE_signed,
+            ty::Uint(_) | ty::Never | ty::Tuple(_) | ty::Param(_) => gimli::DW_ATE_unsigned,
+            ty::Float(_) => gimli::DW_ATE_float,
+            _ => unreachable!("not a basic type: {ty}"),
+        };
+
+        let type_id = self.dwarf.unit.add(self.dwarf.unit.root(), gimli::DW_TAG_base_type);
+        let name = self.type_name(tcx, ty);
+        let type_entry = self.dwarf.unit.get_mut(type_id);
+        type_entry.set(gimli::DW_AT_name, name);
+        type_entry.set(gimli::DW_AT_encoding, AttributeValue::Encoding(encoding));
+        type_entry.set(gimli::DW_AT_byte_size, AttributeValue::Udata(size.bytes()));
+
+        type_id
+    }
+
+    fn pointer_type<'tcx>(
+        &mut self,
+        tcx: TyCtxt<'tcx>,
+        type_dbg: &mut TypeDebugContext<'tcx>,
+        ty: Ty<'tcx>,
+        pointee_ty: Ty<'tcx>,
+        size: Size,
+    ) -> UnitEntryId {
+        let type_id = self.dwarf.unit.add(self.dwarf.unit.root(), gimli::DW_TAG_pointer_type);
+        let name = self.type_name(tcx, ty);
+        let type_entry = self.dwarf.unit.get_mut(type_id);
+        type_entry.set(gimli::DW_AT_name, name);
+        type_entry.set(gimli::DW_AT_byte_size, AttributeValue::Udata(size.bytes()));
+
+        // Register the pointer before visiting the pointee to support recursive types.
+        type_dbg.type_map.insert(ty, type_id);
+        let pointee_id = self.debug_type(tcx, type_dbg, pointee_ty);
+        self.dwarf
+            .unit
+            .get_mut(type_id)
+            .set(gimli::DW_AT_type, AttributeValue::UnitRef(pointee_id));
+
+        type_id
+    }
+
+    fn array_type<'tcx>(
+        &mut self,
+        tcx: TyCtxt<'tcx>,
+        type_dbg: &mut TypeDebugContext<'tcx>,
+        ty: Ty<'tcx>,
+        elem_ty: Ty<'tcx>,
+        len: u64,
+        size: Size,
+    ) -> UnitEntryId {
+        let elem_id = self.debug_type(tcx, type_dbg, elem_ty);
+
+        let type_id = self.dwarf.unit.add(self.dwarf.unit.root(), gimli::DW_TAG_array_type);
+        let name = self.type_name(tcx, ty);
+        let type_entry = self.dwarf.unit.get_mut(type_id);
+        type_entry.set(gimli::DW_AT_name, name);
+        type_entry.set(gimli::DW_AT_byte_size, AttributeValue::Udata(size.bytes()));
+        type_entry.set(gimli::DW_AT_type, AttributeValue::UnitRef(elem_id));
+
+        let subrange_id = self.dwarf.unit.add(type_id, gimli::DW_TAG_subrange_type);
+        let subrange_entry = self.dwarf.unit.get_mut(subrange_id);
+        subrange_entry.set(gimli::DW_AT_count, AttributeValue::Udata(len));
+
+        type_dbg.type_map.insert(ty, type_id);
+        type_id
+    }
+
+    /// Emits a `DW_TAG_structure_type` or `DW_TAG_union_type` with a member for every field of
+    /// `layout`.
+    fn struct_type<'tcx>(
+        &mut self,
+        tcx: TyCtxt<'tcx>,
+        type_dbg: &mut TypeDebugContext<'tcx>,
+        ty: Ty<'tcx>,
+        layout: TyAndLayout<'tcx>,
+        field_names: Vec<String>,
+    ) -> UnitEntryId {
+        let tag =
+            if ty.is_union() { gimli::DW_TAG_union_type } else { gimli::DW_TAG_structure_type };
+        let type_id = self.dwarf.unit.add(self.dwarf.unit.root(), tag);
+        let name = self.type_name(tcx, ty);
+        let type_entry = self.dwarf.unit.get_mut(type_id);
+        type_entry.set(gimli::DW_AT_name, name);
+        type_entry.set(gimli::DW_AT_byte_size, AttributeValue::Udata(layout.size.bytes()));
+
+        // Register the struct before visiting the fields to support recursive types.
+        type_dbg.type_map.insert(ty, type_id);
+
+        self.add_members(tcx, type_dbg, type_id, layout, field_names);
+
+        type_id
+    }
+
+    /// Adds a `DW_TAG_member` for every field of `layout` to the type entry `type_id`.
+    fn add_members<'tcx>(
+        &mut self,
+        tcx: TyCtxt<'tcx>,
+        type_dbg: &mut TypeDebugContext<'tcx>,
+        type_id: UnitEntryId,
+        layout: TyAndLayout<'tcx>,
+        field_names: Vec<String>,
+    ) {
+        for (i, field_name) in field_names.into_iter().enumerate() {
+            let field_layout = layout.field(&RevealAllLayoutCx(tcx), i);
+            let field_type_id = self.debug_type(tcx, type_dbg, field_layout.ty);
+            let offset = layout.fields.offset(i);
+
+            let field_id = self.dwarf.unit.add(type_id, gimli::DW_TAG_member);
+            let field_name = self.dwarf.strings.add(field_name);
+            let field_entry = self.dwarf.unit.get_mut(field_id);
+            field_entry.set(gimli::DW_AT_name, AttributeValue::StringRef(field_name));
+            field_entry.set(gimli::DW_AT_type, AttributeValue::UnitRef(field_type_id));
+            field_entry
+                .set(gimli::DW_AT_data_member_location, AttributeValue::Udata(offset.bytes()));
+        }
+    }
+
+    fn enum_type<'tcx>(
+        &mut self,
+        tcx: TyCtxt<'tcx>,
+        type_dbg: &mut TypeDebugContext<'tcx>,
+        ty: Ty<'tcx>,
+        layout: TyAndLayout<'tcx>,
+    ) -> UnitEntryId {
+        let ty::Adt(adt_def, _) = *ty.kind() else { unreachable!() };
+
+        let is_c_like = adt_def.variants().iter().all(|variant| variant.fields.is_empty());
+        match layout.variants {
+            Variants::Multiple { tag, tag_encoding: TagEncoding::Direct, .. } if is_c_like => {
+                let tag_ty = tag.primitive().to_int_ty(tcx);
+                self.c_like_enum_type(tcx, type_dbg, ty, layout, tag_ty)
+            }
+            _ if adt_def.variants().is_empty() => {
+                let type_id = self.placeholder_for_type(tcx, ty, layout.size);
+                type_dbg.type_map.insert(ty, type_id);
+                type_id
+            }
+            _ => self.variant_part_enum_type(tcx, type_dbg, ty, layout),
+        }
+    }
+
+    /// Emits a `DW_TAG_enumeration_type` for fieldless enums with a directly encoded tag.
+    fn c_like_enum_type<'tcx>(
+        &mut self,
+        tcx: TyCtxt<'tcx>,
+        type_dbg: &mut TypeDebugContext<'tcx>,
+        ty: Ty<'tcx>,
+        layout: TyAndLayout<'tcx>,
+        tag_ty: Ty<'tcx>,
+    ) -> UnitEntryId {
+        let ty::Adt(adt_def, _) = *ty.kind() else { unreachable!() };
+
+        let tag_type_id = self.debug_type(tcx, type_dbg, tag_ty);
+
+        let type_id = self.dwarf.unit.add(self.dwarf.unit.root(), gimli::DW_TAG_enumeration_type);
+        let name = self.type_name(tcx, ty);
+        let type_entry = self.dwarf.unit.get_mut(type_id);
+        type_entry.set(gimli::DW_AT_name, name);
+        type_entry.set(gimli::DW_AT_byte_size, AttributeValue::Udata(layout.size.bytes()));
+        type_entry.set(gimli::DW_AT_type, AttributeValue::UnitRef(tag_type_id));
+
+        for (variant_idx, discr) in adt_def.discriminants(tcx) {
+            let variant_name = adt_def.variant(variant_idx).name.to_string();
+            let variant_name = self.dwarf.strings.add(variant_name);
+
+            let enumerator_id = self.dwarf.unit.add(type_id, gimli::DW_TAG_enumerator);
+            let enumerator_entry = self.dwarf.unit.get_mut(enumerator_id);
+            enumerator_entry.set(gimli::DW_AT_name, AttributeValue::StringRef(variant_name));
+            enumerator_entry.set(gimli::DW_AT_const_value, discr_value(tcx, tag_ty, discr.val));
+        }
+
+        type_dbg.type_map.insert(ty, type_id);
+        type_id
+    }
+
+    /// Emits a `DW_TAG_structure_type` containing a `DW_TAG_variant_part` for enums with fields
+    /// or a niche encoded tag, like the LLVM backend does on targets other than MSVC.
+    fn variant_part_enum_type<'tcx>(
+        &mut self,
+        tcx: TyCtxt<'tcx>,
+        type_dbg: &mut TypeDebugContext<'tcx>,
+        ty: Ty<'tcx>,
+        layout: TyAndLayout<'tcx>,
+    ) -> UnitEntryId {
+        let ty::Adt(adt_def, _) = *ty.kind() else { unreachable!() };
+
+        let type_id = self.dwarf.unit.add(self.dwarf.unit.root(), gimli::DW_TAG_structure_type);
+        let name = self.type_name(tcx, ty);
+        let type_entry = self.dwarf.unit.get_mut(type_id);
+        type_entry.set(gimli::DW_AT_name, name);
+        type_entry.set(gimli::DW_AT_byte_size, AttributeValue::Udata(layout.size.bytes()));
+
+        // Register the enum before visiting the variants to support recursive types.
+        type_dbg.type_map.insert(ty, type_id);
+
+        let variant_part_id = self.dwarf.unit.add(type_id, gimli::DW_TAG_variant_part);
+
+        // The value of the tag for every variant that can be represented. The variant without a
+        // value is the one used when the tag matches no other variant.
+        let (tag_ty, discr_values): (_, Vec<(VariantIdx, Option<u128>)>) = match layout.variants {
+            Variants::Single { index } => (None, vec![(index, None)]),
+            Variants::Multiple { tag, ref tag_encoding, tag_field, .. } => {
+                let tag_ty = tag.primitive().to_int_ty(tcx);
+                let tag_type_id = self.debug_type(tcx, type_dbg, tag_ty);
+                let tag_offset = layout.fields.offset(tag_field);
+
+                let tag_member_id = self.dwarf.unit.add(variant_part_id, gimli::DW_TAG_member);
+                let tag_entry = self.dwarf.unit.get_mut(tag_member_id);
+                tag_entry.set(gimli::DW_AT_type, AttributeValue::UnitRef(tag_type_id));
+                tag_entry.set(
+                    gimli::DW_AT_data_member_location,
+                    AttributeValue::Udata(tag_offset.bytes()),
+                );
+                tag_entry.set(gimli::DW_AT_artificial, AttributeValue::Flag(true));
+                self.dwarf
+                    .unit
+                    .get_mut(variant_part_id)
+                    .set(gimli::DW_AT_discr, AttributeValue::UnitRef(tag_member_id));
+
+                let discr_values = match *tag_encoding {
+                    TagEncoding::Direct => adt_def
+                        .discriminants(tcx)
+                        .map(|(variant_idx, discr)| (variant_idx, Some(discr.val)))
+                        .collect(),
+                    TagEncoding::Niche { untagged_variant, ref niche_variants, niche_start } => {
+                        // Variants that are neither untagged nor in the niche are uninhabited
+                        // and can't be represented.
+                        adt_def
+                            .variants()
+                            .indices()
+                            .filter_map(|variant_idx| {
+                                if variant_idx == untagged_variant {
+                                    Some((variant_idx, None))
+                                } else if niche_variants.contains(&variant_idx) {
+                                    let niche =
+                                        variant_idx.as_u32() - niche_variants.start().as_u32();
+                                    Some((
+                                        variant_idx,
+                                        Some(u128::from(niche).wrapping_add(niche_start)),
+                                    ))
+                                } else {
+                                    None
+                                }
+                            })
+                            .collect()
+                    }
+                };
+                (Some(tag_ty), discr_values)
+            }
+        };
+
+        for (variant_idx, discr) in discr_values {
+            let variant_def = adt_def.variant(variant_idx);
+            let variant_layout = layout.for_variant(&RevealAllLayoutCx(tcx), variant_idx);
+
+            // The fields of a variant are described by a struct nested in the enum, whose field
+            // offsets are relative to the start of the enum.
+            let variant_type_id = self.dwarf.unit.add(type_id, gimli::DW_TAG_structure_type);
+            let variant_name = self.dwarf.strings.add(variant_def.name.as_str());
+            let variant_type_entry = self.dwarf.unit.get_mut(variant_type_id);
+            variant_type_entry.set(gimli::DW_AT_name, AttributeValue::StringRef(variant_name));
+            variant_type_entry
+                .set(gimli::DW_AT_byte_size, AttributeValue::Udata(layout.size.bytes()));
+            let field_names =
+                variant_def.fields.iter().map(|field| field.name.to_string()).collect();
+            self.add_members(tcx, type_dbg, variant_type_id, variant_layout, field_names);
+
+            let variant_id = self.dwarf.unit.add(variant_part_id, gimli::DW_TAG_variant);
+            if let (Some(tag_ty), Some(discr)) = (tag_ty, discr) {
+                let discr_value = discr_value(tcx, tag_ty, discr);
+                self.dwarf.unit.get_mut(variant_id).set(gimli::DW_AT_discr_value, discr_value);
+            }
+            let member_id = self.dwarf.unit.add(variant_id, gimli::DW_TAG_member);
+            let member_entry = self.dwarf.unit.get_mut(member_id);
+            member_entry.set(gimli::DW_AT_name, AttributeValue::StringRef(variant_name));
+            member_entry.set(gimli::DW_AT_type, AttributeValue::UnitRef(variant_type_id));
+            member_entry.set(gimli::DW_AT_data_member_location, AttributeValue::Udata(0));
+        }
+
+        type_id
+    }
+
+    /// Emits an opaque struct of the right size for types we can't describe yet.
+    fn placeholder_for_type<'tcx>(
+        &mut self,
+        tcx: TyCtxt<'tcx>,
+        ty: Ty<'tcx>,
+        size: Size,
+    ) -> UnitEntryId {
+        let type_id = self.dwarf.unit.add(self.dwarf.unit.root(), gimli::DW_TAG_structure_type);
+        let name = self.type_name(tcx, ty);
+        let type_entry = self.dwarf.unit.get_mut(type_id);
+        type_entry.set(gimli::DW_AT_name, name);
+        type_entry.set(gimli::DW_AT_byte_size, AttributeValue::Udata(size.bytes()));
+
+        type_id
+    }
+}
+
+/// Returns the value of a discriminant or tag of the integer type `tag_ty` for
+/// `DW_AT_const_value` and `DW_AT_discr_value`. Values of signed types are sign extended, so that
+/// negative discriminants are read back correctly. Like LLVM, values are truncated to 64 bits.
+fn discr_value<'tcx>(tcx: TyCtxt<'tcx>, tag_ty: Ty<'tcx>, val: u128) -> AttributeValue {
+    let size = RevealAllLayoutCx(tcx).layout_of(tag_ty).size;
+    if tag_ty.is_signed() {
+        AttributeValue::Sdata(size.sign_extend(val) as i128 as i64)
+    } else {
+        AttributeValue::Udata(size.truncate(val) as u64)
+    }
+}
@@ -86,141 +86,196 @@ pub(crate) fn codegen_x86_llvm_intrinsic_call<'tcx>(
                 bool_to_zero_or_max_uint(fx, res_lane_ty, res_lane)
             });
         }
-        "llvm.x86.sse2.psrli.d" => {
-            let (a, imm8) = match args {
-                [a, imm8] => (a, imm8),
-                _ => bug!("wrong number of args for intrinsic {intrinsic}"),
-            };
-            let a = codegen_operand(fx, a);
-            let imm8 = crate::constant::mir_operand_get_const_val(fx, imm8)
-                .expect("llvm.x86.sse2.psrli.d imm8 not const");
-
-            simd_for_each_lane(fx, a, ret, &|fx, _lane_ty, _res_lane_ty, lane| match imm8
-                .try_to_bits(Size::from_bytes(4))
-                .unwrap_or_else(|| panic!("imm8 not scalar: {:?}", imm8))
-            {
-                imm8 if imm8 < 32 => fx.bcx.ins().ushr_imm(lane, i64::from(imm8 as u8)),
-                _ => fx.bcx.ins().iconst(types::I32, 0),
-            });
+        "llvm.x86.sse2.psrli.w"
+        | "llvm.x86.sse2.psrli.d"
+        | "llvm.x86.sse2.psrli.q"
+        | "llvm.x86.avx.psrli.d"
+        | "llvm.x86.avx2.psrli.w"
+        | "llvm.x86.avx2.psrli.d"
+        | "llvm.x86.avx2.psrli.q" => {
+            llvm_x86_shift_imm(fx, intrinsic, args, ret, ShiftKind::LogicalRight);
         }
-        "llvm.x86.sse2.pslli.d" => {
-            let (a, imm8) = match args {
-                [a, imm8] => (a, imm8),
-                _ => bug!("wrong number of args for intrinsic {intrinsic}"),
-            };
-            let a = codegen_operand(fx, a);
-            let imm8 = crate::constant::mir_operand_get_const_val(fx, imm8)
-                .expect("llvm.x86.sse2.pslli.d imm8 not const");
-
-            simd_for_each_lane(fx, a, ret, &|fx, _lane_ty, _res_lane_ty, lane| match imm8
-                .try_to_bits(Size::from_bytes(4))
-                .unwrap_or_else(|| panic!("imm8 not scalar: {:?}", imm8))
-            {
-                imm8 if imm8 < 32 => fx.bcx.ins().ishl_imm(lane, i64::from(imm8 as u8)),
-                _ => fx.bcx.ins().iconst(types::I32, 0),
-            });
+        "llvm.x86.sse2.pslli.w"
+        | "llvm.x86.sse2.pslli.d"
+        | "llvm.x86.sse2.pslli.q"
+        | "llvm.x86.avx.pslli.d"
+        | "llvm.x86.avx2.pslli.w"
+        | "llvm.x86.avx2.pslli.d"
+        | "llvm.x86.avx2.pslli.q" => {
+            llvm_x86_shift_imm(fx, intrinsic, args, ret, ShiftKind::Left);
         }
-        "llvm.x86.sse2.psrli.w" => {
-            let (a, imm8) = match args {
-                [a, imm8] => (a, imm8),
-                _ => bug!("wrong number of args for intrinsic {intrinsic}"),
-            };
-            let a = codegen_operand(fx, a);
-            let imm8 = crate::constant::mir_operand_get_const_val(fx, imm8)
-                .expect("llvm.x86.sse2.psrli.d imm8 not const");
-
-            simd_for_each_lane(fx, a, ret, &|fx, _lane_ty, _res_lane_ty, lane| match imm8
-                .try_to_bits(Size::from_bytes(4))
-                .unwrap_or_else(|| panic!("imm8 not scalar: {:?}", imm8))
-            {
-                imm8 if imm8 < 16 => fx.bcx.ins().ushr_imm(lane, i64::from(imm8 as u8)),
-                _ => fx.bcx.ins().iconst(types::I32, 0),
-            });
+        "llvm.x86.sse2.psrai.w"
+        | "llvm.x86.sse2.psrai.d"
+        | "llvm.x86.avx2.psrai.w"
+        | "llvm.x86.avx2.psrai.d" => {
+            llvm_x86_shift_imm(fx, intrinsic, args, ret, ShiftKind::ArithmeticRight);
         }
-        "llvm.x86.sse2.pslli.w" => {
-            let (a, imm8) = match args {
-                [a, imm8] => (a, imm8),
-                _ => bug!("wrong number of args for intrinsic {intrinsic}"),
-            };
-            let a = codegen_operand(fx, a);
-            let imm8 = crate::constant::mir_operand_get_const_val(fx, imm8)
-                .expect("llvm.x86.sse2.pslli.d imm8 not const");
-
-            simd_for_each_lane(fx, a, ret, &|fx, _lane_ty, _res_lane_ty, lane| match imm8
-                .try_to_bits(Size::from_bytes(4))
-                .unwrap_or_else(|| panic!("imm8 not scalar: {:?}", imm8))
-            {
-                imm8 if imm8 < 16 => fx.bcx.ins().ishl_imm(lane, i64::from(imm8 as u8)),
-                _ => fx.bcx.ins().iconst(types::I32, 0),
-            });
+        "llvm.x86.sse.max.ps"
+        | "llvm.x86.sse2.max.pd"
+        | "llvm.x86.avx.max.ps.256"
+        | "llvm.x86.avx.max.pd.256" => {
+            intrinsic_args!(fx, args => (a, b); intrinsic);
+
+            // Unlike `fmax`, this returns the second operand when either operand is NaN or when
+            // both are zero.
+            simd_pair_for_each_lane(
+                fx,
+                a,
+                b,
+                ret,
+                &|fx, _lane_ty, _res_lane_ty, a_lane, b_lane| {
+                    let gt = fx.bcx.ins().fcmp(FloatCC::GreaterThan, a_lane, b_lane);
+                    fx.bcx.ins().select(gt, a_lane, b_lane)
+                },
+            );
         }
-        "llvm.x86.avx.psrli.d" => {
-            let (a, imm8) = match args {
-                [a, imm8] => (a, imm8),
-                _ => bug!("wrong number of args for intrinsic {intrinsic}"),
-            };
-            let a = codegen_operand(fx, a);
-            let imm8 = crate::constant::mir_operand_get_const_val(fx, imm8)
-                .expect("llvm.x86.avx.psrli.d imm8 not const");
-
-            simd_for_each_lane(fx, a, ret, &|fx, _lane_ty, _res_lane_ty, lane| match imm8
-                .try_to_bits(Size::from_bytes(4))
-                .unwrap_or_else(|| panic!("imm8 not scalar: {:?}", imm8))
-            {
-                imm8 if imm8 < 32 => fx.bcx.ins().ushr_imm(lane, i64::from(imm8 as u8)),
-                _ => fx.bcx.ins().iconst(types::I32, 0),
-            });
+        "llvm.x86.sse.min.ps"
+        | "llvm.x86.sse2.min.pd"
+        | "llvm.x86.avx.min.ps.256"
+        | "llvm.x86.avx.min.pd.256" => {
+            intrinsic_args!(fx, args => (a, b); intrinsic);
+
+            simd_pair_for_each_lane(
+                fx,
+                a,
+                b,
+                ret,
+                &|fx, _lane_ty, _res_lane_ty, a_lane, b_lane| {
+                    let lt = fx.bcx.ins().fcmp(FloatCC::LessThan, a_lane, b_lane);
+                    fx.bcx.ins().select(lt, a_lane, b_lane)
+                },
+            );
         }
-        "llvm.x86.avx.pslli.d" => {
-            let (a, imm8) = match args {
-                [a, imm8] => (a, imm8),
-                _ => bug!("wrong number of args for intrinsic {intrinsic}"),
-            };
-            let a = codegen_operand(fx, a);
-            let imm8 = crate::constant::mir_operand_get_const_val(fx, imm8)
-                .expect("llvm.x86.avx.pslli.d imm8 not const");
+        "llvm.x86.sse2.pavg.b"
+        | "llvm.x86.sse2.pavg.w"
+        | "llvm.x86.avx2.pavg.b"
+        | "llvm.x86.avx2.pavg.w" => {
+            intrinsic_args!(fx, args => (a, b); intrinsic);
+
+            // (a + b + 1) >> 1 without overflowing the lane type
+            simd_pair_for_each_lane(
+                fx,
+                a,
+                b,
+                ret,
+                &|fx, _lane_ty, _res_lane_ty, a_lane, b_lane| {
+                    let lane_ty = fx.bcx.func.dfg.value_type(a_lane);
+                    let a_lane = fx.bcx.ins().uextend(types::I32, a_lane);
+                    let b_lane = fx.bcx.ins().uextend(types::I32, b_lane);
+                    let sum = fx.bcx.ins().iadd(a_lane, b_lane);
+                    let sum = fx.bcx.ins().iadd_imm(sum, 1);
+                    let avg = fx.bcx.ins().ushr_imm(sum, 1);
+                    fx.bcx.ins().ireduce(lane_ty, avg)
+                },
+            );
+        }
+        "llvm.x86.sse2.pmadd.wd" | "llvm.x86.avx2.pmadd.wd" => {
+            intrinsic_args!(fx, args => (a, b); intrinsic);
+
+            assert_eq!(a.layout(), b.layout());
+            let layout = a.layout();
+
+            let (lane_count, lane_ty) = layout.ty.simd_size_and_type(fx.tcx);
+            let (ret_lane_count, ret_lane_ty) = ret.layout().ty.simd_size_and_type(fx.tcx);
+            assert_eq!(lane_ty, fx.tcx.types.i16);
+            assert_eq!(ret_lane_ty, fx.tcx.types.i32);
+            assert_eq!(lane_count, ret_lane_count * 2);
+
+            let ret_lane_layout = fx.layout_of(fx.tcx.types.i32);
+            for out_lane_idx in 0..lane_count / 2 {
+                let a_lane0 = a.value_lane(fx, out_lane_idx * 2).load_scalar(fx);
+                let a_lane0 = fx.bcx.ins().sextend(types::I32, a_lane0);
+                let b_lane0 = b.value_lane(fx, out_lane_idx * 2).load_scalar(fx);
+                let b_lane0 = fx.bcx.ins().sextend(types::I32, b_lane0);
+
+                let a_lane1 = a.value_lane(fx, out_lane_idx * 2 + 1).load_scalar(fx);
+                let a_lane1 = fx.bcx.ins().sextend(types::I32, a_lane1);
+                let b_lane1 = b.value_lane(fx, out_lane_idx * 2 + 1).load_scalar(fx);
+                let b_lane1 = fx.bcx.ins().sextend(types::I32, b_lane1);
+
+                let mul0 = fx.bcx.ins().imul(a_lane0, b_lane0);
+                let mul1 = fx.bcx.ins().imul(a_lane1, b_lane1);
+
+                // Only `-32768 * -32768 * 2` overflows, which wraps on real hardware too.
+                let res_lane = fx.bcx.ins().iadd(mul0, mul1);
+                let res_lane = CValue::by_val(res_lane, ret_lane_layout);
+
+                ret.place_lane(fx, out_lane_idx).write_cvalue(fx, res_lane);
+            }
+        }
+        "llvm.x86.sse2.packsswb.128" | "llvm.x86.avx2.packsswb" => {
+            intrinsic_args!(fx, args => (a, b); intrinsic);
+            pack_instruction(fx, a, b, ret, PackSize::S8, PackWidth::from_intrinsic(intrinsic));
+        }
+        "llvm.x86.sse2.packuswb.128" | "llvm.x86.avx2.packuswb" => {
+            intrinsic_args!(fx, args => (a, b); intrinsic);
+            pack_instruction(fx, a, b, ret, PackSize::U8, PackWidth::from_intrinsic(intrinsic));
+        }
+        "llvm.x86.sse2.packssdw.128" | "llvm.x86.avx2.packssdw" => {
+            intrinsic_args!(fx, args => (a, b); intrinsic);
+            pack_instruction(fx, a, b, ret, PackSize::S16, PackWidth::from_intrinsic(intrinsic));
+        }
+        "llvm.x86.sse41.packusdw" | "llvm.x86.avx2.packusdw" => {
+            intrinsic_args!(fx, args => (a, b); intrinsic);
+            pack_instruction(fx, a, b, ret, PackSize::U16, PackWidth::from_intrinsic(intrinsic));
+        }
+        "llvm.x86.sse41.ptestz" | "llvm.x86.avx.ptestz.256" => {
+            // https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_testz_si128
+            intrinsic_args!(fx, args => (a, b); intrinsic);
 
-            simd_for_each_lane(fx, a, ret, &|fx, _lane_ty, _res_lane_ty, lane| match imm8
-                .try_to_bits(Size::from_bytes(4))
-                .unwrap_or_else(|| panic!("imm8 not scalar: {:?}", imm8))
-            {
-                imm8 if imm8 < 32 => fx.bcx.ins().ishl_imm(lane, i64::from(imm8 as u8)),
-                _ => fx.bcx.ins().iconst(types::I32, 0),
-            });
+            let (lane_count, _lane_ty) = a.layout().ty.simd_size_and_type(fx.tcx);
+            let mut zero_flag = fx.bcx.ins().iconst(types::I64, 0);
+            for lane in 0..lane_count {
+                let a_lane = a.value_lane(fx, lane).load_scalar(fx);
+                let b_lane = b.value_lane(fx, lane).load_scalar(fx);
+                let and = fx.bcx.ins().band(a_lane, b_lane);
+                zero_flag = fx.bcx.ins().bor(zero_flag, and);
+            }
+            let zero_flag = fx.bcx.ins().icmp_imm(IntCC::Equal, zero_flag, 0);
+            let res = fx.bcx.ins().uextend(types::I32, zero_flag);
+            ret.write_cvalue(fx, CValue::by_val(res, fx.layout_of(fx.tcx.types.i32)));
         }
-        "llvm.x86.avx2.psrli.w" => {
-            let (a, imm8) = match args {
-                [a, imm8] => (a, imm8),
-                _ => bug!("wrong number of args for intrinsic {intrinsic}"),
-            };
-            let a = codegen_operand(fx, a);
-            let imm8 = crate::constant::mir_operand_get_const_val(fx, imm8)
-                .expect("llvm.x86.avx.psrli.w imm8 not const");
+        "llvm.x86.sse41.ptestc" | "llvm.x86.avx.ptestc.256" => {
+            // https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_testc_si128
+            intrinsic_args!(fx, args => (a, b); intrinsic);
 
-            simd_for_each_lane(fx, a, ret, &|fx, _lane_ty, _res_lane_ty, lane| match imm8
-                .try_to_bits(Size::from_bytes(4))
-                .unwrap_or_else(|| panic!("imm8 not scalar: {:?}", imm8))
-            {
-                imm8 if imm8 < 16 => fx.bcx.ins().ushr_imm(lane, i64::from(imm8 as u8)),
-                _ => fx.bcx.ins().iconst(types::I32, 0),
-            });
+            let (lane_count, _lane_ty) = a.layout().ty.simd_size_and_type(fx.tcx);
+            let mut carry_flag = fx.bcx.ins().iconst(types::I64, 0);
+            for lane in 0..lane_count {
+                let a_lane = a.value_lane(fx, lane).load_scalar(fx);
+                let b_lane = b.value_lane(fx, lane).load_scalar(fx);
+                let and = fx.bcx.ins().band_not(b_lane, a_lane);
+                carry_flag = fx.bcx.ins().bor(carry_flag, and);
+            }
+            let carry_flag = fx.bcx.ins().icmp_imm(IntCC::Equal, carry_flag, 0);
+            let res = fx.bcx.ins().uextend(types::I32, carry_flag);
+            ret.write_cvalue(fx, CValue::by_val(res, fx.layout_of(fx.tcx.types.i32)));
         }
-        "llvm.x86.avx2.pslli.w" => {
-            let (a, imm8) = match args {
-                [a, imm8] => (a, imm8),
-                _ => bug!("wrong number of args for intrinsic {intrinsic}"),
-            };
-            let a = codegen_operand(fx, a);
-            let imm8 = crate::constant::mir_operand_get_const_val(fx, imm8)
-                .expect("llvm.x86.avx.pslli.w imm8 not const");
+        "llvm.x86.sse42.crc32.32.8"
+        | "llvm.x86.sse42.crc32.32.16"
+        | "llvm.x86.sse42.crc32.32.32"
+        | "llvm.x86.sse42.crc32.64.64" => {
+            // https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_crc32_u8
+            intrinsic_args!(fx, args => (crc, v); intrinsic);
+
+            let crc_ty = fx.clif_type(crc.layout().ty).unwrap();
+            let data_bits = v.layout().size.bits();
+            let crc = crc.load_scalar(fx);
+            let v = v.load_scalar(fx);
+
+            // Bitwise CRC32C (Castagnoli) using the reflected polynomial.
+            let v = clif_intcast(fx, v, crc_ty, false);
+            let mut crc = fx.bcx.ins().bxor(crc, v);
+            for _ in 0..data_bits {
+                let lsb = fx.bcx.ins().band_imm(crc, 1);
+                let mask = fx.bcx.ins().ineg(lsb);
+                let poly = fx.bcx.ins().band_imm(mask, 0x82F6_3B78);
+                let shifted = fx.bcx.ins().ushr_imm(crc, 1);
+                crc = fx.bcx.ins().bxor(shifted, poly);
+            }
 
-            simd_for_each_lane(fx, a, ret, &|fx, _lane_ty, _res_lane_ty, lane| match imm8
-                .try_to_bits(Size::from_bytes(4))
-                .unwrap_or_else(|| panic!("imm8 not scalar: {:?}", imm8))
-            {
-                imm8 if imm8 < 16 => fx.bcx.ins().ishl_imm(lane, i64::from(imm8 as u8)),
-                _ => fx.bcx.ins().iconst(types::I32, 0),
-            });
+            let res = CValue::by_val(crc, ret.layout());
+            ret.write_cvalue(fx, res);
         }
         "llvm.x86.ssse3.pshuf.b.128" | "llvm.x86.avx2.pshuf.b" => {
             let (a, b) = match args {
@@ -347,11 +402,157 @@ fn select4(
     fx.bcx.ins().jump(ret_block, &[]);
 }
 
-// llvm.x86.avx2.vperm2i128
-// llvm.x86.ssse3.pshuf.b.128
-// llvm.x86.avx2.pshuf.b
-// llvm.x86.avx2.psrli.w
-// llvm.x86.sse2.psrli.w
+#[derive(Copy, Clone)]
+enum ShiftKind {
+    Left,
+    LogicalRight,
+    ArithmeticRight,
+}
+
+/// Shifts every lane by a constant amount. Shifting by at least the lane width gives zero for
+/// logical shifts and fills the lane with its sign bit for arithmetic shifts.
+fn llvm_x86_shift_imm<'tcx>(
+    fx: &mut FunctionCx<'_, '_, 'tcx>,
+    intrinsic: &str,
+    args: &[mir::Operand<'tcx>],
+    ret: CPlace<'tcx>,
+    kind: ShiftKind,
+) {
+    let (a, imm8) = match args {
+        [a, imm8] => (a, imm8),
+        _ => bug!("wrong number of args for intrinsic {intrinsic}"),
+    };
+    let a = codegen_operand(fx, a);
+    let imm8 = crate::constant::mir_operand_get_const_val(fx, imm8)
+        .unwrap_or_else(|| panic!("{intrinsic} imm8 not const"));
+    let imm8 = imm8
+        .try_to_bits(Size::from_bytes(4))
+        .unwrap_or_else(|| panic!("imm8 not scalar: {:?}", imm8));
+
+    simd_for_each_lane(fx, a, ret, &|fx, _lane_ty, _res_lane_ty, lane| {
+        let lane_ty = fx.bcx.func.dfg.value_type(lane);
+        let lane_bits = u128::from(lane_ty.bits());
+        match kind {
+            ShiftKind::Left if imm8 < lane_bits => fx.bcx.ins().ishl_imm(lane, imm8 as i64),
+            ShiftKind::LogicalRight if imm8 < lane_bits => fx.bcx.ins().ushr_imm(lane, imm8 as i64),
+            ShiftKind::Left | ShiftKind::LogicalRight => fx.bcx.ins().iconst(lane_ty, 0),
+            ShiftKind::ArithmeticRight => {
+                fx.bcx.ins().sshr_imm(lane, imm8.min(lane_bits - 1) as i64)
+            }
+        }
+    });
+}
+
+#[derive(Copy, Clone)]
+enum PackSize {
+    U8,
+    U16,
+    S8,
+    S16,
+}
+
+impl PackSize {
+    fn ret_clif_type(self) -> Type {
+        match self {
+            Self::U8 | Self::S8 => types::I8,
+            Self::U16 | Self::S16 => types::I16,
+        }
+    }
+
+    fn src_clif_type(self) -> Type {
+        match self {
+            Self::U8 | Self::S8 => types::I16,
+            Self::U16 | Self::S16 => types::I32,
+        }
+    }
+
+    fn src_ty<'tcx>(self, tcx: TyCtxt<'tcx>) -> Ty<'tcx> {
+        match self {
+            Self::U8 | Self::S8 => tcx.types.i16,
+            Self::U16 | Self::S16 => tcx.types.i32,
+        }
+    }
+
+    fn ret_ty<'tcx>(self, tcx: TyCtxt<'tcx>) -> Ty<'tcx> {
+        match self {
+            Self::U8 => tcx.types.u8,
+            Self::S8 => tcx.types.i8,
+            Self::U16 => tcx.types.u16,
+            Self::S16 => tcx.types.i16,
+        }
+    }
+
+    fn max(self) -> i64 {
+        match self {
+            Self::U8 => u8::MAX.into(),
+            Self::S8 => i8::MAX.into(),
+            Self::U16 => u16::MAX.into(),
+            Self::S16 => i16::MAX.into(),
+        }
+    }
+
+    fn min(self) -> i64 {
+        match self {
+            Self::U8 | Self::U16 => 0,
+            Self::S8 => i8::MIN.into(),
+            Self::S16 => i16::MIN.into(),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum PackWidth {
+    Sse = 1,
+    Avx = 2,
+}
+
+impl PackWidth {
+    fn from_intrinsic(intrinsic: &str) -> Self {
+        if intrinsic.starts_with("llvm.x86.avx2.") { PackWidth::Avx } else { PackWidth::Sse }
+    }
+}
+
+/// Implement an x86 pack instruction with the intrinsic `_mm{,256}_pack{us,s}_epi{16,32}`.
+fn pack_instruction<'tcx>(
+    fx: &mut FunctionCx<'_, '_, 'tcx>,
+    a: CValue<'tcx>,
+    b: CValue<'tcx>,
+    ret: CPlace<'tcx>,
+    ret_size: PackSize,
+    width: PackWidth,
+) {
+    assert_eq!(a.layout(), b.layout());
+    let layout = a.layout();
+
+    let (src_lane_count, src_lane_ty) = layout.ty.simd_size_and_type(fx.tcx);
+    let (ret_lane_count, ret_lane_ty) = ret.layout().ty.simd_size_and_type(fx.tcx);
+    assert_eq!(src_lane_ty, ret_size.src_ty(fx.tcx));
+    assert_eq!(ret_lane_ty, ret_size.ret_ty(fx.tcx));
+    assert_eq!(src_lane_count * 2, ret_lane_count);
+
+    let min = fx.bcx.ins().iconst(ret_size.src_clif_type(), ret_size.min());
+    let max = fx.bcx.ins().iconst(ret_size.src_clif_type(), ret_size.max());
+    let ret_lane_layout = fx.layout_of(ret_size.ret_ty(fx.tcx));
+
+    // Each 128-bit half of the result takes the matching half of `a` followed by that of `b`.
+    let lanes_per_half = src_lane_count / width as u64;
+    let mut round = |source: CValue<'tcx>, source_idx: u64, dest_idx: u64| {
+        let mut lane = source.value_lane(fx, source_idx).load_scalar(fx);
+        lane = fx.bcx.ins().smax(lane, min);
+        lane = fx.bcx.ins().smin(lane, max);
+        lane = fx.bcx.ins().ireduce(ret_size.ret_clif_type(), lane);
+        let lane = CValue::by_val(lane, ret_lane_layout);
+        ret.place_lane(fx, dest_idx).write_cvalue(fx, lane);
+    };
+
+    for half in 0..width as u64 {
+        for idx in 0..lanes_per_half {
+            let src_idx = half * lanes_per_half + idx;
+            round(a, src_idx, half * lanes_per_half * 2 + idx);
+            round(b, src_idx, half * lanes_per_half * 2 + lanes_per_half + idx);
+        }
+    }
+}
 
 fn llvm_add_sub<'tcx>(
     fx: &mut FunctionCx<'_, '_, 'tcx>,
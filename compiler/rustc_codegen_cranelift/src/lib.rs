@@ -8,6 +8,7 @@
 #[macro_use]
 extern crate rustc_middle;
 extern crate rustc_ast;
+extern crate rustc_attr;
 extern crate rustc_codegen_ssa;
 extern crate rustc_data_structures;
 extern crate rustc_errors;
@@ -35,7 +36,7 @@
 use rustc_errors::ErrorGuaranteed;
 use rustc_metadata::EncodedMetadata;
 use rustc_middle::dep_graph::{WorkProduct, WorkProductId};
-use rustc_session::config::OutputFilenames;
+use rustc_session::config::{OptLevel, OutputFilenames};
 use rustc_session::Session;
 use rustc_span::Symbol;
 
@@ -133,6 +134,10 @@ struct CodegenCx {
     debug_context: Option<DebugContext>,
     unwind_context: UnwindContext,
     cgu_name: Symbol,
+    backend_config: BackendConfig,
+    /// Target isas for functions with an `#[optimize(level = "...")]` that needs a different
+    /// Cranelift `opt_level` than the rest of the crate, keyed by that `opt_level`.
+    opt_level_isas: FxHashMap<&'static str, Arc<dyn TargetIsa>>,
 }
 
 impl CodegenCx {
@@ -161,8 +166,29 @@ fn new(
             debug_context,
             unwind_context,
             cgu_name,
+            backend_config,
+            opt_level_isas: FxHashMap::default(),
         }
     }
+
+    /// Returns the target isa to compile a function with `#[optimize(level = "...")]` with, or
+    /// `None` if the isa of the module already uses the right Cranelift optimization level.
+    fn isa_for_opt_level(
+        &mut self,
+        sess: &Session,
+        opt_level: OptLevel,
+    ) -> Option<Arc<dyn TargetIsa>> {
+        let cranelift_opt_level = cranelift_opt_level(opt_level);
+        if cranelift_opt_level == crate::cranelift_opt_level(sess.opts.optimize) {
+            return None;
+        }
+        Some(
+            self.opt_level_isas
+                .entry(cranelift_opt_level)
+                .or_insert_with(|| build_isa_for_opt_level(sess, &self.backend_config, opt_level))
+                .clone(),
+        )
+    }
 }
 
 pub struct CraneliftCodegenBackend {
@@ -250,6 +276,22 @@ fn target_triple(sess: &Session) -> target_lexicon::Triple {
 }
 
 fn build_isa(sess: &Session, backend_config: &BackendConfig) -> Arc<dyn isa::TargetIsa + 'static> {
+    build_isa_for_opt_level(sess, backend_config, sess.opts.optimize)
+}
+
+/// The value of Cranelift's `opt_level` setting used for the given rustc optimization level.
+fn cranelift_opt_level(opt_level: OptLevel) -> &'static str {
+    match opt_level {
+        OptLevel::No | OptLevel::Less | OptLevel::Default => "none",
+        OptLevel::Size | OptLevel::SizeMin | OptLevel::Aggressive => "speed_and_size",
+    }
+}
+
+fn build_isa_for_opt_level(
+    sess: &Session,
+    backend_config: &BackendConfig,
+    opt_level: OptLevel,
+) -> Arc<dyn isa::TargetIsa + 'static> {
     use target_lexicon::BinaryFormat;
 
     let target_triple = crate::target_triple(sess);
@@ -272,16 +314,7 @@ fn build_isa(sess: &Session, backend_config: &BackendConfig) -> Arc<dyn isa::Tar
 
     flags_builder.set("enable_llvm_abi_extensions", "true").unwrap();
 
-    use rustc_session::config::OptLevel;
-    match sess.opts.optimize {
-        OptLevel::No => {
-            flags_builder.set("opt_level", "none").unwrap();
-        }
-        OptLevel::Less | OptLevel::Default => {}
-        OptLevel::Size | OptLevel::SizeMin | OptLevel::Aggressive => {
-            flags_builder.set("opt_level", "speed_and_size").unwrap();
-        }
-    }
+    flags_builder.set("opt_level", cranelift_opt_level(opt_level)).unwrap();
 
     if let target_lexicon::Architecture::Aarch64(_)
     | target_lexicon::Architecture::Riscv64(_)
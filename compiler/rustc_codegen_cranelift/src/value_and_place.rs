@@ -2,7 +2,10 @@
 
 use crate::prelude::*;
 
+use crate::debuginfo::VariableLocation;
+
 use cranelift_codegen::entity::EntityRef;
+use cranelift_codegen::ir::ValueLabel;
 use cranelift_codegen::ir::immediates::Offset32;
 
 fn codegen_field<'tcx>(
@@ -350,6 +353,34 @@ pub(crate) fn layout(&self) -> TyAndLayout<'tcx> {
         self.layout
     }
 
+    /// Returns where this place is stored for debuginfo, if it can be described.
+    pub(crate) fn debug_location(&self, tcx: TyCtxt<'tcx>) -> Option<VariableLocation> {
+        match self.inner {
+            CPlaceInner::Var(_local, var) => {
+                // FIXME describe `i128` values, which are split over two registers
+                if matches!(self.layout.abi, Abi::Scalar(scalar) if scalar.size(&tcx).bytes() > 8) {
+                    return None;
+                }
+                Some(VariableLocation::Ssa(vec![(debug_label(var), Size::ZERO, self.layout.size)]))
+            }
+            CPlaceInner::VarPair(_local, var1, var2) => {
+                let Abi::ScalarPair(a_scalar, b_scalar) = self.layout.abi else { unreachable!() };
+                let b_offset = a_scalar.size(&tcx).align_to(b_scalar.align(&tcx).abi);
+                Some(VariableLocation::Ssa(vec![
+                    (debug_label(var1), Size::ZERO, a_scalar.size(&tcx)),
+                    (debug_label(var2), b_offset, b_scalar.size(&tcx)),
+                ]))
+            }
+            CPlaceInner::Addr(ptr, None) => match ptr.debug_base_and_offset() {
+                (crate::pointer::PointerBase::Stack(stack_slot), offset) => {
+                    Some(VariableLocation::Stack(stack_slot, i64::from(offset)))
+                }
+                _ => None,
+            },
+            CPlaceInner::Addr(_, Some(_)) => None,
+        }
+    }
+
     pub(crate) fn new_stack_slot(
         fx: &mut FunctionCx<'_, '_, 'tcx>,
         layout: TyAndLayout<'tcx>,
@@ -421,14 +452,14 @@ pub(crate) fn to_cvalue(self, fx: &mut FunctionCx<'_, '_, 'tcx>) -> CValue<'tcx>
         match self.inner {
             CPlaceInner::Var(_local, var) => {
                 let val = fx.bcx.use_var(var);
-                //fx.bcx.set_val_label(val, cranelift_codegen::ir::ValueLabel::new(var.index()));
+                set_debug_label(fx, val, var);
                 CValue::by_val(val, layout)
             }
             CPlaceInner::VarPair(_local, var1, var2) => {
                 let val1 = fx.bcx.use_var(var1);
-                //fx.bcx.set_val_label(val1, cranelift_codegen::ir::ValueLabel::new(var1.index()));
+                set_debug_label(fx, val1, var1);
                 let val2 = fx.bcx.use_var(var2);
-                //fx.bcx.set_val_label(val2, cranelift_codegen::ir::ValueLabel::new(var2.index()));
+                set_debug_label(fx, val2, var2);
                 CValue::by_val_pair(val1, val2, layout)
             }
             CPlaceInner::Addr(ptr, extra) => {
@@ -555,7 +586,7 @@ fn transmute_scalar<'tcx>(
                 // calling `write_cvalue` you need to add a `bint` instruction.
                 _ => unreachable!("write_cvalue_transmute: {:?} -> {:?}", src_ty, dst_ty),
             };
-            //fx.bcx.set_val_label(data, cranelift_codegen::ir::ValueLabel::new(var.index()));
+            set_debug_label(fx, data, var);
             fx.bcx.def_var(var, data);
         }
 
@@ -785,6 +816,17 @@ pub(crate) fn downcast_variant(
     }
 }
 
+/// The value label used to track the locations of the values of an SSA variable for debuginfo.
+fn debug_label(var: Variable) -> ValueLabel {
+    ValueLabel::new(var.index())
+}
+
+fn set_debug_label(fx: &mut FunctionCx<'_, '_, '_>, val: Value, var: Variable) {
+    if fx.func_debug_cx.is_some() {
+        fx.bcx.set_val_label(val, debug_label(var));
+    }
+}
+
 #[track_caller]
 pub(crate) fn assert_assignable<'tcx>(
     fx: &FunctionCx<'_, '_, 'tcx>,
@@ -2,7 +2,7 @@
 
 use rustc_codegen_ssa::traits::*;
 use rustc_hir::def_id::DefId;
-use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
+use rustc_middle::middle::codegen_fn_attrs::{CodegenFnAttrFlags, CodegenFnAttrs};
 use rustc_middle::ty::{self, TyCtxt};
 use rustc_session::config::OptLevel;
 use rustc_span::symbol::sym;
@@ -15,7 +15,7 @@
 use crate::llvm::AttributePlace::Function;
 use crate::llvm::{self, AllocKindFlags, Attribute, AttributeKind, AttributePlace, MemoryEffects};
 use crate::llvm_util;
-pub use rustc_attr::{InlineAttr, InstructionSetAttr, OptimizeAttr};
+pub use rustc_attr::{InlineAttr, InstructionSetAttr, OptimizeAttr, OptimizeLevel};
 
 use crate::context::CodegenCx;
 use crate::value::Value;
@@ -270,6 +270,14 @@ pub(crate) fn default_optimisation_attrs<'ll>(
     attrs
 }
 
+/// Whether the function must not be optimized by LLVM.
+///
+/// A function with `#[optimize(level = "0")]` is placed into a module that is not optimized, but
+/// it must also stay unoptimized when that module is merged with others by LTO.
+fn is_optnone(codegen_fn_attrs: &CodegenFnAttrs) -> bool {
+    matches!(codegen_fn_attrs.optimize, OptimizeAttr::Level(OptimizeLevel::No))
+}
+
 fn create_alloc_family_attr(llcx: &llvm::Context) -> &llvm::Attribute {
     llvm::CreateAttrStringValue(llcx, "alloc-family", "__rust_alloc")
 }
@@ -289,19 +297,35 @@ pub fn from_fn_attrs<'ll, 'tcx>(
         OptimizeAttr::None => {
             to_add.extend(default_optimisation_attrs(cx));
         }
-        OptimizeAttr::Size => {
+        OptimizeAttr::Size | OptimizeAttr::Level(OptimizeLevel::SizeMin) => {
             to_add.push(llvm::AttributeKind::MinSize.create_attr(cx.llcx));
             to_add.push(llvm::AttributeKind::OptimizeForSize.create_attr(cx.llcx));
         }
-        OptimizeAttr::Speed => {}
+        OptimizeAttr::Level(OptimizeLevel::Size) => {
+            to_add.push(llvm::AttributeKind::OptimizeForSize.create_attr(cx.llcx));
+        }
+        OptimizeAttr::Speed
+        | OptimizeAttr::Level(
+            OptimizeLevel::No
+            | OptimizeLevel::Less
+            | OptimizeLevel::Default
+            | OptimizeLevel::Aggressive,
+        ) => {}
     }
 
-    let inline =
-        if codegen_fn_attrs.inline == InlineAttr::None && instance.def.requires_inline(cx.tcx) {
-            InlineAttr::Hint
-        } else {
-            codegen_fn_attrs.inline
-        };
+    let optnone = is_optnone(codegen_fn_attrs);
+    if optnone && cx.sess().target.arch != "amdgpu" {
+        to_add.push(llvm::AttributeKind::OptimizeNone.create_attr(cx.llcx));
+    }
+
+    let inline = if optnone {
+        // LLVM requires `optnone` functions to be `noinline`.
+        InlineAttr::Never
+    } else if codegen_fn_attrs.inline == InlineAttr::None && instance.def.requires_inline(cx.tcx) {
+        InlineAttr::Hint
+    } else {
+        codegen_fn_attrs.inline
+    };
     to_add.extend(inline_attr(cx, inline));
 
     // The `uwtable` attribute according to LLVM is:
@@ -15,8 +15,9 @@
 use crate::llvm::archive_ro::{ArchiveRO, Child};
 use crate::llvm::{self, ArchiveKind, LLVMMachineType, LLVMRustCOFFShortExport};
 use rustc_codegen_ssa::back::archive::{
-    get_native_object_symbols, try_extract_macho_fat_archive, ArArchiveBuilder,
-    ArchiveBuildFailure, ArchiveBuilder, ArchiveBuilderBuilder, UnknownArchiveKind,
+    get_native_object_symbols, sort_object_members, try_extract_macho_fat_archive,
+    ArArchiveBuilder, ArchiveBuildFailure, ArchiveBuilder, ArchiveBuilderBuilder,
+    UnknownArchiveKind,
 };
 
 use rustc_session::cstore::DllImport;
@@ -363,10 +364,9 @@ fn build_with_llvm(&mut self, output: &Path) -> io::Result<bool> {
                     Addition::File { path, name_in_archive } => {
                         let path = CString::new(path.to_str().unwrap())?;
                         let name = CString::new(name_in_archive.clone())?;
-                        members.push(llvm::LLVMRustArchiveMemberNew(
-                            path.as_ptr(),
-                            name.as_ptr(),
-                            None,
+                        members.push((
+                            name_in_archive.clone(),
+                            llvm::LLVMRustArchiveMemberNew(path.as_ptr(), name.as_ptr(), None),
                         ));
                         strings.push(path);
                         strings.push(name);
@@ -396,13 +396,18 @@ fn build_with_llvm(&mut self, output: &Path) -> io::Result<bool> {
                                 name.as_ptr(),
                                 Some(child.raw),
                             );
-                            members.push(m);
+                            members.push((child_name.to_owned(), m));
                             strings.push(name);
                         }
                     }
                 }
             }
 
+            if self.sess.opts.unstable_opts.deterministic {
+                sort_object_members(&mut members, |(name, _)| name.as_bytes());
+            }
+            let members: Vec<_> = members.into_iter().map(|(_, member)| member).collect();
+
             let r = llvm::LLVMRustWriteArchive(
                 dst.as_ptr(),
                 members.len() as libc::size_t,
@@ -104,7 +104,13 @@ pub fn create_informational_target_machine(sess: &Session) -> &'static mut llvm:
         .unwrap_or_else(|err| llvm_err(sess.diagnostic(), err).raise())
 }
 
-pub fn create_target_machine(tcx: TyCtxt<'_>, mod_name: &str) -> &'static mut llvm::TargetMachine {
+/// Creates the target machine of a module, which is configured for `opt_level` when given instead
+/// of the optimization level of the crate.
+pub fn create_target_machine(
+    tcx: TyCtxt<'_>,
+    mod_name: &str,
+    opt_level: Option<config::OptLevel>,
+) -> &'static mut llvm::TargetMachine {
     let split_dwarf_file = if tcx.sess.target_can_use_split_dwarf() {
         tcx.output_filenames(()).split_dwarf_path(
             tcx.sess.split_debuginfo(),
@@ -117,7 +123,7 @@ pub fn create_target_machine(tcx: TyCtxt<'_>, mod_name: &str) -> &'static mut ll
     let config = TargetMachineFactoryConfig { split_dwarf_file };
     target_machine_factory(
         &tcx.sess,
-        tcx.backend_optimization_level(()),
+        opt_level.unwrap_or_else(|| tcx.backend_optimization_level(())),
         tcx.global_backend_features(()),
     )(config)
     .unwrap_or_else(|err| llvm_err(tcx.sess.diagnostic(), err).raise())
@@ -204,7 +210,8 @@ pub fn target_machine_factory(
     let abi = SmallCStr::new(&sess.target.llvm_abiname);
     let trap_unreachable =
         sess.opts.unstable_opts.trap_unreachable.unwrap_or(sess.target.trap_unreachable);
-    let emit_stack_size_section = sess.opts.unstable_opts.emit_stack_sizes;
+    let emit_stack_size_section = sess.opts.unstable_opts.emit_stack_sizes
+        || sess.opts.unstable_opts.stack_usage_report.is_some();
 
     let asm_comments = sess.opts.unstable_opts.asm_comments;
     let relax_elf_relocations =
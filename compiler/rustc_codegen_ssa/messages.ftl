@@ -37,6 +37,12 @@ codegen_ssa_extract_bundled_libs_write_file = failed to write file '{$rlib}': {$
 
 codegen_ssa_failed_to_write = failed to write {$path}: {$error}
 
+codegen_ssa_failed_to_write_size_map = failed to write size map `{$path}`: {$error}
+
+codegen_ssa_failed_to_write_stack_usage_report = failed to write stack usage report `{$path}`: {$error}
+
+codegen_ssa_fuse_ld_not_found = the C compiler couldn't find the linker it was asked to use with `-fuse-ld`; make sure it is installed and in `PATH`
+
 codegen_ssa_ignoring_emit_path = ignoring emit path because multiple .{$extension} files were produced
 
 codegen_ssa_ignoring_output = ignoring -o because multiple .{$extension} files were produced
@@ -149,6 +155,8 @@ codegen_ssa_linker_file_stem = couldn't extract file stem from specified linker
 codegen_ssa_linker_not_found = linker `{$linker_path}` not found
     .note = {$error}
 
+codegen_ssa_linker_plugin_lto_unsupported = `-C linker-plugin-lto` requires a linker that can read LLVM bitcode, from the same or a newer LLVM version than the one used by rustc
+
 codegen_ssa_linker_unsupported_modifier = `as-needed` modifier not supported for current linker
 
 codegen_ssa_linking_failed = linking with `{$linker_path}` failed: {$exit_status}
@@ -191,6 +199,8 @@ codegen_ssa_rlib_only_rmeta_found = could not find rlib for: `{$crate_name}`, fo
 
 codegen_ssa_select_cpp_build_tool_workload = in the Visual Studio installer, ensure the "C++ build tools" workload is selected
 
+codegen_ssa_self_contained_mold = the `mold` linker is not distributed with rustc and cannot be used with `-C link-self-contained=+linker`
+
 codegen_ssa_shuffle_indices_evaluation = could not evaluate shuffle_indices at compile time
 
 codegen_ssa_specify_libraries_to_link = use the `-l` flag to specify native libraries to link
@@ -292,6 +302,18 @@ codegen_ssa_unable_to_run_dsymutil = unable to run `dsymutil`: {$error}
 
 codegen_ssa_unable_to_write_debugger_visualizer = Unable to write debugger visualizer file `{$path}`: {$error}
 
+codegen_ssa_undefined_symbol_native_lib = undefined symbol `{$symbol}` is declared by crate `{$krate}` in an `extern` block linked to the native library `{$lib}`; make sure this library is installed, provides the symbol, and can be found by the linker (e.g. with `-L` or the `cargo:rustc-link-search` directive)
+
+codegen_ssa_undefined_symbol_no_link_attribute = undefined symbol `{$symbol}` is declared by crate `{$krate}` in an `extern` block without a `#[link]` attribute; the native library providing it must be linked with `#[link(name = "...")]`, the `-l` flag, or the `cargo:rustc-link-lib` directive
+
+codegen_ssa_undefined_symbol_rust_item = undefined symbol `{$symbol}` is the Rust item `{$demangled}` from crate `{$krate}`; this can happen when linking artifacts of this crate that were built by a different compiler or with different options
+
+codegen_ssa_undefined_symbol_rust_item_unknown_crate = undefined symbol `{$symbol}` is the Rust item `{$demangled}`, from a crate that isn't part of the crate graph
+
+codegen_ssa_undefined_symbol_unknown = undefined symbol `{$symbol}` isn't declared in any `extern` block of the crate graph; it may come from a native library or object file passed to the linker
+
+codegen_ssa_undefined_symbols_omitted = ...and {$omitted_undefined_symbols} other undefined symbols
+
 codegen_ssa_unknown_archive_kind =
     Don't know how to build archive of type: {$kind}
 
@@ -301,6 +323,8 @@ codegen_ssa_unknown_atomic_ordering = unknown ordering in atomic intrinsic
 
 codegen_ssa_unsupported_arch = unsupported arch `{$arch}` for os `{$os}`
 
+codegen_ssa_unsupported_fuse_ld = the C compiler doesn't know how to use the `{$fuse_ld}` linker; a recent version of clang, or GCC 12.1 or later for `mold`, is required
+
 codegen_ssa_unsupported_link_self_contained = option `-C link-self-contained` is not supported on this target
 
 codegen_ssa_use_cargo_directive = use the `cargo:rustc-link-lib` directive to specify the native libraries to link with Cargo (see https://doc.rust-lang.org/cargo/reference/build-scripts.html#cargorustc-link-libkindname)
@@ -20,6 +20,9 @@
 // Re-exporting for rustc_codegen_llvm::back::archive
 pub use crate::errors::{ArchiveBuildFailure, ExtractBundledLibsError, UnknownArchiveKind};
 
+#[cfg(test)]
+mod tests;
+
 pub trait ArchiveBuilderBuilder {
     fn new_archive_builder<'a>(&self, sess: &'a Session) -> Box<dyn ArchiveBuilder<'a> + 'a>;
 
@@ -104,6 +107,33 @@ enum ArchiveEntry {
     File(PathBuf),
 }
 
+/// Sorts the object files of an archive by name, so that the layout of the archive doesn't
+/// depend on the order in which they were added. Used by `-Zdeterministic`.
+///
+/// Only consecutive object files are reordered: `link_rlib` places the other members, like the
+/// metadata and the bundled static libraries, before or after all the objects on purpose, and
+/// linkers infer the architecture of the archive from its first member. The sort is stable, so
+/// members with the same name keep their relative order.
+pub fn sort_object_members<T>(members: &mut [T], name: impl Fn(&T) -> &[u8]) {
+    let is_object = |member: &T| {
+        let name = name(member);
+        name.ends_with(b".o") || name.ends_with(b".obj")
+    };
+    let mut start = 0;
+    while start < members.len() {
+        if !is_object(&members[start]) {
+            start += 1;
+            continue;
+        }
+        let end = members[start..]
+            .iter()
+            .position(|member| !is_object(member))
+            .map_or(members.len(), |len| start + len);
+        members[start..end].sort_by(|a, b| name(a).cmp(name(b)));
+        start = end;
+    }
+}
+
 impl<'a> ArArchiveBuilder<'a> {
     pub fn new(
         sess: &'a Session,
@@ -227,7 +257,7 @@ fn build(self: Box<Self>, output: &Path) -> bool {
 }
 
 impl<'a> ArArchiveBuilder<'a> {
-    fn build_inner(self, output: &Path) -> io::Result<bool> {
+    fn build_inner(mut self, output: &Path) -> io::Result<bool> {
         let archive_kind = match &*self.sess.target.archive_format {
             "gnu" => ArchiveKind::Gnu,
             "bsd" => ArchiveKind::Bsd,
@@ -239,6 +269,10 @@ fn build_inner(self, output: &Path) -> io::Result<bool> {
             }
         };
 
+        if self.sess.opts.unstable_opts.deterministic {
+            sort_object_members(&mut self.entries, |(name, _)| name.as_slice());
+        }
+
         let mut entries = Vec::new();
 
         for (entry_name, entry) in self.entries {
@@ -0,0 +1,29 @@
+use super::sort_object_members;
+
+fn sorted(members: &[&str]) -> Vec<String> {
+    let mut members: Vec<String> = members.iter().map(|name| name.to_string()).collect();
+    sort_object_members(&mut members, |name| name.as_bytes());
+    members
+}
+
+#[test]
+fn sorts_objects() {
+    assert_eq!(sorted(&["b.o", "c.obj", "a.o"]), ["a.o", "b.o", "c.obj"]);
+}
+
+#[test]
+fn keeps_leading_metadata_and_trailing_members() {
+    assert_eq!(
+        sorted(&["lib.rmeta", "foo.cgu-1.rcgu.o", "foo.cgu-0.rcgu.o", "libbar.a", "libbaz.a"]),
+        ["lib.rmeta", "foo.cgu-0.rcgu.o", "foo.cgu-1.rcgu.o", "libbar.a", "libbaz.a"]
+    );
+    assert_eq!(sorted(&["z.o", "y.o", "lib.rmeta"]), ["y.o", "z.o", "lib.rmeta"]);
+}
+
+#[test]
+fn sorts_runs_of_objects_separately() {
+    assert_eq!(
+        sorted(&["d.o", "c.o", "x.txt", "b.o", "a.o"]),
+        ["c.o", "d.o", "x.txt", "a.o", "b.o"]
+    );
+}
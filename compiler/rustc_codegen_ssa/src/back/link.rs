@@ -12,7 +12,7 @@
 use rustc_middle::middle::debugger_visualizer::DebuggerVisualizerFile;
 use rustc_middle::middle::dependency_format::Linkage;
 use rustc_middle::middle::exported_symbols::SymbolExportKind;
-use rustc_session::config::{self, CFGuard, CrateType, DebugInfo, Strip};
+use rustc_session::config::{self, CFGuard, CrateType, DebugInfo, LdImpl, Strip};
 use rustc_session::config::{OutputFilenames, OutputType, PrintRequest, SplitDwarfKind};
 use rustc_session::cstore::DllImport;
 use rustc_session::output::{check_file_is_writeable, invalid_output_for_target, out_filename};
@@ -31,6 +31,9 @@
 use super::linker::{self, Linker};
 use super::metadata::{create_wrapper_file, MetadataPosition};
 use super::rpath::{self, RPathConfig};
+use super::size_map::emit_size_map;
+use super::stack_usage::emit_stack_usage_report;
+use super::undefined_symbols::{explain_undefined_symbol, parse_undefined_symbols};
 use crate::{
     errors, looks_like_rust_object_file, CodegenResults, CompiledModule, CrateInfo, NativeLib,
 };
@@ -138,6 +141,30 @@ pub fn link_binary<'a>(
                         codegen_results,
                         path.as_ref(),
                     )?;
+                    if let Some(size_map) = &sess.opts.unstable_opts.emit_size_map {
+                        let size_map = report_path(sess, size_map, crate_type);
+                        if let Err(error) =
+                            emit_size_map(&codegen_results.crate_info, &out_filename, &size_map)
+                        {
+                            sess.emit_err(errors::FailedToWriteSizeMap {
+                                path: &size_map,
+                                error: error.to_string(),
+                            });
+                        }
+                    }
+                    if let Some(report) = &sess.opts.unstable_opts.stack_usage_report {
+                        let report = report_path(sess, report, crate_type);
+                        if let Err(error) = emit_stack_usage_report(
+                            &codegen_results.crate_info,
+                            &out_filename,
+                            &report,
+                        ) {
+                            sess.emit_err(errors::FailedToWriteStackUsageReport {
+                                path: &report,
+                                error: error.to_string(),
+                            });
+                        }
+                    }
                 }
             }
             if sess.opts.json_artifact_notifications {
@@ -908,12 +935,26 @@ fn is_illegal_instruction(_status: &ExitStatus) -> bool {
                 let mut output = prog.stderr.clone();
                 output.extend_from_slice(&prog.stdout);
                 let escaped_output = escape_linker_output(&output, flavor);
+                // Only explain the first few undefined symbols, the rest is usually caused by the
+                // same missing library.
+                const MAX_EXPLAINED_UNDEFINED_SYMBOLS: usize = 10;
+                let undefined_symbols = parse_undefined_symbols(&escaped_output);
+                let omitted_undefined_symbols =
+                    undefined_symbols.len().saturating_sub(MAX_EXPLAINED_UNDEFINED_SYMBOLS);
+                let undefined_symbols = undefined_symbols
+                    .into_iter()
+                    .take(MAX_EXPLAINED_UNDEFINED_SYMBOLS)
+                    .map(|symbol| explain_undefined_symbol(&codegen_results.crate_info, symbol))
+                    .collect();
                 // FIXME: Add UI tests for this error.
                 let err = errors::LinkingFailed {
                     linker_path: &linker_path,
                     exit_status: prog.status,
                     command: &cmd,
                     escaped_output,
+                    linker_plugin_lto: sess.opts.cg.linker_plugin_lto.enabled(),
+                    undefined_symbols,
+                    omitted_undefined_symbols,
                 };
                 sess.diagnostic().emit_err(err);
                 // If MSVC's `link.exe` was expected but the return code
@@ -1270,6 +1311,27 @@ pub fn ignored_for_lto(sess: &Session, info: &CrateInfo, cnum: CrateNum) -> bool
         && (info.compiler_builtins == Some(cnum) || info.is_no_builtins.contains(&cnum))
 }
 
+/// The file `-Zemit-size-map` or `-Zstack-usage-report` write their report about the `crate_type`
+/// output to. When several outputs are linked, the crate type is added to the file name to tell
+/// their reports apart.
+fn report_path(sess: &Session, path: &Path, crate_type: CrateType) -> PathBuf {
+    let linked_outputs = sess
+        .crate_types()
+        .iter()
+        .filter(|crate_type| !matches!(crate_type, CrateType::Rlib | CrateType::Staticlib))
+        .count();
+    if linked_outputs <= 1 {
+        return path.to_path_buf();
+    }
+    let mut file_name = path.file_stem().unwrap_or_default().to_os_string();
+    file_name.push(format!(".{crate_type}"));
+    if let Some(extension) = path.extension() {
+        file_name.push(".");
+        file_name.push(extension);
+    }
+    path.with_file_name(file_name)
+}
+
 /// This functions tries to determine the appropriate linker (and corresponding LinkerFlavor) to use
 pub fn linker_and_flavor(sess: &Session) -> (PathBuf, LinkerFlavor) {
     fn infer_from(
@@ -1302,6 +1364,8 @@ fn infer_from(
                     | LinkerFlavor::Darwin(_, Lld::Yes)
                     | LinkerFlavor::WasmLld(..)
                     | LinkerFlavor::Msvc(Lld::Yes) => "lld",
+                    // The `gnu-mold` flavor invokes `mold` directly.
+                    LinkerFlavor::Gnu(..) if uses_mold(sess) => "mold",
                     LinkerFlavor::Gnu(..) | LinkerFlavor::Darwin(..) | LinkerFlavor::Unix(..) => {
                         "ld"
                     }
@@ -1689,14 +1753,21 @@ fn detect_self_contained_mingw(sess: &Session) -> bool {
 /// We only provide such support for a very limited number of targets.
 fn self_contained(sess: &Session, crate_type: CrateType) -> bool {
     if let Some(self_contained) = sess.opts.cg.link_self_contained.explicitly_set {
-        if sess.target.link_self_contained == LinkSelfContainedDefault::False {
-            sess.emit_err(errors::UnsupportedLinkSelfContained);
+        match sess.target.link_self_contained {
+            LinkSelfContainedDefault::False => {
+                sess.emit_err(errors::UnsupportedLinkSelfContained);
+            }
+            // Only the linker is distributed for these targets, it can still be turned off.
+            LinkSelfContainedDefault::Linker if self_contained => {
+                sess.emit_err(errors::UnsupportedLinkSelfContained);
+            }
+            _ => {}
         }
         return self_contained;
     }
 
     match sess.target.link_self_contained {
-        LinkSelfContainedDefault::False => false,
+        LinkSelfContainedDefault::False | LinkSelfContainedDefault::Linker => false,
         LinkSelfContainedDefault::True => true,
         // FIXME: Find a better heuristic for "native musl toolchain is available",
         // based on host and linker path, for example.
@@ -2949,20 +3020,58 @@ fn get_apple_sdk_root(sdk_name: &str) -> Result<String, errors::AppleSdkRootErro
     }
 }
 
+/// Returns whether the `mold` linker was requested, either with the unstable `gnu-mold` and
+/// `gnu-mold-cc` linker flavors, or with `-Zgcc-ld=mold`.
+fn uses_mold(sess: &Session) -> bool {
+    sess.opts.cg.linker_flavor.is_some_and(|flavor| flavor.uses_mold())
+        || matches!(sess.opts.unstable_opts.gcc_ld, Some(LdImpl::Mold))
+}
+
+/// Returns whether the build of `lld` distributed with rustc should be used: either because it
+/// was requested with `-Clink-self-contained`, or because the target uses it by default and it
+/// wasn't disabled.
+fn self_contained_linker(sess: &Session) -> bool {
+    let link_self_contained = &sess.opts.cg.link_self_contained;
+    link_self_contained.linker()
+        || (sess.target.link_self_contained == LinkSelfContainedDefault::Linker
+            && !link_self_contained.is_linker_disabled())
+}
+
 /// When using the linker flavors opting in to `lld`, or the unstable `-Zgcc-ld=lld` flag, add the
 /// necessary paths and arguments to invoke it:
 /// - when the self-contained linker flag is active: the build of `lld` distributed with rustc,
 /// - or any `lld` available to `cc`.
+///
+/// The unstable `gnu-mold-cc` linker flavor and `-Zgcc-ld=mold` flag similarly ask `cc` to use the
+/// `mold` linker, which we don't distribute and is looked up by `cc` itself.
 fn add_lld_args(cmd: &mut dyn Linker, sess: &Session, flavor: LinkerFlavor) {
-    let unstable_use_lld = sess.opts.unstable_opts.gcc_ld.is_some();
-    debug!("add_lld_args requested, flavor: '{flavor:?}', `-Zgcc-ld=lld`: {unstable_use_lld}");
+    let gcc_ld = sess.opts.unstable_opts.gcc_ld;
+    let unstable_use_lld = matches!(gcc_ld, Some(LdImpl::Lld));
+    let use_mold = uses_mold(sess);
+    debug!(
+        "add_lld_args requested, flavor: '{flavor:?}', `-Zgcc-ld=lld`: {unstable_use_lld}, \
+         mold: {use_mold}"
+    );
 
-    // Sanity check: using the old unstable `-Zgcc-ld=lld` option requires a `cc`-using flavor.
+    // Sanity check: using the old unstable `-Zgcc-ld` option requires a `cc`-using flavor.
     let flavor_uses_cc = flavor.uses_cc();
-    if unstable_use_lld && !flavor_uses_cc {
+    if gcc_ld.is_some() && !flavor_uses_cc {
         sess.emit_fatal(errors::OptionGccOnly);
     }
 
+    // `mold` is not distributed with rustc: requesting it takes precedence over the target's
+    // `LinkSelfContainedDefault::Linker` default, but can't be combined with an explicit
+    // `-Clink-self-contained=+linker`.
+    if use_mold {
+        if sess.opts.cg.link_self_contained.linker() {
+            sess.emit_err(errors::SelfContainedMold);
+        }
+        if flavor_uses_cc {
+            cmd.arg("-fuse-ld=mold");
+        }
+        return;
+    }
+
     // If the flavor doesn't use a C/C++ compiler to invoke the linker, or doesn't opt in to `lld`,
     // we don't need to do anything.
     let use_lld = flavor.uses_lld() || unstable_use_lld;
@@ -2972,7 +3081,7 @@ fn add_lld_args(cmd: &mut dyn Linker, sess: &Session, flavor: LinkerFlavor) {
 
     // 1. Implement the "self-contained" part of this feature by adding rustc distribution
     //    directories to the tool's search path.
-    let self_contained_linker = sess.opts.cg.link_self_contained.linker() || unstable_use_lld;
+    let self_contained_linker = self_contained_linker(sess) || unstable_use_lld;
     if self_contained_linker {
         for path in sess.get_tools_search_paths(false) {
             cmd.arg({
@@ -5,5 +5,8 @@
 pub mod lto;
 pub mod metadata;
 pub mod rpath;
+pub mod size_map;
+pub mod stack_usage;
 pub mod symbol_export;
+pub mod undefined_symbols;
 pub mod write;
@@ -0,0 +1,179 @@
+//! `-Zemit-size-map`: a report of the size of every symbol in a linked binary, attributed to the
+//! crate and generic definition it comes from.
+
+use object::{Object, ObjectSection, ObjectSymbol, SectionIndex, SectionKind, SymbolKind};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::memmap::Mmap;
+use serde_json::json;
+
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::CrateInfo;
+
+struct SizedSymbol<'data> {
+    name: &'data str,
+    is_text: bool,
+    size: u64,
+}
+
+/// Reads the symbols of the linked binary at `binary` and writes their sizes and origins as JSON
+/// to `path`.
+pub fn emit_size_map(
+    crate_info: &CrateInfo,
+    binary: &Path,
+    path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let file = File::open(binary)?;
+    // SAFETY: the binary was just written by the linker and isn't modified while we read it.
+    let data = unsafe { Mmap::map(file) }?;
+    let object = object::File::parse(&*data)?;
+    let symbols = sized_symbols(&object);
+
+    #[derive(Default)]
+    struct CrateSize {
+        text: u64,
+        data: u64,
+    }
+
+    let mut crate_sizes: FxHashMap<String, CrateSize> = Default::default();
+    let mut entries = Vec::with_capacity(symbols.len());
+    for symbol in &symbols {
+        // Apple targets prefix symbols with an underscore.
+        let origin = crate_info
+            .symbol_origins
+            .get(symbol.name)
+            .or_else(|| crate_info.symbol_origins.get(symbol.name.strip_prefix('_')?));
+        let (krate, definition, instance) = match origin {
+            Some(origin) => (
+                origin.krate.to_string(),
+                Some(origin.definition.clone()),
+                Some(origin.instance.clone()),
+            ),
+            // Symbols defined by upstream crates or native code: we only know about what their
+            // name tells us.
+            None => (
+                crate_of_symbol(symbol.name).unwrap_or_else(|| "[unknown]".to_string()),
+                None,
+                None,
+            ),
+        };
+
+        let crate_size = crate_sizes.entry(krate.clone()).or_default();
+        if symbol.is_text {
+            crate_size.text += symbol.size;
+        } else {
+            crate_size.data += symbol.size;
+        }
+
+        entries.push(json!({
+            "symbol": symbol.name,
+            "demangled": demangle(symbol.name),
+            "kind": if symbol.is_text { "text" } else { "data" },
+            "size": symbol.size,
+            "crate": krate,
+            "definition": definition,
+            "instance": instance,
+        }));
+    }
+
+    let mut crates: Vec<_> = crate_sizes.into_iter().collect();
+    crates.sort_unstable_by(|(a_name, a), (b_name, b)| {
+        (b.text + b.data).cmp(&(a.text + a.data)).then_with(|| a_name.cmp(b_name))
+    });
+    let crates: Vec<_> = crates
+        .into_iter()
+        .map(|(name, size)| json!({ "name": name, "text": size.text, "data": size.data }))
+        .collect();
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(
+        &mut file,
+        &json!({
+            "binary": binary.display().to_string(),
+            "crates": crates,
+            "symbols": entries,
+        }),
+    )?;
+    file.flush()?;
+
+    Ok(())
+}
+
+/// Returns the defined text and data symbols of `object`, from the largest to the smallest.
+///
+/// Symbols without a size, like in Mach-O binaries, are assumed to extend up to the next symbol
+/// of their section, or the end of the section.
+fn sized_symbols<'data>(object: &object::File<'data>) -> Vec<SizedSymbol<'data>> {
+    let mut symbols_per_section: FxHashMap<SectionIndex, Vec<_>> = Default::default();
+    for symbol in object.symbols() {
+        if !symbol.is_definition() || !matches!(symbol.kind(), SymbolKind::Text | SymbolKind::Data)
+        {
+            continue;
+        }
+        let Some(section_index) = symbol.section_index() else { continue };
+        if symbol.name().is_err() {
+            continue;
+        }
+        symbols_per_section.entry(section_index).or_default().push(symbol);
+    }
+
+    let mut sized_symbols = Vec::new();
+    for (section_index, mut symbols) in symbols_per_section {
+        let Ok(section) = object.section_by_index(section_index) else { continue };
+        let is_text = match section.kind() {
+            SectionKind::Text => true,
+            SectionKind::Data
+            | SectionKind::ReadOnlyData
+            | SectionKind::ReadOnlyString
+            | SectionKind::UninitializedData
+            | SectionKind::Tls
+            | SectionKind::UninitializedTls => false,
+            _ => continue,
+        };
+        let section_end = section.address() + section.size();
+
+        symbols.sort_by_key(|symbol| symbol.address());
+        for (i, symbol) in symbols.iter().enumerate() {
+            let size = if symbol.size() != 0 {
+                symbol.size()
+            } else {
+                let next_address = symbols[i + 1..]
+                    .iter()
+                    .map(|next| next.address())
+                    .find(|&address| address > symbol.address())
+                    .unwrap_or(section_end);
+                next_address.saturating_sub(symbol.address())
+            };
+            if size == 0 {
+                continue;
+            }
+            // Names were checked to be valid when grouping symbols by section.
+            sized_symbols.push(SizedSymbol { name: symbol.name().unwrap(), is_text, size });
+        }
+    }
+
+    sized_symbols.sort_unstable_by(|a, b| b.size.cmp(&a.size).then_with(|| a.name.cmp(b.name)));
+    sized_symbols
+}
+
+pub(super) fn demangle(symbol: &str) -> Option<String> {
+    // Apple targets prefix symbols with an underscore.
+    rustc_demangle::try_demangle(symbol)
+        .or_else(|_| rustc_demangle::try_demangle(symbol.strip_prefix('_').unwrap_or(symbol)))
+        .ok()
+        .map(|demangled| format!("{demangled:#}"))
+}
+
+/// Guesses the crate defining a Rust symbol from the first segment of its demangled path.
+fn crate_of_symbol(symbol: &str) -> Option<String> {
+    let demangled = demangle(symbol)?;
+    // Trait impls are demangled as `<crate::Type as crate::Trait>::method`.
+    let path = demangled.trim_start_matches('<');
+    let krate = path.split("::").next()?;
+    Some(krate.to_string())
+}
@@ -0,0 +1,282 @@
+//! `-Zstack-usage-report`: the worst-case stack usage of every function of the local crate,
+//! computed from the frame sizes LLVM records in the `.stack_sizes` section of ELF binaries and
+//! the call graph of the monomorphized MIR.
+
+use object::{Object, ObjectSection, ObjectSymbol, SymbolKind};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::memmap::Mmap;
+use rustc_data_structures::stack::ensure_sufficient_stack;
+use serde_json::json;
+
+use std::collections::BTreeSet;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use super::size_map::demangle;
+use crate::{CallGraphNode, CrateInfo};
+
+/// Reads the frame sizes of the functions of the linked binary at `binary`, and writes the
+/// worst-case stack usage of the local crate's functions as JSON to `path`.
+pub fn emit_stack_usage_report(
+    crate_info: &CrateInfo,
+    binary: &Path,
+    path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let file = File::open(binary)?;
+    // SAFETY: the binary was just written by the linker and isn't modified while we read it.
+    let data = unsafe { Mmap::map(file) }?;
+    let object = object::File::parse(&*data)?;
+    let frame_sizes = frame_sizes(&object)?;
+
+    // Functions of the call graph that aren't in the binary were inlined everywhere or removed.
+    let functions: Vec<&str> = crate_info
+        .call_graph
+        .keys()
+        .map(|symbol| symbol.as_str())
+        .filter(|symbol| frame_sizes.contains_key(symbol))
+        .collect();
+    // Include the calls made by inlined functions, which were made from their callers.
+    let called: FxHashSet<&str> = crate_info
+        .call_graph
+        .values()
+        .flat_map(|node| &node.callees)
+        .map(|callee| callee.as_str())
+        .collect();
+
+    let mut analysis = Analysis {
+        call_graph: &crate_info.call_graph,
+        frame_sizes: &frame_sizes,
+        states: Default::default(),
+    };
+    let mut entries: Vec<_> = functions
+        .into_iter()
+        .map(|symbol| (symbol, !called.contains(symbol), analysis.stack_usage(symbol)))
+        .collect();
+    entries.sort_unstable_by(|(a_symbol, a_entry, a), (b_symbol, b_entry, b)| {
+        b_entry.cmp(a_entry).then(b.stack.cmp(&a.stack)).then_with(|| a_symbol.cmp(b_symbol))
+    });
+
+    let name = |symbol: &str| demangle(symbol).unwrap_or_else(|| symbol.to_string());
+    let entries: Vec<_> = entries
+        .into_iter()
+        .map(|(symbol, entry_point, usage)| {
+            let reasons: Vec<_> = usage
+                .unbounded_reasons
+                .iter()
+                .map(|reason| match *reason {
+                    UnboundedReason::Recursion { function } => {
+                        json!({ "kind": "recursion", "function": name(function) })
+                    }
+                    UnboundedReason::IndirectCall { function } => {
+                        json!({ "kind": "indirect_call", "function": name(function) })
+                    }
+                    UnboundedReason::InlineAsm { function } => {
+                        json!({ "kind": "inline_asm", "function": name(function) })
+                    }
+                    UnboundedReason::MissingStackSize { function } => {
+                        json!({ "kind": "missing_stack_size", "function": name(function) })
+                    }
+                    UnboundedReason::UnknownCallees { function } => {
+                        json!({ "kind": "unknown_callees", "function": name(function) })
+                    }
+                })
+                .collect();
+            json!({
+                "symbol": symbol,
+                "demangled": demangle(symbol),
+                "frame": frame_sizes[symbol],
+                "stack": usage.stack,
+                "bounded": reasons.is_empty(),
+                "entry_point": entry_point,
+                "deepest_path": usage.deepest_path.iter().map(|&f| name(f)).collect::<Vec<_>>(),
+                "unbounded_reasons": reasons,
+            })
+        })
+        .collect();
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(
+        &mut file,
+        &json!({ "binary": binary.display().to_string(), "functions": entries }),
+    )?;
+    file.flush()?;
+
+    Ok(())
+}
+
+/// Why the stack usage of a function may exceed the computed one.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum UnboundedReason<'a> {
+    /// `function` is part of a cycle of calls.
+    Recursion { function: &'a str },
+    /// `function` calls through a function pointer or a vtable.
+    IndirectCall { function: &'a str },
+    /// `function` contains inline assembly which may use the stack.
+    InlineAsm { function: &'a str },
+    /// `function` has no entry in `.stack_sizes`, e.g. because it was written in another
+    /// language or it comes from a crate built without `.stack_sizes`.
+    MissingStackSize { function: &'a str },
+    /// `function` comes from another crate, so its callees are unknown. This includes the functions
+    /// of other crates that were inlined everywhere.
+    UnknownCallees { function: &'a str },
+}
+
+#[derive(Clone)]
+struct StackUsage<'a> {
+    /// The stack usage of the deepest known chain of calls, in bytes.
+    stack: u64,
+    /// The functions of that chain, starting with the function itself.
+    deepest_path: Vec<&'a str>,
+    /// Empty if `stack` is the worst-case stack usage.
+    unbounded_reasons: BTreeSet<UnboundedReason<'a>>,
+}
+
+enum State<'a> {
+    InProgress,
+    Done(StackUsage<'a>),
+}
+
+struct Analysis<'a> {
+    call_graph: &'a FxHashMap<String, CallGraphNode>,
+    frame_sizes: &'a FxHashMap<&'a str, Option<u64>>,
+    states: FxHashMap<&'a str, State<'a>>,
+}
+
+impl<'a> Analysis<'a> {
+    /// Computes the stack usage of `symbol`, a function of the call graph.
+    ///
+    /// A function which isn't defined in the binary was inlined into all of its callers: its frame
+    /// is part of theirs, but the functions it calls still add to their stack usage.
+    fn stack_usage(&mut self, symbol: &'a str) -> StackUsage<'a> {
+        if let Some(State::Done(usage)) = self.states.get(symbol) {
+            return usage.clone();
+        }
+        self.states.insert(symbol, State::InProgress);
+
+        let call_graph = self.call_graph;
+        let node = &call_graph[symbol];
+        let mut unbounded_reasons = BTreeSet::new();
+        let frame = match self.frame_sizes.get(symbol) {
+            Some(&Some(frame)) => frame,
+            Some(None) => {
+                unbounded_reasons.insert(UnboundedReason::MissingStackSize { function: symbol });
+                0
+            }
+            None => 0,
+        };
+        if node.indirect_calls {
+            unbounded_reasons.insert(UnboundedReason::IndirectCall { function: symbol });
+        }
+        if node.inline_asm {
+            unbounded_reasons.insert(UnboundedReason::InlineAsm { function: symbol });
+        }
+
+        let mut deepest: Option<StackUsage<'a>> = None;
+        for callee in &node.callees {
+            let callee_usage = if call_graph.contains_key(callee) {
+                if let Some(State::InProgress) = self.states.get(callee.as_str()) {
+                    unbounded_reasons.insert(UnboundedReason::Recursion { function: symbol });
+                    continue;
+                }
+                ensure_sufficient_stack(|| self.stack_usage(callee))
+            } else {
+                // A function of another crate: even when it was inlined and isn't in the binary,
+                // the functions it calls are unknown.
+                let function = callee.as_str();
+                let callee_frame = self.frame_sizes.get(function).copied();
+                let reason = match callee_frame {
+                    Some(None) => UnboundedReason::MissingStackSize { function },
+                    Some(Some(_)) | None => UnboundedReason::UnknownCallees { function },
+                };
+                StackUsage {
+                    stack: callee_frame.flatten().unwrap_or(0),
+                    deepest_path: vec![function],
+                    unbounded_reasons: BTreeSet::from([reason]),
+                }
+            };
+            unbounded_reasons.extend(callee_usage.unbounded_reasons.iter().copied());
+            if deepest.as_ref().map_or(true, |deepest| callee_usage.stack > deepest.stack) {
+                deepest = Some(callee_usage);
+            }
+        }
+
+        let (callee_stack, mut deepest_path) =
+            deepest.map_or((0, Vec::new()), |deepest| (deepest.stack, deepest.deepest_path));
+        deepest_path.insert(0, symbol);
+        let usage = StackUsage { stack: frame + callee_stack, deepest_path, unbounded_reasons };
+        self.states.insert(symbol, State::Done(usage.clone()));
+        usage
+    }
+}
+
+/// Returns the frame size of every function defined in `object`, or `None` for the functions
+/// without an entry in `.stack_sizes`.
+///
+/// Each entry of `.stack_sizes` is the address of a function followed by its frame size as an
+/// ULEB128. The linker has resolved the addresses, so they can be matched to the symbol table.
+fn frame_sizes<'data>(
+    object: &object::File<'data>,
+) -> Result<FxHashMap<&'data str, Option<u64>>, Box<dyn std::error::Error>> {
+    let mut frame_sizes = FxHashMap::default();
+    let mut functions_by_address: FxHashMap<u64, Vec<&str>> = Default::default();
+    for symbol in object.symbols() {
+        if !symbol.is_definition() || symbol.kind() != SymbolKind::Text {
+            continue;
+        }
+        let Ok(name) = symbol.name() else { continue };
+        frame_sizes.insert(name, None);
+        functions_by_address.entry(symbol.address()).or_default().push(name);
+    }
+
+    let address_size = if object.is_64() { 8 } else { 4 };
+    let mut found_section = false;
+    for section in object.sections().filter(|section| section.name() == Ok(".stack_sizes")) {
+        found_section = true;
+        let data = section.data()?;
+        let mut offset = 0;
+        while offset < data.len() {
+            let bytes = data
+                .get(offset..offset + address_size)
+                .ok_or("truncated `.stack_sizes` section")?;
+            offset += address_size;
+            let mut address = [0; 8];
+            let address = if object.is_little_endian() {
+                address[..address_size].copy_from_slice(bytes);
+                u64::from_le_bytes(address)
+            } else {
+                address[8 - address_size..].copy_from_slice(bytes);
+                u64::from_be_bytes(address)
+            };
+            let frame_size = read_uleb128(data, &mut offset)?;
+            for &name in functions_by_address.get(&address).into_iter().flatten() {
+                frame_sizes.insert(name, Some(frame_size));
+            }
+        }
+    }
+    if !found_section {
+        return Err("the binary has no `.stack_sizes` section, is it an ELF binary?".into());
+    }
+
+    Ok(frame_sizes)
+}
+
+fn read_uleb128(data: &[u8], offset: &mut usize) -> Result<u64, &'static str> {
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte = *data.get(*offset).ok_or("truncated `.stack_sizes` section")?;
+        *offset += 1;
+        if shift >= 64 {
+            return Err("invalid frame size in `.stack_sizes` section");
+        }
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
@@ -0,0 +1,110 @@
+//! Recognizes the undefined symbol errors reported by common linkers, and explains which crate
+//! and native library each missing symbol is expected to come from.
+
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::LOCAL_CRATE;
+use rustc_span::Symbol;
+
+use super::size_map::demangle;
+use crate::errors::UndefinedSymbolNote;
+use crate::{CrateInfo, ForeignItemSource};
+
+#[cfg(test)]
+mod tests;
+
+/// Returns the undefined symbols reported in the output of GNU ld, lld, mold, ld64 or `link.exe`,
+/// deduplicated and in the order they were first reported.
+pub fn parse_undefined_symbols(output: &str) -> Vec<&str> {
+    let mut seen = FxHashSet::default();
+    output
+        .lines()
+        .filter_map(parse_undefined_symbol)
+        .filter(|symbol| seen.insert(*symbol))
+        .collect()
+}
+
+fn parse_undefined_symbol(line: &str) -> Option<&str> {
+    // GNU ld: "main.c:(.text+0x5): undefined reference to `foo'"
+    if let Some((_, rest)) = line.split_once("undefined reference to ") {
+        let rest = rest.trim_start_matches(['`', '\'', '‘']);
+        let end = rest.find(['\'', '’'])?;
+        return Some(&rest[..end]);
+    }
+
+    // lld, lld-link and mold: "ld.lld: error: undefined symbol: foo". Older versions of mold also
+    // print the object file referencing the symbol: "mold: error: undefined symbol: main.o: foo".
+    if let Some((_, rest)) = line.split_once("undefined symbol: ") {
+        return rest.rsplit(": ").next().map(str::trim).filter(|symbol| !symbol.is_empty());
+    }
+
+    // link.exe: "main.obj : error LNK2019: unresolved external symbol foo referenced in function
+    // main"
+    if let Some((_, rest)) = line.split_once("unresolved external symbol ") {
+        return rest.split_whitespace().next();
+    }
+
+    // ld64: `  "_foo", referenced from:`
+    let (symbol, _) = line.trim_start().strip_prefix('"')?.split_once("\", referenced from:")?;
+    Some(symbol)
+}
+
+/// Explains where an undefined symbol is expected to come from, using the `extern` blocks and
+/// crates of the crate graph.
+pub fn explain_undefined_symbol(info: &CrateInfo, symbol: &str) -> UndefinedSymbolNote {
+    let crate_name =
+        |cnum| if cnum == LOCAL_CRATE { info.local_crate_name } else { info.crate_name[&cnum] };
+
+    // Apple targets and 32-bit Windows prefix the symbols of C functions with an underscore.
+    let source = info.foreign_item_sources.get(&Symbol::intern(symbol)).or_else(|| {
+        let unprefixed = symbol.strip_prefix('_')?;
+        info.foreign_item_sources.get(&Symbol::intern(unprefixed))
+    });
+    let symbol = symbol.to_string();
+    match source {
+        Some(&ForeignItemSource { krate, native_lib: Some(lib) }) => {
+            UndefinedSymbolNote::NativeLib { symbol, krate: crate_name(krate), lib }
+        }
+        Some(&ForeignItemSource { krate, native_lib: None }) => {
+            UndefinedSymbolNote::NoLinkAttribute { symbol, krate: crate_name(krate) }
+        }
+        None => {
+            // GNU ld, ld64 and `link.exe` report the mangled symbols, which are demangled here,
+            // while lld and mold report them demangled already.
+            if let Some(demangled) = demangle(&symbol) {
+                match rust_crate(info, &demangled) {
+                    Some(krate) => UndefinedSymbolNote::RustItem { symbol, demangled, krate },
+                    None => UndefinedSymbolNote::RustItemUnknownCrate { symbol, demangled },
+                }
+            } else if let Some(demangled) = strip_legacy_hash(&symbol)
+                && let Some(krate) = rust_crate(info, demangled)
+            {
+                // Demangled C++ symbols look the same, so only paths starting with the name of a
+                // crate of the crate graph are taken for Rust items.
+                let demangled = demangled.to_string();
+                UndefinedSymbolNote::RustItem { symbol, demangled, krate }
+            } else {
+                UndefinedSymbolNote::Unknown { symbol }
+            }
+        }
+    }
+}
+
+/// Returns the crate of the crate graph whose name is the first segment of a demangled path.
+fn rust_crate(info: &CrateInfo, demangled: &str) -> Option<Symbol> {
+    // Trait impls are demangled as `<crate::Type as crate::Trait>::method`.
+    let krate = demangled.trim_start_matches('<').split("::").next()?;
+    std::iter::once(&info.local_crate_name)
+        .chain(info.crate_name.values())
+        .find(|name| name.as_str() == krate)
+        .copied()
+}
+
+/// Removes the `::h0123456789abcdef` hash that the legacy mangling scheme appends to paths from a
+/// symbol demangled by the linker. Returns `None` if the symbol isn't a path.
+fn strip_legacy_hash(symbol: &str) -> Option<&str> {
+    let (path, last) = symbol.rsplit_once("::")?;
+    let is_hash = last.len() == 17
+        && last.starts_with('h')
+        && last[1..].bytes().all(|byte| byte.is_ascii_hexdigit());
+    Some(if is_hash { path } else { symbol })
+}
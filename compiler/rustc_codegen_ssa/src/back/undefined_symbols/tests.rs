@@ -0,0 +1,82 @@
+use super::{parse_undefined_symbols, strip_legacy_hash};
+
+#[test]
+fn gnu_ld() {
+    let output = "\
+/usr/bin/ld: main.main.7rcbfp3g-cgu.0.rcgu.o: in function `main::main':
+main.7rcbfp3g-cgu.0:(.text._ZN4main4main17h0a3a4c8b2e2f5e1aE+0x5): undefined reference to `foo_init'
+/usr/bin/ld: main.7rcbfp3g-cgu.0:(.text._ZN4main4main17h0a3a4c8b2e2f5e1aE+0xa): undefined reference to `foo_init'
+/usr/bin/ld: main.7rcbfp3g-cgu.0:(.text._ZN4main4main17h0a3a4c8b2e2f5e1aE+0xf): undefined reference to `_ZN3dep6helper17h5d3c0a6e1f2b4c7dE'
+collect2: error: ld returned 1 exit status
+";
+    assert_eq!(parse_undefined_symbols(output), ["foo_init", "_ZN3dep6helper17h5d3c0a6e1f2b4c7dE"]);
+}
+
+#[test]
+fn gnu_ld_unicode_quotes() {
+    let output = "main.c:(.text+0x5): undefined reference to ‘foo_init’";
+    assert_eq!(parse_undefined_symbols(output), ["foo_init"]);
+}
+
+#[test]
+fn lld() {
+    let output = "\
+ld.lld: error: undefined symbol: foo_init
+>>> referenced by main.rs:4
+>>>               main.main.7rcbfp3g-cgu.0.rcgu.o:(main::main::h0a3a4c8b2e2f5e1a)
+ld.lld: error: undefined symbol: foo::bar::h5d3c0a6e1f2b4c7d
+>>> referenced by main.rs:5
+";
+    assert_eq!(parse_undefined_symbols(output), ["foo_init", "foo::bar::h5d3c0a6e1f2b4c7d"]);
+}
+
+#[test]
+fn mold() {
+    let output = "\
+mold: error: undefined symbol: foo_init
+>>> referenced by main.rs:4
+mold: error: undefined symbol: main.o: foo_shutdown
+";
+    assert_eq!(parse_undefined_symbols(output), ["foo_init", "foo_shutdown"]);
+}
+
+#[test]
+fn ld64() {
+    let output = "\
+Undefined symbols for architecture arm64:
+  \"_foo_init\", referenced from:
+      main::main::h0a3a4c8b2e2f5e1a in main.main.7rcbfp3g-cgu.0.rcgu.o
+ld: symbol(s) not found for architecture arm64
+";
+    assert_eq!(parse_undefined_symbols(output), ["_foo_init"]);
+}
+
+#[test]
+fn link_exe() {
+    let output = "\
+main.main.7rcbfp3g-cgu.0.rcgu.o : error LNK2019: unresolved external symbol foo_init referenced in function _ZN4main4main17h0a3a4c8b2e2f5e1aE
+main.exe : fatal error LNK1120: 1 unresolved externals
+";
+    assert_eq!(parse_undefined_symbols(output), ["foo_init"]);
+}
+
+#[test]
+fn unrelated_errors() {
+    let output = "\
+/usr/bin/ld: cannot find -lfoo: No such file or directory
+collect2: error: ld returned 1 exit status
+";
+    assert!(parse_undefined_symbols(output).is_empty());
+}
+
+#[test]
+fn linker_demangled_symbols() {
+    assert_eq!(strip_legacy_hash("dep::helper::h5d3c0a6e1f2b4c7d"), Some("dep::helper"));
+    assert_eq!(strip_legacy_hash("dep::helper"), Some("dep::helper"));
+    assert_eq!(
+        strip_legacy_hash("<dep::Foo as core::fmt::Debug>::fmt::h5d3c0a6e1f2b4c7d"),
+        Some("<dep::Foo as core::fmt::Debug>::fmt"),
+    );
+    assert_eq!(strip_legacy_hash("dep::hash::hello"), Some("dep::hash::hello"));
+    assert_eq!(strip_legacy_hash("foo_init"), None);
+}
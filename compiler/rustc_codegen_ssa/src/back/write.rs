@@ -126,6 +126,24 @@ fn new(
         sess: &Session,
         no_builtins: bool,
         is_compiler_builtins: bool,
+    ) -> ModuleConfig {
+        ModuleConfig::with_opt_level(
+            kind,
+            sess,
+            sess.opts.optimize,
+            no_builtins,
+            is_compiler_builtins,
+        )
+    }
+
+    /// The configuration of a module optimized at `opt_level` instead of `-Copt-level`, which
+    /// is used for the codegen units of the functions with an `#[optimize(level = "...")]`.
+    fn with_opt_level(
+        kind: ModuleKind,
+        sess: &Session,
+        opt_level: config::OptLevel,
+        no_builtins: bool,
+        is_compiler_builtins: bool,
     ) -> ModuleConfig {
         // If it's a regular module, use `$regular`, otherwise use `$other`.
         // `$regular` and `$other` are evaluated lazily.
@@ -135,7 +153,7 @@ macro_rules! if_regular {
             };
         }
 
-        let opt_level_and_size = if_regular!(Some(sess.opts.optimize), None);
+        let opt_level_and_size = if_regular!(Some(opt_level), None);
 
         let save_temps = sess.opts.cg.save_temps;
 
@@ -236,10 +254,10 @@ macro_rules! if_regular {
             // Copy what clang does by turning on loop vectorization at O2 and
             // slp vectorization at O3.
             vectorize_loop: !sess.opts.cg.no_vectorize_loops
-                && (sess.opts.optimize == config::OptLevel::Default
-                    || sess.opts.optimize == config::OptLevel::Aggressive),
+                && (opt_level == config::OptLevel::Default
+                    || opt_level == config::OptLevel::Aggressive),
             vectorize_slp: !sess.opts.cg.no_vectorize_slp
-                && sess.opts.optimize == config::OptLevel::Aggressive,
+                && opt_level == config::OptLevel::Aggressive,
 
             // Some targets (namely, NVPTX) interact badly with the
             // MergeFunctions pass. This is because MergeFunctions can generate
@@ -259,7 +277,7 @@ macro_rules! if_regular {
                 MergeFunctions::Disabled => false,
                 MergeFunctions::Trampolines | MergeFunctions::Aliases => {
                     use config::OptLevel::*;
-                    match sess.opts.optimize {
+                    match opt_level {
                         Aggressive | Default | SizeMin | Size => true,
                         Less | No => false,
                     }
@@ -334,6 +352,9 @@ pub struct CodegenContext<B: WriteBackendMethods> {
     pub regular_module_config: Arc<ModuleConfig>,
     pub metadata_module_config: Arc<ModuleConfig>,
     pub allocator_module_config: Arc<ModuleConfig>,
+    /// The configurations of the regular modules optimized at an `#[optimize(level = "...")]`
+    /// instead of `-Copt-level`, keyed by module name.
+    pub opt_level_module_configs: Arc<FxHashMap<String, ModuleConfig>>,
     pub tm_factory: TargetMachineFactoryFn<B>,
     pub msvc_imps_needed: bool,
     pub is_pe_coff: bool,
@@ -372,6 +393,16 @@ pub fn config(&self, kind: ModuleKind) -> &ModuleConfig {
             ModuleKind::Allocator => &self.allocator_module_config,
         }
     }
+
+    /// Like `config`, but takes the optimization level of the module's codegen unit into account.
+    pub fn module_config(&self, kind: ModuleKind, name: &str) -> &ModuleConfig {
+        match kind {
+            ModuleKind::Regular => {
+                self.opt_level_module_configs.get(name).unwrap_or(&self.regular_module_config)
+            }
+            ModuleKind::Metadata | ModuleKind::Allocator => self.config(kind),
+        }
+    }
 }
 
 fn generate_lto_work<B: ExtraBackendMethods>(
@@ -456,6 +487,27 @@ pub fn start_async_codegen<B: ExtraBackendMethods>(
         ModuleConfig::new(ModuleKind::Metadata, sess, no_builtins, is_compiler_builtins);
     let allocator_config =
         ModuleConfig::new(ModuleKind::Allocator, sess, no_builtins, is_compiler_builtins);
+    // The codegen units of the functions with an `#[optimize(level = "...")]` are optimized at
+    // that level, see `rustc_monomorphize::partitioning`.
+    let opt_level_configs =
+        if sess.opts.unstable_opts.no_codegen || !sess.opts.output_types.should_codegen() {
+            FxHashMap::default()
+        } else {
+            let (_, codegen_units) = tcx.collect_and_partition_mono_items(());
+            codegen_units
+                .iter()
+                .filter_map(|cgu| {
+                    let config = ModuleConfig::with_opt_level(
+                        ModuleKind::Regular,
+                        sess,
+                        cgu.opt_level()?,
+                        no_builtins,
+                        is_compiler_builtins,
+                    );
+                    Some((cgu.name().to_string(), config))
+                })
+                .collect()
+        };
 
     let (shared_emitter, shared_emitter_main) = SharedEmitter::new();
     let (codegen_worker_send, codegen_worker_receive) = channel();
@@ -471,6 +523,7 @@ pub fn start_async_codegen<B: ExtraBackendMethods>(
         Arc::new(regular_config),
         Arc::new(metadata_config),
         Arc::new(allocator_config),
+        Arc::new(opt_level_configs),
         coordinator_send.clone(),
     );
 
@@ -700,6 +753,14 @@ pub fn module_kind(&self) -> ModuleKind {
         }
     }
 
+    fn module_name(&self) -> &str {
+        match self {
+            WorkItem::Optimize(m) => &m.name,
+            WorkItem::CopyPostLtoArtifacts(m) => &m.name,
+            WorkItem::LTO(m) => m.name(),
+        }
+    }
+
     /// Generate a short description of this work item suitable for use as a thread name.
     fn short_description(&self) -> String {
         // `pthread_setname()` on *nix ignores anything beyond the first 15
@@ -1004,6 +1065,7 @@ fn start_executing_work<B: ExtraBackendMethods>(
     regular_config: Arc<ModuleConfig>,
     metadata_config: Arc<ModuleConfig>,
     allocator_config: Arc<ModuleConfig>,
+    opt_level_configs: Arc<FxHashMap<String, ModuleConfig>>,
     tx_to_llvm_workers: Sender<Box<dyn Any + Send>>,
 ) -> thread::JoinHandle<Result<CompiledModules, ()>> {
     let coordinator_send = tx_to_llvm_workers;
@@ -1098,6 +1160,7 @@ fn start_executing_work<B: ExtraBackendMethods>(
         regular_module_config: regular_config,
         metadata_module_config: metadata_config,
         allocator_module_config: allocator_config,
+        opt_level_module_configs: opt_level_configs,
         tm_factory: backend.target_machine_factory(tcx.sess, ol, backend_features),
         msvc_imps_needed: msvc_imps_needed(tcx),
         is_pe_coff: tcx.sess.target.is_like_windows,
@@ -1705,7 +1768,7 @@ fn drop(&mut self) {
         // as a diagnostic was already sent off to the main thread - just
         // surface that there was an error in this worker.
         bomb.result = {
-            let module_config = cgcx.config(work.module_kind());
+            let module_config = cgcx.module_config(work.module_kind(), work.module_name());
 
             Some(match work {
                 WorkItem::Optimize(m) => {
@@ -11,14 +11,16 @@
 use crate::mir::operand::OperandValue;
 use crate::mir::place::PlaceRef;
 use crate::traits::*;
-use crate::{CachedModuleCodegen, CompiledModule, CrateInfo, MemFlags, ModuleCodegen, ModuleKind};
+use crate::{CachedModuleCodegen, CompiledModule, CrateInfo, ForeignItemSource, MemFlags};
+use crate::{CallGraphNode, ModuleCodegen, ModuleKind, SymbolOrigin};
 
 use rustc_ast::expand::allocator::{global_fn_name, AllocatorKind, ALLOCATOR_METHODS};
 use rustc_attr as attr;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
-use rustc_data_structures::profiling::{get_resident_set_size, print_time_passes_entry};
+use rustc_data_structures::profiling::get_resident_set_size;
 use rustc_data_structures::sync::par_map;
 use rustc_hir as hir;
+use rustc_hir::def::DefKind;
 use rustc_hir::def_id::{DefId, LOCAL_CRATE};
 use rustc_hir::lang_items::LangItem;
 use rustc_metadata::EncodedMetadata;
@@ -27,9 +29,10 @@
 use rustc_middle::middle::exported_symbols;
 use rustc_middle::middle::exported_symbols::SymbolExportKind;
 use rustc_middle::middle::lang_items;
-use rustc_middle::mir::mono::{CodegenUnit, CodegenUnitNameBuilder, MonoItem};
+use rustc_middle::mir::mono::{CodegenUnit, CodegenUnitNameBuilder, MonoCallee, MonoItem};
 use rustc_middle::query::Providers;
 use rustc_middle::ty::layout::{HasTyCtxt, LayoutOf, TyAndLayout};
+use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::{self, Instance, Ty, TyCtxt};
 use rustc_session::cgu_reuse_tracker::CguReuse;
 use rustc_session::config::{self, CrateType, EntryFnType, OutputType};
@@ -795,12 +798,11 @@ pub fn codegen_crate<B: ExtraBackendMethods>(
     if tcx.sess.opts.unstable_opts.time_passes {
         let end_rss = get_resident_set_size();
 
-        print_time_passes_entry(
+        tcx.sess.prof.time_passes_entry(
             "codegen_to_LLVM_IR",
             total_codegen_time,
             start_rss.unwrap(),
             end_rss,
-            tcx.sess.opts.unstable_opts.time_passes_format,
         );
     }
 
@@ -875,6 +877,9 @@ pub fn new(tcx: TyCtxt<'_>, target_cpu: String) -> CrateInfo {
             windows_subsystem,
             natvis_debugger_visualizers: Default::default(),
             feature_packed_bundled_libs: tcx.features().packed_bundled_libs,
+            foreign_item_sources: Default::default(),
+            symbol_origins: Default::default(),
+            call_graph: Default::default(),
         };
         let crates = tcx.crates(());
 
@@ -970,10 +975,112 @@ pub fn new(tcx: TyCtxt<'_>, target_cpu: String) -> CrateInfo {
                 collect_debugger_visualizers_transitive(tcx, DebuggerVisualizerType::Natvis);
         }
 
+        let invokes_linker = tcx.sess.opts.output_types.should_link()
+            && tcx
+                .sess
+                .crate_types()
+                .iter()
+                .any(|&crate_type| !matches!(crate_type, CrateType::Rlib | CrateType::Staticlib));
+        if invokes_linker {
+            info.foreign_item_sources = collect_foreign_item_sources(tcx);
+        }
+
+        if tcx.sess.opts.unstable_opts.emit_size_map.is_some() {
+            info.symbol_origins = collect_symbol_origins(tcx);
+        }
+
+        if tcx.sess.opts.unstable_opts.stack_usage_report.is_some() {
+            info.call_graph = collect_call_graph(tcx);
+        }
+
         info
     }
 }
 
+/// Maps the symbol names of the items declared in `extern` blocks of the local crate and its
+/// dependencies to the crate declaring them, and the native library they are linked to.
+///
+/// The linker only runs once the type context is gone, so this can't wait until linking fails.
+/// To keep it cheap for crates declaring many foreign items, like `libc`, the symbol names are
+/// taken from `#[link_name]` or the item names instead of computing them with the `symbol_name`
+/// query. This misses the symbols of the `#[link(wasm_import_module)]` imports, which are
+/// mangled, but the wasm linkers don't report those as undefined anyway.
+fn collect_foreign_item_sources(tcx: TyCtxt<'_>) -> FxHashMap<Symbol, ForeignItemSource> {
+    let mut sources = FxHashMap::default();
+    for &cnum in std::iter::once(&LOCAL_CRATE).chain(tcx.crates(()).iter()) {
+        let foreign_modules = tcx.foreign_modules(cnum);
+        if foreign_modules.is_empty() {
+            continue;
+        }
+        let native_libs = tcx.native_libraries(cnum);
+        for foreign_module in foreign_modules.values() {
+            let native_lib = native_libs
+                .iter()
+                .find(|lib| lib.foreign_module == Some(foreign_module.def_id))
+                .map(|lib| lib.name);
+            for &def_id in &foreign_module.foreign_items {
+                // Foreign types don't have symbols.
+                if !matches!(tcx.def_kind(def_id), DefKind::Fn | DefKind::Static(_)) {
+                    continue;
+                }
+                let symbol_name =
+                    tcx.codegen_fn_attrs(def_id).link_name.unwrap_or_else(|| tcx.item_name(def_id));
+                sources.entry(symbol_name).or_insert(ForeignItemSource { krate: cnum, native_lib });
+            }
+        }
+    }
+    sources
+}
+
+/// Maps the symbol names of the local crate's mono items to the definitions they were
+/// instantiated from, for `-Zemit-size-map`.
+fn collect_symbol_origins(tcx: TyCtxt<'_>) -> FxHashMap<String, SymbolOrigin> {
+    let (_, codegen_units) = tcx.collect_and_partition_mono_items(());
+    codegen_units
+        .iter()
+        .flat_map(|cgu| cgu.items().keys())
+        .filter(|item| !matches!(item, MonoItem::GlobalAsm(..)))
+        .map(|item| {
+            let def_id = item.def_id();
+            let origin = SymbolOrigin {
+                krate: tcx.crate_name(def_id.krate),
+                definition: with_no_trimmed_paths!(tcx.def_path_str(def_id)),
+                instance: with_no_trimmed_paths!(item.to_string()),
+            };
+            (item.symbol_name(tcx).name.to_string(), origin)
+        })
+        .collect()
+}
+
+/// Maps the symbol names of the local crate's functions to the functions they call, for
+/// `-Zstack-usage-report`.
+fn collect_call_graph(tcx: TyCtxt<'_>) -> FxHashMap<String, CallGraphNode> {
+    let (_, codegen_units) = tcx.collect_and_partition_mono_items(());
+    codegen_units
+        .iter()
+        .flat_map(|cgu| cgu.items().keys())
+        .filter_map(|item| match *item {
+            MonoItem::Fn(instance) => Some(instance),
+            MonoItem::Static(_) | MonoItem::GlobalAsm(_) => None,
+        })
+        .map(|instance| {
+            let mut node = CallGraphNode::default();
+            for callee in tcx.mono_callees(instance) {
+                match *callee {
+                    MonoCallee::Direct(callee) => {
+                        node.callees.push(tcx.symbol_name(callee).name.to_string())
+                    }
+                    MonoCallee::Indirect(_) => node.indirect_calls = true,
+                    MonoCallee::InlineAsm(_) => node.inline_asm = true,
+                }
+            }
+            node.callees.sort_unstable();
+            node.callees.dedup();
+            (tcx.symbol_name(instance).name.to_string(), node)
+        })
+        .collect()
+}
+
 pub fn provide(providers: &mut Providers) {
     providers.backend_optimization_level = |tcx, cratenum| {
         let for_speed = match tcx.sess.opts.optimize {
@@ -999,7 +1106,9 @@ pub fn provide(providers: &mut Providers) {
         let any_for_speed = defids.items().any(|id| {
             let CodegenFnAttrs { optimize, .. } = tcx.codegen_fn_attrs(*id);
             match optimize {
-                attr::OptimizeAttr::None | attr::OptimizeAttr::Size => false,
+                attr::OptimizeAttr::None
+                | attr::OptimizeAttr::Size
+                | attr::OptimizeAttr::Level(_) => false,
                 attr::OptimizeAttr::Speed => true,
             }
         });
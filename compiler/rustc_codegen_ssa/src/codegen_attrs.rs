@@ -1,5 +1,7 @@
 use rustc_ast::{ast, MetaItemKind, NestedMetaItem};
-use rustc_attr::{list_contains_name, InlineAttr, InstructionSetAttr, OptimizeAttr};
+use rustc_attr::{
+    list_contains_name, InlineAttr, InstructionSetAttr, OptimizeAttr, OptimizeLevel,
+};
 use rustc_errors::struct_span_err;
 use rustc_hir as hir;
 use rustc_hir::def::DefKind;
@@ -482,6 +484,21 @@ fn codegen_fn_attrs(tcx: TyCtxt<'_>, did: LocalDefId) -> CodegenFnAttrs {
                     OptimizeAttr::Size
                 } else if list_contains_name(&items, sym::speed) {
                     OptimizeAttr::Speed
+                } else if let Some(item) = items[0].meta_item()
+                    && item.has_name(sym::level)
+                {
+                    match item.value_str().and_then(|level| OptimizeLevel::from_str(level.as_str()))
+                    {
+                        Some(level) => OptimizeAttr::Level(level),
+                        None => {
+                            err(
+                                item.span,
+                                "invalid optimization level, expected one of \
+                                 `\"0\"`, `\"1\"`, `\"2\"`, `\"3\"`, `\"s\"` or `\"z\"`",
+                            );
+                            OptimizeAttr::None
+                        }
+                    }
                 } else {
                     err(items[0].span(), "invalid argument");
                     OptimizeAttr::None
@@ -6,7 +6,7 @@
     DiagnosticArgValue, DiagnosticBuilder, ErrorGuaranteed, Handler, IntoDiagnostic,
     IntoDiagnosticArg,
 };
-use rustc_macros::Diagnostic;
+use rustc_macros::{Diagnostic, Subdiagnostic};
 use rustc_middle::ty::Ty;
 use rustc_span::{Span, Symbol};
 use rustc_type_ir::FloatTy;
@@ -338,11 +338,28 @@ fn into_diagnostic(self, handler: &Handler) -> DiagnosticBuilder<'_, ErrorGuaran
     }
 }
 
+#[derive(Diagnostic)]
+#[diag(codegen_ssa_failed_to_write_size_map)]
+pub struct FailedToWriteSizeMap<'a> {
+    pub path: &'a Path,
+    pub error: String,
+}
+
+#[derive(Diagnostic)]
+#[diag(codegen_ssa_failed_to_write_stack_usage_report)]
+pub struct FailedToWriteStackUsageReport<'a> {
+    pub path: &'a Path,
+    pub error: String,
+}
+
 pub struct LinkingFailed<'a> {
     pub linker_path: &'a PathBuf,
     pub exit_status: ExitStatus,
     pub command: &'a Command,
     pub escaped_output: String,
+    pub linker_plugin_lto: bool,
+    pub undefined_symbols: Vec<UndefinedSymbolNote>,
+    pub omitted_undefined_symbols: usize,
 }
 
 impl IntoDiagnostic<'_> for LinkingFailed<'_> {
@@ -355,17 +372,61 @@ fn into_diagnostic(self, handler: &Handler) -> DiagnosticBuilder<'_, ErrorGuaran
 
         diag.note(format!("{:?}", self.command)).note(self.escaped_output.to_string());
 
-        // Trying to match an error from OS linkers
-        // which by now we have no way to translate.
-        if contains_undefined_ref {
+        // Explain where the undefined symbols recognized in the linker output come from, and
+        // otherwise try to match an error from OS linkers which by now we have no way to
+        // translate.
+        if !self.undefined_symbols.is_empty() {
+            for note in self.undefined_symbols {
+                diag.eager_subdiagnostic(handler, note);
+            }
+            if self.omitted_undefined_symbols > 0 {
+                diag.set_arg("omitted_undefined_symbols", self.omitted_undefined_symbols);
+                diag.note(fluent::codegen_ssa_undefined_symbols_omitted);
+            }
+        } else if contains_undefined_ref {
             diag.note(fluent::codegen_ssa_extern_funcs_not_found)
                 .note(fluent::codegen_ssa_specify_libraries_to_link)
                 .note(fluent::codegen_ssa_use_cargo_directive);
         }
+
+        // `cc` couldn't use the linker requested with `-fuse-ld`, e.g. with `-Zgcc-ld=mold`
+        // or a flavor using `lld`.
+        if let Some((_, fuse_ld)) =
+            self.escaped_output.split_once("invalid linker name in argument '-fuse-ld=")
+        {
+            let linker = fuse_ld.split('\'').next().unwrap_or_default();
+            diag.set_arg("fuse_ld", linker.to_string());
+            diag.note(fluent::codegen_ssa_unsupported_fuse_ld);
+        } else if self.escaped_output.contains("collect2: fatal error: cannot find 'ld'") {
+            diag.note(fluent::codegen_ssa_fuse_ld_not_found);
+        }
+
+        // Errors from linkers that can't read the LLVM bitcode emitted for `-Clinker-plugin-lto`.
+        let bitcode_errors =
+            ["file format not recognized", "Invalid record", "Unknown attribute kind", "Producer:"];
+        if self.linker_plugin_lto
+            && bitcode_errors.iter().any(|pattern| self.escaped_output.contains(pattern))
+        {
+            diag.note(fluent::codegen_ssa_linker_plugin_lto_unsupported);
+        }
         diag
     }
 }
 
+#[derive(Subdiagnostic)]
+pub enum UndefinedSymbolNote {
+    #[note(codegen_ssa_undefined_symbol_native_lib)]
+    NativeLib { symbol: String, krate: Symbol, lib: Symbol },
+    #[note(codegen_ssa_undefined_symbol_no_link_attribute)]
+    NoLinkAttribute { symbol: String, krate: Symbol },
+    #[note(codegen_ssa_undefined_symbol_rust_item)]
+    RustItem { symbol: String, demangled: String, krate: Symbol },
+    #[note(codegen_ssa_undefined_symbol_rust_item_unknown_crate)]
+    RustItemUnknownCrate { symbol: String, demangled: String },
+    #[note(codegen_ssa_undefined_symbol_unknown)]
+    Unknown { symbol: String },
+}
+
 #[derive(Diagnostic)]
 #[diag(codegen_ssa_link_exe_unexpected_error)]
 pub struct LinkExeUnexpectedError;
@@ -540,6 +601,10 @@ pub struct ReadFileError {
 #[diag(codegen_ssa_unsupported_link_self_contained)]
 pub struct UnsupportedLinkSelfContained;
 
+#[derive(Diagnostic)]
+#[diag(codegen_ssa_self_contained_mold)]
+pub struct SelfContainedMold;
+
 #[derive(Diagnostic)]
 #[diag(codegen_ssa_archive_build_failure)]
 // Public for rustc_codegen_llvm::back::archive
@@ -139,6 +139,39 @@ fn from(lib: &cstore::NativeLib) -> Self {
     }
 }
 
+/// An item declared in an `extern` block, used to explain which crate and native library are
+/// responsible for an undefined symbol when linking fails.
+#[derive(Clone, Debug, Encodable, Decodable)]
+pub struct ForeignItemSource {
+    /// The crate declaring the item.
+    pub krate: CrateNum,
+    /// The native library that the `extern` block is linked to with `#[link(name = "...")]`, if
+    /// any.
+    pub native_lib: Option<Symbol>,
+}
+
+/// Where the code or data of a monomorphized item comes from, used by `-Zemit-size-map`.
+#[derive(Clone, Debug, Encodable, Decodable)]
+pub struct SymbolOrigin {
+    /// The crate of the definition the item was instantiated from.
+    pub krate: Symbol,
+    /// The path of that definition, e.g. `alloc::vec::Vec::push`.
+    pub definition: String,
+    /// The item itself, including its generic arguments, e.g. `alloc::vec::Vec::<u8>::push`.
+    pub instance: String,
+}
+
+/// The calls made by a function of the local crate, used by `-Zstack-usage-report`.
+#[derive(Clone, Debug, Default, Encodable, Decodable)]
+pub struct CallGraphNode {
+    /// The symbol names of the functions called directly.
+    pub callees: Vec<String>,
+    /// Whether the function also calls through function pointers or vtables.
+    pub indirect_calls: bool,
+    /// Whether the function contains inline assembly which may use the stack.
+    pub inline_asm: bool,
+}
+
 /// Misc info we load from metadata to persist beyond the tcx.
 ///
 /// Note: though `CrateNum` is only meaningful within the same tcx, information within `CrateInfo`
@@ -165,6 +198,15 @@ pub struct CrateInfo {
     pub windows_subsystem: Option<String>,
     pub natvis_debugger_visualizers: BTreeSet<DebuggerVisualizerFile>,
     pub feature_packed_bundled_libs: bool, // unstable feature flag.
+    /// The items declared in `extern` blocks of the crate graph, keyed by their symbol name.
+    /// Only collected when the linker is invoked.
+    pub foreign_item_sources: FxHashMap<Symbol, ForeignItemSource>,
+    /// The origins of the symbols defined by the local crate, keyed by their symbol name. Only
+    /// collected for `-Zemit-size-map`.
+    pub symbol_origins: FxHashMap<String, SymbolOrigin>,
+    /// The calls made by the functions of the local crate, keyed by their symbol name. Only
+    /// collected for `-Zstack-usage-report`.
+    pub call_graph: FxHashMap<String, CallGraphNode>,
 }
 
 #[derive(Encodable, Decodable)]
@@ -92,12 +92,12 @@
 use std::intrinsics::unlikely;
 use std::path::Path;
 use std::process;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use std::time::{Duration, Instant};
 
 pub use measureme::EventId;
 use measureme::{EventIdBuilder, Profiler, SerializableString, StringId};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use smallvec::SmallVec;
 
 bitflags::bitflags! {
@@ -153,6 +153,9 @@ pub enum TimePassesFormat {
     Text,
     /// Emit structured JSON
     Json,
+    /// Emit a single JSON document holding the tree of nested passes and
+    /// per-query totals once the compilation session ends
+    JsonTree,
 }
 
 /// A reference to the SelfProfiler. It can be cloned and sent across thread
@@ -170,6 +173,10 @@ pub struct SelfProfilerRef {
 
     // Print verbose generic activities to stderr.
     print_verbose_generic_activities: Option<TimePassesFormat>,
+
+    // Collects verbose generic activities into a tree instead of printing
+    // them. Only present with `-Z time-passes-format=json-tree`.
+    time_passes_tree: Option<Arc<TimePassesTree>>,
 }
 
 impl SelfProfilerRef {
@@ -182,7 +189,16 @@ pub fn new(
         let event_filter_mask =
             profiler.as_ref().map_or(EventFilter::empty(), |p| p.event_filter_mask);
 
-        SelfProfilerRef { profiler, event_filter_mask, print_verbose_generic_activities }
+        let time_passes_tree = (print_verbose_generic_activities
+            == Some(TimePassesFormat::JsonTree))
+        .then(|| Arc::new(TimePassesTree::new()));
+
+        SelfProfilerRef {
+            profiler,
+            event_filter_mask,
+            print_verbose_generic_activities,
+            time_passes_tree,
+        }
     }
 
     /// This shim makes sure that calls only get executed if the filter mask
@@ -220,7 +236,11 @@ pub fn verbose_generic_activity(&self, event_label: &'static str) -> VerboseTimi
         let message_and_format =
             self.print_verbose_generic_activities.map(|format| (event_label.to_owned(), format));
 
-        VerboseTimingGuard::start(message_and_format, self.generic_activity(event_label))
+        VerboseTimingGuard::start(
+            message_and_format,
+            self.time_passes_tree.as_deref(),
+            self.generic_activity(event_label),
+        )
     }
 
     /// Like `verbose_generic_activity`, but with an extra arg.
@@ -238,10 +258,37 @@ pub fn verbose_generic_activity_with_arg<A>(
 
         VerboseTimingGuard::start(
             message_and_format,
+            self.time_passes_tree.as_deref(),
             self.generic_activity_with_arg(event_label, event_arg),
         )
     }
 
+    /// Report a `-Z time-passes` entry for an activity that had to be timed
+    /// manually, e.g. because the thread performing it is blocked in between.
+    pub fn time_passes_entry(
+        &self,
+        what: &str,
+        dur: Duration,
+        start_rss: Option<usize>,
+        end_rss: Option<usize>,
+    ) {
+        if let Some(tree) = &self.time_passes_tree {
+            let stats =
+                PassStats { time: dur, cpu_time: None, start_rss, end_rss, allocated: None };
+            tree.record(what.to_owned(), stats);
+        } else if let Some(format) = self.print_verbose_generic_activities {
+            print_time_passes_entry(what, dur, start_rss, end_rss, format);
+        }
+    }
+
+    /// Print the tree of passes collected for `-Z time-passes-format=json-tree`
+    /// to stderr as a single JSON document. Does nothing in other modes.
+    pub fn print_time_passes_tree(&self) {
+        if let Some(tree) = &self.time_passes_tree {
+            eprintln!("time: {}", tree.to_json());
+        }
+    }
+
     /// Start profiling a generic activity. Profiling continues until the
     /// TimingGuard returned from this call is dropped.
     #[inline(always)]
@@ -404,6 +451,17 @@ pub fn query_provider(&self) -> TimingGuard<'_> {
         })
     }
 
+    /// Start timing a query provider for the per-query totals reported by
+    /// `-Z time-passes-format=json-tree`. Timing continues until the
+    /// QueryTotalTimingGuard returned from this call is dropped.
+    #[inline(always)]
+    pub fn query_provider_total(&self, query_name: &'static str) -> QueryTotalTimingGuard<'_> {
+        match &self.time_passes_tree {
+            Some(tree) => QueryTotalTimingGuard(Some((tree, query_name, Instant::now()))),
+            None => QueryTotalTimingGuard(None),
+        }
+    }
+
     /// Record a query in-memory cache hit.
     #[inline(always)]
     pub fn query_cache_hit(&self, query_invocation_id: QueryInvocationId) {
@@ -727,14 +785,27 @@ struct VerboseInfo {
 #[must_use]
 pub struct VerboseTimingGuard<'a> {
     info: Option<VerboseInfo>,
+    tree_pass: Option<(&'a TimePassesTree, usize, PassSample)>,
     _guard: TimingGuard<'a>,
 }
 
 impl<'a> VerboseTimingGuard<'a> {
     pub fn start(
         message_and_format: Option<(String, TimePassesFormat)>,
+        tree: Option<&'a TimePassesTree>,
         _guard: TimingGuard<'a>,
     ) -> Self {
+        // With a pass tree the entry is recorded as a node when the guard is
+        // dropped rather than being printed on its own.
+        if let (Some((message, _)), Some(tree)) = (&message_and_format, tree) {
+            let node = tree.enter(message.clone());
+            return VerboseTimingGuard {
+                _guard,
+                info: None,
+                tree_pass: Some((tree, node, PassSample::now())),
+            };
+        }
+
         VerboseTimingGuard {
             _guard,
             info: message_and_format.map(|(message, format)| VerboseInfo {
@@ -743,6 +814,7 @@ pub fn start(
                 message,
                 format,
             }),
+            tree_pass: None,
         }
     }
 
@@ -760,9 +832,241 @@ fn drop(&mut self) {
             let dur = info.start_time.elapsed();
             print_time_passes_entry(&info.message, dur, info.start_rss, end_rss, info.format);
         }
+        if let Some((tree, node, start)) = &self.tree_pass {
+            tree.exit(*node, PassStats::between(start, &PassSample::now()));
+        }
+    }
+}
+
+#[must_use]
+pub struct QueryTotalTimingGuard<'a>(Option<(&'a TimePassesTree, &'static str, Instant)>);
+
+impl Drop for QueryTotalTimingGuard<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some((tree, query_name, start_time)) = self.0 {
+            cold_path(|| tree.record_query(query_name, start_time.elapsed()));
+        }
+    }
+}
+
+/// Resource usage sampled when a pass starts or ends.
+#[derive(Clone, Copy)]
+struct PassSample {
+    time: Instant,
+    cpu_time: Option<Duration>,
+    rss: Option<usize>,
+    allocated: Option<u64>,
+}
+
+impl PassSample {
+    fn now() -> PassSample {
+        PassSample {
+            time: Instant::now(),
+            cpu_time: get_thread_cpu_time(),
+            rss: get_resident_set_size(),
+            allocated: get_thread_allocated_bytes(),
+        }
+    }
+}
+
+/// What is reported for a single node of the pass tree.
+#[derive(Clone, Copy, Debug)]
+struct PassStats {
+    time: Duration,
+    cpu_time: Option<Duration>,
+    start_rss: Option<usize>,
+    end_rss: Option<usize>,
+    allocated: Option<u64>,
+}
+
+impl PassStats {
+    fn between(start: &PassSample, end: &PassSample) -> PassStats {
+        PassStats {
+            time: end.time.duration_since(start.time),
+            cpu_time: start
+                .cpu_time
+                .zip(end.cpu_time)
+                .map(|(start, end)| end.saturating_sub(start)),
+            start_rss: start.rss,
+            end_rss: end.rss,
+            allocated: start
+                .allocated
+                .zip(end.allocated)
+                .map(|(start, end)| end.saturating_sub(start)),
+        }
+    }
+}
+
+struct TimePassesNode {
+    name: String,
+    thread: u32,
+    // `None` while the pass is still running.
+    stats: Option<PassStats>,
+    children: Vec<usize>,
+}
+
+#[derive(Clone, Copy, Default, Debug)]
+struct QueryTotals {
+    count: u64,
+    time: Duration,
+}
+
+#[derive(Default)]
+struct TimePassesTreeData {
+    nodes: Vec<TimePassesNode>,
+    roots: Vec<usize>,
+    // The passes currently running on each thread, innermost last. Passes
+    // started on a thread nest inside the innermost pass running on it.
+    open: FxHashMap<u32, Vec<usize>>,
+    queries: FxHashMap<&'static str, QueryTotals>,
+}
+
+impl TimePassesTreeData {
+    fn push(&mut self, name: String, stats: Option<PassStats>) -> usize {
+        let thread = get_thread_id();
+        let node = self.nodes.len();
+        self.nodes.push(TimePassesNode { name, thread, stats, children: Vec::new() });
+        match self.open.get(&thread).and_then(|stack| stack.last()) {
+            Some(&parent) => self.nodes[parent].children.push(node),
+            None => self.roots.push(node),
+        }
+        node
     }
 }
 
+/// The nested passes of a compilation session together with the time spent
+/// in each query provider, collected for `-Z time-passes-format=json-tree`.
+pub struct TimePassesTree {
+    start: PassSample,
+    data: Mutex<TimePassesTreeData>,
+}
+
+impl TimePassesTree {
+    fn new() -> TimePassesTree {
+        TimePassesTree { start: PassSample::now(), data: Default::default() }
+    }
+
+    fn enter(&self, name: String) -> usize {
+        let mut data = self.data.lock();
+        let node = data.push(name, None);
+        data.open.entry(get_thread_id()).or_default().push(node);
+        node
+    }
+
+    fn exit(&self, node: usize, stats: PassStats) {
+        let mut data = self.data.lock();
+        data.nodes[node].stats = Some(stats);
+        // Guards are not always dropped in the order they were created in, so
+        // also close any pass that was started inside this one.
+        let thread = data.nodes[node].thread;
+        if let Some(stack) = data.open.get_mut(&thread) {
+            if let Some(pos) = stack.iter().rposition(|&open| open == node) {
+                stack.truncate(pos);
+            }
+        }
+    }
+
+    fn record(&self, name: String, stats: PassStats) {
+        self.data.lock().push(name, Some(stats));
+    }
+
+    fn record_query(&self, query_name: &'static str, time: Duration) {
+        let mut data = self.data.lock();
+        let totals = data.queries.entry(query_name).or_default();
+        totals.count += 1;
+        totals.time += time;
+    }
+
+    fn to_json(&self) -> String {
+        let total = PassStats::between(&self.start, &PassSample::now());
+        JsonTimePassesTree { data: &self.data.lock(), total }.to_string()
+    }
+}
+
+struct JsonTimePassesTree<'a> {
+    data: &'a TimePassesTreeData,
+    total: PassStats,
+}
+
+impl JsonTimePassesTree<'_> {
+    fn fmt_stats(f: &mut std::fmt::Formatter<'_>, stats: Option<&PassStats>) -> std::fmt::Result {
+        fn opt(f: &mut std::fmt::Formatter<'_>, v: Option<impl Display>) -> std::fmt::Result {
+            match v {
+                Some(v) => write!(f, "{v}"),
+                None => write!(f, "null"),
+            }
+        }
+
+        write!(f, r#""time":"#)?;
+        opt(f, stats.map(|s| s.time.as_secs_f64()))?;
+        write!(f, r#","cpu_time":"#)?;
+        opt(f, stats.and_then(|s| s.cpu_time).map(|t| t.as_secs_f64()))?;
+        write!(f, r#","rss_start":"#)?;
+        opt(f, stats.and_then(|s| s.start_rss))?;
+        write!(f, r#","rss_end":"#)?;
+        opt(f, stats.and_then(|s| s.end_rss))?;
+        write!(f, r#","allocated":"#)?;
+        opt(f, stats.and_then(|s| s.allocated))
+    }
+
+    fn fmt_nodes(&self, f: &mut std::fmt::Formatter<'_>, nodes: &[usize]) -> std::fmt::Result {
+        write!(f, "[")?;
+        for (i, &node) in nodes.iter().enumerate() {
+            let node = &self.data.nodes[node];
+            if i != 0 {
+                write!(f, ",")?;
+            }
+            write!(f, r#"{{"pass":"#)?;
+            write_json_str(f, &node.name)?;
+            write!(f, ",")?;
+            Self::fmt_stats(f, node.stats.as_ref())?;
+            write!(f, r#","thread":{},"children":"#, node.thread)?;
+            self.fmt_nodes(f, &node.children)?;
+            write!(f, "}}")?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl Display for JsonTimePassesTree<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, r#"{{"total":{{"#)?;
+        Self::fmt_stats(f, Some(&self.total))?;
+        write!(f, r#"}},"passes":"#)?;
+        self.fmt_nodes(f, &self.data.roots)?;
+
+        // Most expensive queries first, ties broken by name to keep the
+        // output stable.
+        let mut queries: Vec<_> = self.data.queries.iter().collect();
+        queries.sort_by(|(a_name, a), (b_name, b)| b.time.cmp(&a.time).then(a_name.cmp(b_name)));
+
+        write!(f, r#","queries":["#)?;
+        for (i, (name, totals)) in queries.into_iter().enumerate() {
+            if i != 0 {
+                write!(f, ",")?;
+            }
+            write!(f, r#"{{"query":"#)?;
+            write_json_str(f, name)?;
+            write!(f, r#","count":{},"time":{}}}"#, totals.count, totals.time.as_secs_f64())?;
+        }
+        write!(f, "]}}")
+    }
+}
+
+fn write_json_str(f: &mut std::fmt::Formatter<'_>, s: &str) -> std::fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    write!(f, "\"")
+}
+
 struct JsonTimePassesEntry<'a> {
     pass: &'a str,
     time: f64,
@@ -796,7 +1100,7 @@ pub fn print_time_passes_entry(
     format: TimePassesFormat,
 ) {
     match format {
-        TimePassesFormat::Json => {
+        TimePassesFormat::Json | TimePassesFormat::JsonTree => {
             let entry =
                 JsonTimePassesEntry { pass: what, time: dur.as_secs_f64(), start_rss, end_rss };
 
@@ -858,6 +1162,39 @@ fn get_thread_id() -> u32 {
     std::thread::current().id().as_u64().get() as u32
 }
 
+static THREAD_ALLOCATED_BYTES: OnceLock<fn() -> Option<u64>> = OnceLock::new();
+
+/// Registers a function returning the number of bytes the calling thread has
+/// allocated so far. The allocator is chosen when linking the `rustc` binary,
+/// so it has to tell us how to query it; without such a function, no
+/// allocation volume is reported for `-Z time-passes-format=json-tree`.
+pub fn set_thread_allocated_bytes_fn(f: fn() -> Option<u64>) {
+    let _ = THREAD_ALLOCATED_BYTES.set(f);
+}
+
+fn get_thread_allocated_bytes() -> Option<u64> {
+    THREAD_ALLOCATED_BYTES.get().and_then(|f| f())
+}
+
+// CPU time reporting
+cfg_if! {
+    if #[cfg(unix)] {
+        pub fn get_thread_cpu_time() -> Option<Duration> {
+            let mut ts = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+            let ret = unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut ts) };
+            if ret == 0 {
+                Some(Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32))
+            } else {
+                None
+            }
+        }
+    } else {
+        pub fn get_thread_cpu_time() -> Option<Duration> {
+            None
+        }
+    }
+}
+
 // Memory reporting
 cfg_if! {
     if #[cfg(windows)] {
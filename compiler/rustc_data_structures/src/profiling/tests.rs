@@ -1,4 +1,8 @@
-use super::JsonTimePassesEntry;
+use super::{
+    JsonTimePassesEntry, JsonTimePassesTree, PassStats, QueryTotals, TimePassesNode,
+    TimePassesTreeData,
+};
+use std::time::Duration;
 
 #[test]
 fn with_rss() {
@@ -17,3 +21,42 @@ fn no_rss() {
         r#"{"pass":"typeck","time":56.1,"rss_start":null,"rss_end":null}"#
     )
 }
+
+#[test]
+fn pass_tree() {
+    let stats = |secs, allocated| PassStats {
+        time: Duration::from_secs_f64(secs),
+        cpu_time: Some(Duration::from_secs_f64(secs)),
+        start_rss: Some(10),
+        end_rss: Some(20),
+        allocated,
+    };
+
+    let mut data = TimePassesTreeData::default();
+    data.nodes.push(TimePassesNode {
+        name: "analysis".to_owned(),
+        thread: 1,
+        stats: Some(stats(2.0, Some(64))),
+        children: vec![1],
+    });
+    data.nodes.push(TimePassesNode {
+        name: "type_check(\"crate\")".to_owned(),
+        thread: 1,
+        stats: None,
+        children: vec![],
+    });
+    data.roots.push(0);
+    data.queries.insert("typeck", QueryTotals { count: 3, time: Duration::from_secs(1) });
+
+    let tree = JsonTimePassesTree { data: &data, total: stats(4.0, None) };
+    assert_eq!(
+        tree.to_string(),
+        concat!(
+            r#"{"total":{"time":4,"cpu_time":4,"rss_start":10,"rss_end":20,"allocated":null},"#,
+            r#""passes":[{"pass":"analysis","time":2,"cpu_time":2,"rss_start":10,"rss_end":20,"#,
+            r#""allocated":64,"thread":1,"children":[{"pass":"type_check(\"crate\")","#,
+            r#""time":null,"cpu_time":null,"rss_start":null,"rss_end":null,"allocated":null,"#,
+            r#""thread":1,"children":[]}]}],"queries":[{"query":"typeck","count":3,"time":1}]}"#,
+        )
+    );
+}
@@ -100,7 +100,7 @@ pub fn set_dyn_thread_safe_mode(mode: bool) {
         unsafe impl<T> Send for T {}
         unsafe impl<T> Sync for T {}
 
-        use std::ops::Add;
+        use std::ops::{Add, Sub};
 
         /// This is a single threaded variant of `AtomicU64`, `AtomicUsize`, etc.
         /// It has explicit ordering arguments and is only intended for use with
@@ -177,6 +177,15 @@ pub fn fetch_add(&self, val: T, _: Ordering) -> T {
             }
         }
 
+        impl<T: Sub<Output=T> + Copy> Atomic<T> {
+            #[inline]
+            pub fn fetch_sub(&self, val: T, _: Ordering) -> T {
+                let old = self.0.get();
+                self.0.set(old - val);
+                old
+            }
+        }
+
         pub type AtomicUsize = Atomic<usize>;
         pub type AtomicBool = Atomic<bool>;
         pub type AtomicU32 = Atomic<u32>;
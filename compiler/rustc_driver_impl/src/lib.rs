@@ -73,6 +73,9 @@
 #[allow(unused_imports)]
 use {do_not_use_print as print, do_not_use_print as println};
 
+// Lets the `rustc` binary report allocations made through the allocator it links.
+pub use rustc_data_structures::profiling::set_thread_allocated_bytes_fn;
+
 pub mod args;
 pub mod pretty;
 #[macro_use]
@@ -1498,7 +1501,10 @@ pub fn main() -> ! {
         RunCompiler::new(&args, &mut callbacks).run()
     });
 
-    if let Some(format) = callbacks.time_passes {
+    // The pass tree of `-Z time-passes-format=json-tree` already contains the total.
+    if let Some(format) =
+        callbacks.time_passes.filter(|&format| format != TimePassesFormat::JsonTree)
+    {
         let end_rss = get_resident_set_size();
         print_time_passes_entry("total", start_time.elapsed(), start_rss, end_rss, format);
     }
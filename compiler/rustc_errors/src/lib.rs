@@ -31,7 +31,7 @@
 use registry::Registry;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap, FxIndexSet};
 use rustc_data_structures::stable_hasher::{Hash128, StableHasher};
-use rustc_data_structures::sync::{self, IntoDynSyncSend, Lock, Lrc};
+use rustc_data_structures::sync::{self, AtomicUsize, IntoDynSyncSend, Lock, Lrc, Ordering};
 use rustc_data_structures::AtomicRef;
 pub use rustc_error_messages::{
     fallback_fluent_bundle, fluent_bundle, DelayDm, DiagnosticMessage, FluentBundle,
@@ -391,21 +391,57 @@ fn push_trailing(
 /// others log errors for later reporting.
 pub struct Handler {
     flags: HandlerFlags,
+    counts: Lrc<ErrorCounts>,
     inner: Lock<HandlerInner>,
 }
 
-/// This inner struct exists to keep it all behind a single lock;
-/// this is done to prevent possible deadlocks in a multi-threaded compiler,
-/// as well as inconsistent state observation.
-struct HandlerInner {
-    flags: HandlerFlags,
+/// The error counts of a [`Handler`]. They are only changed with the lock of
+/// the handler held, but are shared with the handler itself so that checking
+/// whether errors have been emitted, which happens all over the compiler,
+/// doesn't have to wait for threads emitting diagnostics.
+#[derive(Default)]
+struct ErrorCounts {
     /// The number of lint errors that have been emitted.
-    lint_err_count: usize,
+    lint_err_count: AtomicUsize,
     /// The number of errors that have been emitted, including duplicates.
     ///
     /// This is not necessarily the count that's reported to the user once
     /// compilation ends.
-    err_count: usize,
+    err_count: AtomicUsize,
+}
+
+impl ErrorCounts {
+    // No other data is published through the counts, so relaxed accesses
+    // suffice.
+
+    fn err_count(&self) -> usize {
+        self.err_count.load(Ordering::Relaxed)
+    }
+
+    fn lint_err_count(&self) -> usize {
+        self.lint_err_count.load(Ordering::Relaxed)
+    }
+
+    fn count(&self, lint: bool) -> &AtomicUsize {
+        if lint { &self.lint_err_count } else { &self.err_count }
+    }
+
+    fn bump(&self, lint: bool) {
+        self.count(lint).fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn unbump(&self, lint: bool) {
+        self.count(lint).fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// This inner struct exists to keep it all behind a single lock;
+/// this is done to prevent possible deadlocks in a multi-threaded compiler,
+/// as well as inconsistent state observation. Only the error counts can also
+/// be read without the lock.
+struct HandlerInner {
+    flags: HandlerFlags,
+    counts: Lrc<ErrorCounts>,
     warn_count: usize,
     deduplicated_err_count: usize,
     emitter: IntoDynSyncSend<Box<dyn Emitter + sync::Send>>,
@@ -597,12 +633,13 @@ pub fn with_emitter_and_flags(
         emitter: Box<dyn Emitter + sync::Send>,
         flags: HandlerFlags,
     ) -> Self {
+        let counts = Lrc::new(ErrorCounts::default());
         Self {
             flags,
+            counts: counts.clone(),
             inner: Lock::new(HandlerInner {
                 flags,
-                lint_err_count: 0,
-                err_count: 0,
+                counts,
                 warn_count: 0,
                 deduplicated_err_count: 0,
                 deduplicated_warn_count: 0,
@@ -655,7 +692,7 @@ pub fn can_emit_warnings(&self) -> bool {
     /// the overall count of emitted error diagnostics.
     pub fn reset_err_count(&self) {
         let mut inner = self.inner.borrow_mut();
-        inner.err_count = 0;
+        inner.counts.err_count.store(0, Ordering::Relaxed);
         inner.warn_count = 0;
         inner.deduplicated_err_count = 0;
         inner.deduplicated_warn_count = 0;
@@ -1066,18 +1103,18 @@ pub fn bug(&self, msg: impl Into<DiagnosticMessage>) -> ! {
 
     #[inline]
     pub fn err_count(&self) -> usize {
-        self.inner.borrow().err_count()
+        self.counts.err_count()
     }
 
     pub fn has_errors(&self) -> Option<ErrorGuaranteed> {
-        self.inner.borrow().has_errors().then(|| {
+        (self.counts.err_count() > 0).then(|| {
             #[allow(deprecated)]
             ErrorGuaranteed::unchecked_claim_error_was_emitted()
         })
     }
 
     pub fn has_errors_or_lint_errors(&self) -> Option<ErrorGuaranteed> {
-        self.inner.borrow().has_errors_or_lint_errors().then(|| {
+        (self.counts.err_count() > 0 || self.counts.lint_err_count() > 0).then(|| {
             #[allow(deprecated)]
             ErrorGuaranteed::unchecked_claim_error_was_emitted()
         })
@@ -1293,11 +1330,7 @@ fn emit_stashed_diagnostics(&mut self) -> Option<ErrorGuaranteed> {
         for mut diag in diags {
             // Decrement the count tracking the stash; emitting will increment it.
             if diag.is_error() {
-                if matches!(diag.level, Level::Error { lint: true }) {
-                    self.lint_err_count -= 1;
-                } else {
-                    self.err_count -= 1;
-                }
+                self.counts.unbump(matches!(diag.level, Level::Error { lint: true }));
             } else {
                 if diag.is_force_warn() {
                     self.warn_count -= 1;
@@ -1438,7 +1471,7 @@ fn emit_unused_externs(&mut self, lint_level: rustc_lint_defs::Level, unused_ext
 
     fn treat_err_as_bug(&self) -> bool {
         self.flags.treat_err_as_bug.is_some_and(|c| {
-            self.err_count() + self.lint_err_count + self.delayed_bug_count() >= c.get()
+            self.err_count() + self.lint_err_count() + self.delayed_bug_count() >= c.get()
         })
     }
 
@@ -1519,11 +1552,7 @@ fn stash(&mut self, key: (Span, StashKey), diagnostic: Diagnostic) {
         // Track the diagnostic for counts, but don't panic-if-treat-err-as-bug
         // yet; that happens when we actually emit the diagnostic.
         if diagnostic.is_error() {
-            if matches!(diagnostic.level, Level::Error { lint: true }) {
-                self.lint_err_count += 1;
-            } else {
-                self.err_count += 1;
-            }
+            self.counts.bump(matches!(diagnostic.level, Level::Error { lint: true }));
         } else {
             // Warnings are only automatically flushed if they're forced.
             if diagnostic.is_force_warn() {
@@ -1540,11 +1569,7 @@ fn stash(&mut self, key: (Span, StashKey), diagnostic: Diagnostic) {
     fn steal(&mut self, key: (Span, StashKey)) -> Option<Diagnostic> {
         let diagnostic = self.stashed_diagnostics.remove(&key)?;
         if diagnostic.is_error() {
-            if matches!(diagnostic.level, Level::Error { lint: true }) {
-                self.lint_err_count -= 1;
-            } else {
-                self.err_count -= 1;
-            }
+            self.counts.unbump(matches!(diagnostic.level, Level::Error { lint: true }));
         } else {
             if diagnostic.is_force_warn() {
                 self.warn_count -= 1;
@@ -1555,24 +1580,26 @@ fn steal(&mut self, key: (Span, StashKey)) -> Option<Diagnostic> {
 
     #[inline]
     fn err_count(&self) -> usize {
-        self.err_count
+        self.counts.err_count()
+    }
+
+    #[inline]
+    fn lint_err_count(&self) -> usize {
+        self.counts.lint_err_count()
     }
 
     fn has_errors(&self) -> bool {
         self.err_count() > 0
     }
-    fn has_errors_or_lint_errors(&self) -> bool {
-        self.has_errors() || self.lint_err_count > 0
-    }
     fn has_errors_or_delayed_span_bugs(&self) -> bool {
         self.has_errors() || !self.delayed_span_bugs.is_empty()
     }
     fn has_any_message(&self) -> bool {
-        self.err_count() > 0 || self.lint_err_count > 0 || self.warn_count > 0
+        self.err_count() > 0 || self.lint_err_count() > 0 || self.warn_count > 0
     }
 
     fn is_compilation_going_to_fail(&self) -> bool {
-        self.has_errors() || self.lint_err_count > 0 || !self.delayed_span_bugs.is_empty()
+        self.has_errors() || self.lint_err_count() > 0 || !self.delayed_span_bugs.is_empty()
     }
 
     fn abort_if_errors(&mut self) {
@@ -1604,7 +1631,7 @@ fn delay_span_bug(
         // incrementing `err_count` by one, so we need to +1 the comparing.
         // FIXME: Would be nice to increment err_count in a more coherent way.
         if self.flags.treat_err_as_bug.is_some_and(|c| {
-            self.err_count() + self.lint_err_count + self.delayed_bug_count() + 1 >= c.get()
+            self.err_count() + self.lint_err_count() + self.delayed_bug_count() + 1 >= c.get()
         }) {
             // FIXME: don't abort here if report_delayed_bugs is off
             self.span_bug(sp, msg);
@@ -1689,12 +1716,12 @@ fn flush_delayed(
     }
 
     fn bump_lint_err_count(&mut self) {
-        self.lint_err_count += 1;
+        self.counts.bump(true);
         self.panic_if_treat_err_as_bug();
     }
 
     fn bump_err_count(&mut self) {
-        self.err_count += 1;
+        self.counts.bump(false);
         self.panic_if_treat_err_as_bug();
     }
 
@@ -1705,7 +1732,7 @@ fn bump_warn_count(&mut self) {
     fn panic_if_treat_err_as_bug(&self) {
         if self.treat_err_as_bug() {
             match (
-                self.err_count() + self.lint_err_count,
+                self.err_count() + self.lint_err_count(),
                 self.delayed_bug_count(),
                 self.flags.treat_err_as_bug.map(|c| c.get()).unwrap_or(0),
             ) {
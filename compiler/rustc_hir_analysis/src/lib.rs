@@ -95,6 +95,7 @@
 pub mod structured_errors;
 mod variance;
 
+use rustc_data_structures::sync::par_for_each_in;
 use rustc_errors::ErrorGuaranteed;
 use rustc_errors::{DiagnosticMessage, SubdiagnosticMessage};
 use rustc_fluent_macro::fluent_messages;
@@ -455,7 +456,7 @@ pub fn check_crate(tcx: TyCtxt<'_>) -> Result<(), ErrorGuaranteed> {
     // FIXME(matthewjasper) We shouldn't need to use `track_errors`.
     tcx.sess.track_errors(|| {
         tcx.sess.time("type_collecting", || {
-            tcx.hir().for_each_module(|module| tcx.ensure().collect_mod_item_types(module))
+            tcx.hir().par_for_each_module(|module| tcx.ensure().collect_mod_item_types(module))
         });
     })?;
 
@@ -467,15 +468,16 @@ pub fn check_crate(tcx: TyCtxt<'_>) -> Result<(), ErrorGuaranteed> {
 
     tcx.sess.track_errors(|| {
         tcx.sess.time("impl_wf_inference", || {
-            tcx.hir().for_each_module(|module| tcx.ensure().check_mod_impl_wf(module))
+            tcx.hir().par_for_each_module(|module| tcx.ensure().check_mod_impl_wf(module))
         });
     })?;
 
     tcx.sess.track_errors(|| {
         tcx.sess.time("coherence_checking", || {
-            for &trait_def_id in tcx.all_local_trait_impls(()).keys() {
+            let trait_def_ids: Vec<DefId> = tcx.all_local_trait_impls(()).keys().copied().collect();
+            par_for_each_in(&trait_def_ids[..], |&trait_def_id| {
                 tcx.ensure().coherent_trait(trait_def_id);
-            }
+            });
 
             // these queries are executed for side-effects (error reporting):
             tcx.ensure().crate_inherent_impls(());
@@ -497,7 +499,13 @@ pub fn check_crate(tcx: TyCtxt<'_>) -> Result<(), ErrorGuaranteed> {
 
     // NOTE: This is copy/pasted in librustdoc/core.rs and should be kept in sync.
     tcx.sess.time("item_types_checking", || {
-        tcx.hir().for_each_module(|module| tcx.ensure().check_mod_item_types(module))
+        tcx.hir().par_for_each_module(|module| tcx.ensure().check_mod_item_types(module))
+    });
+
+    // Type check all bodies up front, so that it happens in parallel rather
+    // than one body at a time when `check_unused` asks for their trait imports.
+    tcx.sess.time("type_checking", || {
+        tcx.hir().par_body_owners(|body_owner_def_id| tcx.ensure().typeck(body_owner_def_id))
     });
 
     check_unused::check_crate(tcx);
@@ -340,6 +340,7 @@ pub fn run_compiler<R: Send>(config: Config, f: impl FnOnce(&Compiler) -> R + Se
                 let prof = compiler.sess.prof.clone();
 
                 prof.generic_activity("drop_compiler").run(move || drop(compiler));
+                prof.print_time_passes_tree();
                 r
             })
         },
@@ -27,11 +27,12 @@
 use rustc_session::code_stats::VTableSizeInfo;
 use rustc_session::config::{CrateType, Input, OutFileName, OutputFilenames, OutputType};
 use rustc_session::cstore::{MetadataLoader, Untracked};
+use rustc_session::errors::{NondeterminismSource, NondeterministicBuild};
 use rustc_session::output::filename_for_input;
 use rustc_session::search_paths::PathKind;
 use rustc_session::{Limit, Session};
 use rustc_span::symbol::{sym, Symbol};
-use rustc_span::FileName;
+use rustc_span::{FileName, RealFileName};
 use rustc_target::spec::PanicStrategy;
 use rustc_trait_selection::traits;
 
@@ -40,7 +41,7 @@
 use std::io::{self, BufWriter, Write};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, LazyLock};
-use std::{env, fs, iter};
+use std::{env, fs, iter, mem};
 
 pub fn parse<'a>(sess: &'a Session) -> PResult<'a, ast::Crate> {
     let krate = sess.time("parse_crate", || match &sess.io.input {
@@ -307,11 +308,36 @@ fn configure_and_expand(
 
     // Done with macro expansion!
 
+    if sess.opts.unstable_opts.deterministic {
+        sess.time("report_nondeterminism_sources", || report_nondeterminism_sources(sess));
+    }
+
     resolver.resolve_crate(&krate);
 
     krate
 }
 
+/// Reports the sources of nondeterminism found during expansion, which `-Zdeterministic` can't
+/// normalize away, and the source files whose host paths would be embedded in the output.
+fn report_nondeterminism_sources(sess: &Session) {
+    let mut sources = mem::take(&mut *sess.parse_sess.nondeterminism_sources.borrow_mut());
+    for file in sess.source_map().files().iter() {
+        if file.is_imported() {
+            continue;
+        }
+        // Remapped paths and relative paths are fine: the working directory itself is remapped.
+        match &file.name {
+            FileName::Real(RealFileName::LocalPath(path)) if path.is_absolute() => {
+                sources.push(NondeterminismSource::SourcePath { path: path.display().to_string() })
+            }
+            _ => {}
+        }
+    }
+    if !sources.is_empty() {
+        sess.emit_err(NondeterministicBuild { sources });
+    }
+}
+
 fn early_lint_checks(tcx: TyCtxt<'_>, (): ()) {
     let sess = tcx.sess;
     let (resolver, krate) = &*tcx.resolver_for_lowering(()).borrow();
@@ -792,7 +818,7 @@ fn analysis(tcx: TyCtxt<'_>, (): ()) -> Result<()> {
     });
 
     sess.time("MIR_effect_checking", || {
-        for def_id in tcx.hir().body_owners() {
+        tcx.hir().par_body_owners(|def_id| {
             tcx.ensure().thir_check_unsafety(def_id);
             if !tcx.sess.opts.unstable_opts.thir_unsafeck {
                 rustc_mir_transform::check_unsafety::check_unsafety(tcx, def_id);
@@ -808,7 +834,7 @@ fn analysis(tcx: TyCtxt<'_>, (): ()) -> Result<()> {
                 tcx.ensure().mir_drops_elaborated_and_const_checked(def_id);
                 tcx.ensure().unused_generic_params(ty::InstanceDef::Item(def_id.to_def_id()));
             }
-        }
+        });
     });
 
     if tcx.sess.opts.unstable_opts.drop_tracking_mir {
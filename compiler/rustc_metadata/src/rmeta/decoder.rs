@@ -386,12 +386,12 @@ fn cached_ty_for_shorthand<F>(&mut self, shorthand: usize, or_insert_with: F) ->
 
         let key = ty::CReaderCacheKey { cnum: Some(self.cdata().cnum), pos: shorthand };
 
-        if let Some(&ty) = tcx.ty_rcache.borrow().get(&key) {
+        if let Some(&ty) = tcx.ty_rcache.get_shard_by_value(&key).lock().get(&key) {
             return ty;
         }
 
         let ty = or_insert_with(self);
-        tcx.ty_rcache.borrow_mut().insert(key, ty);
+        tcx.ty_rcache.get_shard_by_value(&key).lock().insert(key, ty);
         ty
     }
 
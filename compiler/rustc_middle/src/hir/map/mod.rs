@@ -493,7 +493,7 @@ pub fn body_owners(self) -> impl Iterator<Item = LocalDefId> + 'hir {
 
     #[inline]
     pub fn par_body_owners(self, f: impl Fn(LocalDefId) + DynSend + DynSync) {
-        par_for_each_in(&self.tcx.hir_crate_items(()).body_owners[..], |&def_id| f(def_id));
+        self.tcx.hir_crate_items(()).par_body_owners(f)
     }
 
     pub fn ty_param_owner(self, def_id: LocalDefId) -> LocalDefId {
@@ -93,6 +93,10 @@ pub fn par_impl_items(&self, f: impl Fn(ImplItemId) + DynSend + DynSync) {
     pub fn par_foreign_items(&self, f: impl Fn(ForeignItemId) + DynSend + DynSync) {
         par_for_each_in(&self.foreign_items[..], |&id| f(id))
     }
+
+    pub fn par_body_owners(&self, f: impl Fn(LocalDefId) + DynSend + DynSync) {
+        par_for_each_in(&self.body_owners[..], |&def_id| f(def_id))
+    }
 }
 
 impl<'tcx> TyCtxt<'tcx> {
@@ -1,6 +1,6 @@
 use crate::dep_graph::{DepNode, WorkProduct, WorkProductId};
 use crate::ty::{subst::InternalSubsts, Instance, InstanceDef, SymbolName, TyCtxt};
-use rustc_attr::InlineAttr;
+use rustc_attr::{InlineAttr, OptimizeAttr};
 use rustc_data_structures::base_n;
 use rustc_data_structures::fingerprint::Fingerprint;
 use rustc_data_structures::fx::FxHashMap;
@@ -107,6 +107,13 @@ pub fn instantiation_mode(&self, tcx: TyCtxt<'tcx>) -> InstantiationMode {
                     return InstantiationMode::GloballyShared { may_conflict: false };
                 }
 
+                // Functions with an explicit optimization level are only
+                // codegened in the CGU optimized at that level, instead of
+                // being copied into the CGUs of their callers.
+                if let OptimizeAttr::Level(_) = tcx.codegen_fn_attrs(instance.def_id()).optimize {
+                    return InstantiationMode::GloballyShared { may_conflict: true };
+                }
+
                 // At this point we don't have explicit linkage and we're an
                 // inlined function. If we're inlining into all CGUs then we'll
                 // be creating a local copy per CGU.
@@ -223,6 +230,18 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// A call made by the codegened body of a function, see the `mono_callees` query.
+#[derive(Copy, Clone, Debug, HashStable)]
+pub enum MonoCallee<'tcx> {
+    /// A call to a statically known function, including drop glue and the panic functions of
+    /// assertions.
+    Direct(Instance<'tcx>),
+    /// A call through a function pointer or a trait object, whose callee isn't known statically.
+    Indirect(Span),
+    /// Inline assembly which may use the stack, e.g. to call other functions.
+    InlineAsm(Span),
+}
+
 #[derive(Debug)]
 pub struct CodegenUnit<'tcx> {
     /// A name for this CGU. Incremental compilation requires that
@@ -236,6 +255,9 @@ pub struct CodegenUnit<'tcx> {
     /// True if this is CGU is used to hold code coverage information for dead code,
     /// false otherwise.
     is_code_coverage_dead_code_cgu: bool,
+    /// The optimization level requested with `#[optimize(level = "...")]` by
+    /// the functions of this CGU, if it differs from `-Copt-level`.
+    opt_level: Option<OptLevel>,
 }
 
 /// Specifies the linkage type for a `MonoItem`.
@@ -272,6 +294,7 @@ pub fn new(name: Symbol) -> CodegenUnit<'tcx> {
             size_estimate: 0,
             primary: false,
             is_code_coverage_dead_code_cgu: false,
+            opt_level: None,
         }
     }
 
@@ -310,6 +333,14 @@ pub fn make_code_coverage_dead_code_cgu(&mut self) {
         self.is_code_coverage_dead_code_cgu = true;
     }
 
+    pub fn opt_level(&self) -> Option<OptLevel> {
+        self.opt_level
+    }
+
+    pub fn set_opt_level(&mut self, opt_level: OptLevel) {
+        self.opt_level = Some(opt_level);
+    }
+
     pub fn mangle_name(human_readable_name: &str) -> String {
         // We generate a 80 bit hash from the name. This should be enough to
         // avoid collisions and is still reasonably short for filenames.
@@ -409,10 +440,12 @@ fn hash_stable(&self, hcx: &mut StableHashingContext<'a>, hasher: &mut StableHas
             size_estimate: _,
             primary: _,
             is_code_coverage_dead_code_cgu,
+            opt_level,
         } = *self;
 
         name.hash_stable(hcx, hasher);
         is_code_coverage_dead_code_cgu.hash_stable(hcx, hasher);
+        opt_level.hash_stable(hcx, hasher);
 
         let mut items: Vec<(Fingerprint, _)> = items
             .iter()
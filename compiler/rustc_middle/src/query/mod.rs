@@ -24,7 +24,7 @@
     ConstValue, EvalToAllocationRawResult, EvalToConstValueResult, EvalToValTreeResult,
 };
 use crate::mir::interpret::{LitToConstError, LitToConstInput};
-use crate::mir::mono::CodegenUnit;
+use crate::mir::mono::{CodegenUnit, MonoCallee};
 use crate::query::erase::{erase, restore, Erase};
 use crate::query::plumbing::{query_ensure, query_get_at, DynamicQuery};
 use crate::thir;
@@ -1885,6 +1885,12 @@
         desc { "getting codegen unit `{sym}`" }
     }
 
+    /// The functions called by the codegened body of `key`, used by `-Zstack-usage-report` to
+    /// build the call graph of the crate. Calls to intrinsics and to no-op drop glue are omitted.
+    query mono_callees(key: ty::Instance<'tcx>) -> &'tcx [MonoCallee<'tcx>] {
+        desc { "collecting the callees of `{}`", key }
+    }
+
     query unused_generic_params(key: ty::InstanceDef<'tcx>) -> UnusedGenericParams {
         cache_on_disk_if { key.def_id().is_local() }
         desc {
@@ -555,8 +555,8 @@ pub struct GlobalCtxt<'tcx> {
     pub(crate) query_kinds: &'tcx [DepKindStruct<'tcx>],
 
     // Internal caches for metadata decoding. No need to track deps on this.
-    pub ty_rcache: Lock<FxHashMap<ty::CReaderCacheKey, Ty<'tcx>>>,
-    pub pred_rcache: Lock<FxHashMap<ty::CReaderCacheKey, Predicate<'tcx>>>,
+    pub ty_rcache: ShardedHashMap<ty::CReaderCacheKey, Ty<'tcx>>,
+    pub pred_rcache: ShardedHashMap<ty::CReaderCacheKey, Predicate<'tcx>>,
 
     /// Caches the results of trait selection. This cache is used
     /// for things that do not have to do with the parameters in scope.
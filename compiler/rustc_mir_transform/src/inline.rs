@@ -1,6 +1,6 @@
 //! Inlining pass for MIR functions
 use crate::deref_separator::deref_finder;
-use rustc_attr::InlineAttr;
+use rustc_attr::{InlineAttr, OptimizeAttr, OptimizeLevel};
 use rustc_hir::def_id::DefId;
 use rustc_index::bit_set::BitSet;
 use rustc_index::Idx;
@@ -84,6 +84,10 @@ fn inline<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) -> bool {
     if body.generator.is_some() {
         return false;
     }
+    // Functions with `#[optimize(level = "0")]` must stay unoptimized.
+    if let OptimizeAttr::Level(OptimizeLevel::No) = tcx.codegen_fn_attrs(def_id).optimize {
+        return false;
+    }
 
     let param_env = tcx.param_env_reveal_all_normalized(def_id);
 
@@ -386,6 +390,15 @@ fn check_codegen_attributes(
             return Err("incompatible sanitizer set");
         }
 
+        // A function with an explicit optimization level is only inlined into functions with
+        // the same level, as its body would otherwise be optimized at the level of the caller.
+        if let OptimizeAttr::Level(callee_level) = callee_attrs.optimize {
+            match self.codegen_fn_attrs.optimize {
+                OptimizeAttr::Level(caller_level) if caller_level == callee_level => {}
+                _ => return Err("incompatible optimization level"),
+            }
+        }
+
         // Two functions are compatible if the callee has no attribute (meaning
         // that it's codegen agnostic), or sets an attribute that is identical
         // to this function's attribute.
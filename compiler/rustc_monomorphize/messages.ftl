@@ -4,11 +4,21 @@ monomorphize_consider_type_length_limit =
 monomorphize_couldnt_dump_mono_stats =
     unexpected error occurred while dumping monomorphization stats: {$error}
 
+monomorphize_couldnt_read_cgu_profile =
+    failed to read codegen unit partitioning profile `{$path}`: {$error}
+
+monomorphize_couldnt_write_mono_bloat_report =
+    failed to write monomorphization bloat report `{$path}`: {$error}
+
 monomorphize_encountered_error_while_instantiating =
     the above error was encountered while instantiating `{$formatted_item}`
 
 monomorphize_fatal_error = {$error_message}
 
+monomorphize_invalid_cgu_profile =
+    invalid codegen unit partitioning profile `{$path}`
+    .note = line {$line_number} is neither `<symbol> <cost>` nor `<caller> <callee> <count>`: `{$line}`
+
 monomorphize_large_assignments =
     moving {$size} bytes
     .label = value moved from here
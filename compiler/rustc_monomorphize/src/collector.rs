@@ -164,6 +164,7 @@
 //! this is not implemented however: a mono item will be produced
 //! regardless of whether it is actually needed or not.
 
+use rustc_ast::InlineAsmOptions;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_data_structures::sync::{par_for_each_in, MTLock, MTLockRef};
 use rustc_hir as hir;
@@ -172,10 +173,10 @@
 use rustc_hir::lang_items::LangItem;
 use rustc_middle::mir::interpret::{AllocId, ConstValue};
 use rustc_middle::mir::interpret::{ErrorHandled, GlobalAlloc, Scalar};
-use rustc_middle::mir::mono::{InstantiationMode, MonoItem};
+use rustc_middle::mir::mono::{InstantiationMode, MonoCallee, MonoItem};
 use rustc_middle::mir::visit::Visitor as MirVisitor;
 use rustc_middle::mir::{self, Local, Location};
-use rustc_middle::query::TyCtxtAt;
+use rustc_middle::query::{Providers, TyCtxtAt};
 use rustc_middle::ty::adjustment::{CustomCoerceUnsized, PointerCast};
 use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::subst::{GenericArgKind, InternalSubsts};
@@ -235,6 +236,14 @@ pub fn get_user_items(&self, item: MonoItem<'tcx>) -> Option<&[MonoItem<'tcx>]>
         self.user_map.get(&item).map(|items| items.as_slice())
     }
 
+    /// Internally iterate over all items used by `item`.
+    pub fn for_each_used_item<F>(&self, item: MonoItem<'tcx>, f: F)
+    where
+        F: FnMut(MonoItem<'tcx>),
+    {
+        self.used_map.get(&item).unwrap().iter().copied().for_each(f);
+    }
+
     /// Internally iterate over all inlined items used by `item`.
     pub fn for_each_inlined_used_item<F>(&self, tcx: TyCtxt<'tcx>, item: MonoItem<'tcx>, mut f: F)
     where
@@ -767,10 +776,7 @@ fn visit_terminator(&mut self, terminator: &mir::Terminator<'tcx>, location: Loc
                 }
             }
             mir::TerminatorKind::Assert { ref msg, .. } => {
-                let lang_item = match &**msg {
-                    mir::AssertKind::BoundsCheck { .. } => LangItem::PanicBoundsCheck,
-                    _ => LangItem::Panic,
-                };
+                let lang_item = assert_panic_lang_item(&**msg);
                 let instance = Instance::mono(tcx, tcx.require_lang_item(lang_item, Some(source)));
                 if should_codegen_locally(tcx, &instance) {
                     self.output.push(create_fn_mono_item(tcx, instance, source));
@@ -857,6 +863,18 @@ fn visit_local(
     }
 }
 
+/// Returns the panic lang item called when an `Assert` terminator with this message fails, the
+/// same as the one codegen uses.
+fn assert_panic_lang_item<O>(msg: &mir::AssertKind<O>) -> LangItem {
+    match msg {
+        mir::AssertKind::BoundsCheck { .. } => LangItem::PanicBoundsCheck,
+        mir::AssertKind::MisalignedPointerDereference { .. } => {
+            LangItem::PanicMisalignedPointerDereference
+        }
+        _ => LangItem::Panic,
+    }
+}
+
 fn visit_drop_use<'tcx>(
     tcx: TyCtxt<'tcx>,
     ty: Ty<'tcx>,
@@ -1391,3 +1409,68 @@ fn collect_const_value<'tcx>(
         _ => {}
     }
 }
+
+/// Lists the calls made by the codegened body of `instance`, for tools that need the
+/// monomorphized call graph (e.g. `-Zstack-usage-report`). Calls that can't be resolved
+/// statically (through a vtable or a function pointer) are reported as indirect, and inline
+/// assembly which doesn't promise not to touch the stack is reported too.
+fn mono_callees<'tcx>(tcx: TyCtxt<'tcx>, instance: Instance<'tcx>) -> &'tcx [MonoCallee<'tcx>] {
+    let body = tcx.instance_mir(instance.def);
+    let monomorphize = |ty: Ty<'tcx>| {
+        instance.subst_mir_and_normalize_erasing_regions(
+            tcx,
+            ty::ParamEnv::reveal_all(),
+            ty::EarlyBinder::bind(ty),
+        )
+    };
+
+    let mut callees = Vec::new();
+    for data in body.basic_blocks.iter() {
+        let terminator = data.terminator();
+        let source = terminator.source_info.span;
+        let callee = match terminator.kind {
+            mir::TerminatorKind::Call { ref func, .. } => {
+                match *monomorphize(func.ty(body, tcx)).kind() {
+                    ty::FnDef(def_id, substs) => Some(ty::Instance::expect_resolve(
+                        tcx,
+                        ty::ParamEnv::reveal_all(),
+                        def_id,
+                        substs,
+                    )),
+                    _ => {
+                        callees.push(MonoCallee::Indirect(source));
+                        None
+                    }
+                }
+            }
+            mir::TerminatorKind::Drop { ref place, .. } => {
+                let ty = monomorphize(place.ty(body, tcx).ty);
+                Some(Instance::resolve_drop_in_place(tcx, ty))
+            }
+            mir::TerminatorKind::Assert { ref msg, .. } => {
+                let lang_item = assert_panic_lang_item(&**msg);
+                Some(Instance::mono(tcx, tcx.require_lang_item(lang_item, Some(source))))
+            }
+            mir::TerminatorKind::InlineAsm { options, .. } => {
+                if !options.contains(InlineAsmOptions::NOSTACK) {
+                    callees.push(MonoCallee::InlineAsm(source));
+                }
+                None
+            }
+            _ => None,
+        };
+        let Some(callee) = callee else { continue };
+        match callee.def {
+            // Intrinsics and empty drop glue don't result in a call.
+            InstanceDef::Intrinsic(_) | InstanceDef::DropGlue(_, None) => {}
+            InstanceDef::Virtual(..) => callees.push(MonoCallee::Indirect(source)),
+            _ => callees.push(MonoCallee::Direct(callee)),
+        }
+    }
+
+    tcx.arena.alloc_from_iter(callees)
+}
+
+pub fn provide(providers: &mut Providers) {
+    providers.mono_callees = mono_callees;
+}
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::fluent_generated as fluent;
 use rustc_errors::ErrorGuaranteed;
@@ -85,6 +85,29 @@ pub struct CouldntDumpMonoStats {
     pub error: String,
 }
 
+#[derive(Diagnostic)]
+#[diag(monomorphize_couldnt_write_mono_bloat_report)]
+pub struct CouldntWriteMonoBloatReport<'a> {
+    pub path: &'a Path,
+    pub error: String,
+}
+
+#[derive(Diagnostic)]
+#[diag(monomorphize_couldnt_read_cgu_profile)]
+pub struct CouldntReadCguProfile<'a> {
+    pub path: &'a Path,
+    pub error: String,
+}
+
+#[derive(Diagnostic)]
+#[diag(monomorphize_invalid_cgu_profile)]
+#[note]
+pub struct InvalidCguProfile<'a> {
+    pub path: &'a Path,
+    pub line_number: usize,
+    pub line: &'a str,
+}
+
 #[derive(Diagnostic)]
 #[diag(monomorphize_encountered_error_while_instantiating)]
 pub struct EncounteredErrorWhileInstantiating {
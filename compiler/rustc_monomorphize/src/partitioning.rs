@@ -91,13 +91,36 @@
 //! Note though that as a side-effect of creating a codegen units per
 //! source-level module, functions from the same module will be available for
 //! inlining, even when they are not marked `#[inline]`.
+//!
+//! Profile-Guided Partitioning
+//! ---------------------------
+//! The size estimates used to balance codegen units are based on MIR and are
+//! only loosely related to how long LLVM takes to compile a codegen unit. With
+//! `-Z cgu-partitioning-profile=<file>`, the partitioner instead balances the
+//! codegen units by per-item costs measured by a previous compilation (e.g.
+//! LLVM instruction counts or build times): it merges the cheapest codegen
+//! units along the hottest call edges of the profile, so that LLVM can inline
+//! across them, and afterwards splits codegen units that are much costlier
+//! than the average one. The MIR-based size estimates are still what decides
+//! whether a codegen unit is too small to stand on its own. See [`CguProfile`]
+//! for the format of the profile.
+//!
+//! Per-Function Optimization Levels
+//! --------------------------------
+//! LLVM optimizes a whole module at a single level, so the functions with an
+//! `#[optimize(level = "...")]` differing from `-Copt-level` are placed into
+//! one codegen unit per requested level, which the backend then optimizes at
+//! that level. These codegen units are never merged with others, so they come
+//! on top of the `-Ccodegen-units` limit.
 
 use std::cmp;
 use std::collections::hash_map::Entry;
 use std::fs::{self, File};
 use std::io::{BufWriter, Write};
+use std::mem;
 use std::path::{Path, PathBuf};
 
+use rustc_attr::OptimizeAttr;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_data_structures::sync;
 use rustc_hir::def::DefKind;
@@ -112,17 +135,67 @@
 use rustc_middle::query::Providers;
 use rustc_middle::ty::print::{characteristic_def_id_of_type, with_no_trimmed_paths};
 use rustc_middle::ty::{self, visit::TypeVisitableExt, InstanceDef, TyCtxt};
-use rustc_session::config::{DumpMonoStatsFormat, SwitchWithOptPath};
+use rustc_session::config::{DumpMonoStatsFormat, OptLevel, SwitchWithOptPath};
 use rustc_session::CodegenUnits;
 use rustc_span::symbol::Symbol;
 
 use crate::collector::UsageMap;
 use crate::collector::{self, MonoItemCollectionMode};
-use crate::errors::{CouldntDumpMonoStats, SymbolAlreadyDefined, UnknownCguCollectionMode};
+use crate::errors::{
+    CouldntDumpMonoStats, CouldntReadCguProfile, CouldntWriteMonoBloatReport, InvalidCguProfile,
+    SymbolAlreadyDefined, UnknownCguCollectionMode,
+};
+
+#[cfg(test)]
+mod tests;
 
 struct PartitioningCx<'a, 'tcx> {
     tcx: TyCtxt<'tcx>,
     usage_map: &'a UsageMap<'tcx>,
+    profile: Option<&'a CguProfile>,
+}
+
+/// Costs and call counts measured by a previous compilation, read from the file
+/// given to `-Z cgu-partitioning-profile`.
+///
+/// The profile is a text file with one entry per line, where `#` starts a
+/// comment and symbols are the mangled symbol names of mono items:
+///
+/// - `<symbol> <cost>` is the cost of compiling the item, in whatever unit
+///   the profile uses consistently (e.g. LLVM instructions or microseconds).
+///   Items missing from the profile are assumed to cost as much per unit of
+///   MIR size estimate as the profiled items do on average.
+/// - `<caller symbol> <callee symbol> <count>` is how often a call edge was
+///   taken at runtime.
+#[derive(Default, Debug)]
+struct CguProfile {
+    costs: FxHashMap<String, u64>,
+    edges: Vec<(String, String, u64)>,
+}
+
+impl CguProfile {
+    /// Parses a profile, returning the line number and contents of the first
+    /// malformed line on failure.
+    fn parse(contents: &str) -> Result<CguProfile, (usize, &str)> {
+        let mut profile = CguProfile::default();
+        for (index, line) in contents.lines().enumerate() {
+            let entry = line.split('#').next().unwrap();
+            let fields: Vec<&str> = entry.split_whitespace().collect();
+            match fields[..] {
+                [] => {}
+                [symbol, cost] => {
+                    let cost = cost.parse().map_err(|_| (index + 1, line))?;
+                    profile.costs.insert(symbol.to_owned(), cost);
+                }
+                [caller, callee, count] => {
+                    let count = count.parse().map_err(|_| (index + 1, line))?;
+                    profile.edges.push((caller.to_owned(), callee.to_owned(), count));
+                }
+                _ => return Err((index + 1, line)),
+            }
+        }
+        Ok(profile)
+    }
 }
 
 struct PlacedMonoItems<'tcx> {
@@ -148,7 +221,16 @@ fn partition<'tcx, I>(
 {
     let _prof_timer = tcx.prof.generic_activity("cgu_partitioning");
 
-    let cx = &PartitioningCx { tcx, usage_map };
+    let profile = tcx.sess.opts.unstable_opts.cgu_partitioning_profile.as_ref().map(|path| {
+        let contents = fs::read_to_string(path).unwrap_or_else(|error| {
+            tcx.sess.emit_fatal(CouldntReadCguProfile { path, error: error.to_string() })
+        });
+        CguProfile::parse(&contents).unwrap_or_else(|(line_number, line)| {
+            tcx.sess.emit_fatal(InvalidCguProfile { path, line_number, line })
+        })
+    });
+
+    let cx = &PartitioningCx { tcx, usage_map, profile: profile.as_ref() };
 
     // Place all mono items into a codegen unit. `place_mono_items` is
     // responsible for initializing the CGU size estimates.
@@ -232,19 +314,27 @@ fn place_mono_items<'tcx, I>(cx: &PartitioningCx<'_, 'tcx>, mono_items: I) -> Pl
 
         let characteristic_def_id = characteristic_def_id_of_mono_item(cx.tcx, mono_item);
         let is_volatile = is_incremental_build && mono_item.is_generic_fn();
+        let opt_level = requested_opt_level(cx.tcx, mono_item);
 
-        let cgu_name = match characteristic_def_id {
-            Some(def_id) => compute_codegen_unit_name(
+        let cgu_name = match (opt_level, characteristic_def_id) {
+            (Some(opt_level), _) => opt_level_cgu_name(cgu_name_builder, opt_level),
+            (None, Some(def_id)) => compute_codegen_unit_name(
                 cx.tcx,
                 cgu_name_builder,
                 def_id,
                 is_volatile,
                 cgu_name_cache,
             ),
-            None => fallback_cgu_name(cgu_name_builder),
+            (None, None) => fallback_cgu_name(cgu_name_builder),
         };
 
-        let cgu = codegen_units.entry(cgu_name).or_insert_with(|| CodegenUnit::new(cgu_name));
+        let cgu = codegen_units.entry(cgu_name).or_insert_with(|| {
+            let mut cgu = CodegenUnit::new(cgu_name);
+            if let Some(opt_level) = opt_level {
+                cgu.set_opt_level(opt_level);
+            }
+            cgu
+        });
 
         let mut can_be_internalized = true;
         let (linkage, visibility) = mono_item_linkage_and_visibility(
@@ -273,6 +363,24 @@ fn place_mono_items<'tcx, I>(cx: &PartitioningCx<'_, 'tcx>, mono_items: I) -> Pl
             // This is a CGU-private copy.
             cgu.items_mut().insert(inlined_item, (Linkage::Internal, Visibility::Default));
         }
+
+        // Without optimizations, `#[inline]` functions are not copied into the
+        // CGUs of their callers. The functions optimized at a requested level
+        // still need private copies of them to inline.
+        if opt_level.is_some() {
+            let mut inlinable_items = FxHashSet::default();
+            get_reachable_shared_inline_items(
+                cx.tcx,
+                mono_item,
+                cx.usage_map,
+                &mut inlinable_items,
+            );
+            for inlinable_item in inlinable_items {
+                cgu.items_mut()
+                    .entry(inlinable_item)
+                    .or_insert((Linkage::Internal, Visibility::Default));
+            }
+        }
     }
 
     // Always ensure we have at least one CGU; otherwise, if we have a
@@ -295,21 +403,50 @@ fn place_mono_items<'tcx, I>(cx: &PartitioningCx<'_, 'tcx>, mono_items: I) -> Pl
         unique_inlined_stats: (num_unique_inlined_items, unique_inlined_items_size),
     };
 
-    fn get_reachable_inlined_items<'tcx>(
+    // Like `get_reachable_inlined_items`, but for the `#[inline]` functions
+    // which are instantiated once instead of being copied into every CGU.
+    fn get_reachable_shared_inline_items<'tcx>(
         tcx: TyCtxt<'tcx>,
         item: MonoItem<'tcx>,
         usage_map: &UsageMap<'tcx>,
         visited: &mut FxHashSet<MonoItem<'tcx>>,
     ) {
-        usage_map.for_each_inlined_used_item(tcx, item, |inlined_item| {
-            let is_new = visited.insert(inlined_item);
-            if is_new {
-                get_reachable_inlined_items(tcx, inlined_item, usage_map, visited);
+        usage_map.for_each_used_item(item, |used_item| {
+            // Functions with an explicit optimization level are shared too, but
+            // must not be optimized at the level of their callers.
+            let has_opt_level = match used_item {
+                MonoItem::Fn(instance) => matches!(
+                    tcx.codegen_fn_attrs(instance.def_id()).optimize,
+                    OptimizeAttr::Level(_)
+                ),
+                MonoItem::Static(_) | MonoItem::GlobalAsm(_) => false,
+            };
+            let is_shared_inline = used_item.instantiation_mode(tcx)
+                == InstantiationMode::GloballyShared { may_conflict: true }
+                && !has_opt_level;
+            if is_shared_inline && visited.insert(used_item) {
+                get_reachable_shared_inline_items(tcx, used_item, usage_map, visited);
             }
         });
     }
 }
 
+// Collects the inlined items that are reachable from `item` without going via
+// another root item into `visited`.
+fn get_reachable_inlined_items<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    item: MonoItem<'tcx>,
+    usage_map: &UsageMap<'tcx>,
+    visited: &mut FxHashSet<MonoItem<'tcx>>,
+) {
+    usage_map.for_each_inlined_used_item(tcx, item, |inlined_item| {
+        let is_new = visited.insert(inlined_item);
+        if is_new {
+            get_reachable_inlined_items(tcx, inlined_item, usage_map, visited);
+        }
+    });
+}
+
 // This function requires the CGUs to be sorted by name on input, and ensures
 // they are sorted by name on return, for deterministic behaviour.
 fn merge_codegen_units<'tcx>(
@@ -321,6 +458,12 @@ fn merge_codegen_units<'tcx>(
     // A sorted order here ensures merging is deterministic.
     assert!(codegen_units.is_sorted_by(|a, b| Some(a.name().as_str().cmp(b.name().as_str()))));
 
+    // The CGUs with a requested optimization level can't be merged with CGUs
+    // optimized at another level, so set them aside.
+    let (mut opt_level_cgus, other_cgus): (Vec<_>, Vec<_>) =
+        mem::take(codegen_units).into_iter().partition(|cgu| cgu.opt_level().is_some());
+    *codegen_units = other_cgus;
+
     // This map keeps track of what got merged into what.
     let mut cgu_contents: FxHashMap<Symbol, Vec<Symbol>> =
         codegen_units.iter().map(|cgu| (cgu.name(), vec![cgu.name()])).collect();
@@ -332,6 +475,8 @@ fn merge_codegen_units<'tcx>(
     // common in larger programs, so this isn't all that large.
     const NON_INCR_MIN_CGU_SIZE: usize = 1800;
 
+    let mut profiled = cx.profile.map(|profile| ProfiledCgus::new(cx, profile, codegen_units));
+
     // Repeatedly merge the two smallest codegen units as long as:
     // - we have more CGUs than the upper limit, or
     // - (Non-incremental builds only) the user didn't specify a CGU count, and
@@ -348,28 +493,49 @@ fn merge_codegen_units<'tcx>(
             && codegen_units.len() > 1
             && codegen_units.iter().any(|cgu| cgu.size_estimate() < NON_INCR_MIN_CGU_SIZE))
     {
-        // Sort small cgus to the back.
-        codegen_units.sort_by_cached_key(|cgu| cmp::Reverse(cgu.size_estimate()));
+        // Sort small cgus to the back. With a profile, the profiled costs of
+        // the CGUs decide what is small.
+        match &profiled {
+            Some(profiled) => {
+                codegen_units.sort_by_cached_key(|cgu| cmp::Reverse(profiled.costs[&cgu.name()]))
+            }
+            None => codegen_units.sort_by_cached_key(|cgu| cmp::Reverse(cgu.size_estimate())),
+        }
 
         let mut smallest = codegen_units.pop().unwrap();
-        let second_smallest = codegen_units.last_mut().unwrap();
 
-        // Move the items from `smallest` to `second_smallest`. Some of them
-        // may be duplicate inlined items, in which case the destination CGU is
+        // Usually `smallest` is merged into the second smallest CGU. With a
+        // profile, it is instead merged into the CGU it shares the hottest
+        // call edges with, so that LLVM can inline along them.
+        let target_index = profiled
+            .as_ref()
+            .and_then(|profiled| profiled.merge_target(&smallest, &codegen_units[..]))
+            .unwrap_or(codegen_units.len() - 1);
+        let target = &mut codegen_units[target_index];
+
+        // Move the items from `smallest` to `target`. Some of them may be
+        // duplicate inlined items, in which case the destination CGU is
         // unaffected. Recalculate size estimates afterwards.
-        second_smallest.items_mut().extend(smallest.items_mut().drain());
-        second_smallest.compute_size_estimate(cx.tcx);
+        target.items_mut().extend(smallest.items_mut().drain());
+        target.compute_size_estimate(cx.tcx);
+        if let Some(profiled) = &mut profiled {
+            profiled.merge(smallest.name(), target);
+        }
 
-        // Record that `second_smallest` now contains all the stuff that was
-        // in `smallest` before.
+        // Record that `target` now contains all the stuff that was in
+        // `smallest` before.
         let mut consumed_cgu_names = cgu_contents.remove(&smallest.name()).unwrap();
-        cgu_contents.get_mut(&second_smallest.name()).unwrap().append(&mut consumed_cgu_names);
+        cgu_contents.get_mut(&target.name()).unwrap().append(&mut consumed_cgu_names);
 
-        debug!(
-            "CodegenUnit {} merged into CodegenUnit {}",
-            smallest.name(),
-            second_smallest.name()
-        );
+        debug!("CodegenUnit {} merged into CodegenUnit {}", smallest.name(), target.name());
+    }
+
+    // Splitting CGUs changes their contents from one compilation to the next,
+    // which defeats the reuse of incremental builds.
+    if let Some(profiled) = &mut profiled {
+        if cx.tcx.sess.opts.incremental.is_none() {
+            split_costly_codegen_units(cx, profiled, codegen_units, NON_INCR_MIN_CGU_SIZE);
+        }
     }
 
     let cgu_name_builder = &mut CodegenUnitNameBuilder::new(cx.tcx);
@@ -435,7 +601,8 @@ fn merge_codegen_units<'tcx>(
         // If we didn't zero-pad the sorted-by-name order would be `XYZ-cgu.0`,
         // `XYZ-cgu.1`, `XYZ-cgu.10`, `XYZ-cgu.11`, ..., `XYZ-cgu.2`, etc.
         codegen_units.sort_by_key(|cgu| cmp::Reverse(cgu.size_estimate()));
-        let num_digits = codegen_units.len().ilog10() as usize + 1;
+        // All CGUs may have been set aside for their optimization level.
+        let num_digits = codegen_units.len().checked_ilog10().unwrap_or(0) as usize + 1;
         for (index, cgu) in codegen_units.iter_mut().enumerate() {
             // Note: `WorkItem::short_description` depends on this name ending
             // with `-cgu.` followed by a numeric suffix. Please keep it in
@@ -446,6 +613,258 @@ fn merge_codegen_units<'tcx>(
             cgu.set_name(numbered_codegen_unit_name);
         }
     }
+
+    codegen_units.append(&mut opt_level_cgus);
+    codegen_units.sort_by(|a, b| a.name().as_str().cmp(b.name().as_str()));
+}
+
+/// The profiled costs and root items of the codegen units being merged, keyed
+/// by CGU name.
+struct ProfiledCgus<'a, 'tcx> {
+    profile: &'a CguProfile,
+    /// The cost of every mono item, see [`CguProfile`] for items that are
+    /// missing from the profile.
+    item_costs: FxHashMap<MonoItem<'tcx>, u64>,
+    costs: FxHashMap<Symbol, u64>,
+    /// Inlined items are copied into every CGU using them, so only the root
+    /// items of a CGU determine which calls cross CGU boundaries.
+    root_symbols: FxHashMap<Symbol, FxHashSet<&'tcx str>>,
+}
+
+impl<'a, 'tcx> ProfiledCgus<'a, 'tcx> {
+    fn new(
+        cx: &PartitioningCx<'_, 'tcx>,
+        profile: &'a CguProfile,
+        codegen_units: &[CodegenUnit<'tcx>],
+    ) -> Self {
+        let symbols: FxHashMap<MonoItem<'tcx>, &'tcx str> = codegen_units
+            .iter()
+            .flat_map(|cgu| cgu.items().keys())
+            .map(|&mono_item| (mono_item, mono_item.symbol_name(cx.tcx).name))
+            .collect();
+
+        // Express the MIR size estimates of unprofiled items in the unit of
+        // the profile, so that both kinds of items can be balanced together.
+        let (mut profiled_cost, mut profiled_size) = (0u64, 0u64);
+        for (mono_item, symbol) in &symbols {
+            if let Some(&cost) = profile.costs.get(*symbol) {
+                profiled_cost += cost;
+                profiled_size += mono_item.size_estimate(cx.tcx) as u64;
+            }
+        }
+        let item_costs = symbols
+            .iter()
+            .map(|(&mono_item, symbol)| {
+                let cost = match profile.costs.get(*symbol) {
+                    Some(&cost) => cost,
+                    None if profiled_size == 0 => mono_item.size_estimate(cx.tcx) as u64,
+                    None => mono_item.size_estimate(cx.tcx) as u64 * profiled_cost / profiled_size,
+                };
+                (mono_item, cost)
+            })
+            .collect();
+
+        let mut profiled = ProfiledCgus {
+            profile,
+            item_costs,
+            costs: Default::default(),
+            root_symbols: Default::default(),
+        };
+        for cgu in codegen_units {
+            profiled.costs.insert(cgu.name(), profiled.cost(cgu));
+            let root_symbols = cgu
+                .items()
+                .keys()
+                .filter(|mono_item| {
+                    matches!(
+                        mono_item.instantiation_mode(cx.tcx),
+                        InstantiationMode::GloballyShared { .. }
+                    )
+                })
+                .map(|mono_item| symbols[mono_item])
+                .collect();
+            profiled.root_symbols.insert(cgu.name(), root_symbols);
+        }
+        profiled
+    }
+
+    fn cost(&self, cgu: &CodegenUnit<'tcx>) -> u64 {
+        cgu.items().keys().map(|mono_item| self.item_costs[mono_item]).sum()
+    }
+
+    /// Returns the index of the CGU in `codegen_units` that `cgu` should be
+    /// merged into, if it shares profiled calls with any of them. Only CGUs
+    /// that would not become costlier than the currently costliest CGU by
+    /// merging `cgu` into them are considered, to keep the CGUs balanced.
+    /// `codegen_units` must be sorted by decreasing cost.
+    fn merge_target(
+        &self,
+        cgu: &CodegenUnit<'tcx>,
+        codegen_units: &[CodegenUnit<'tcx>],
+    ) -> Option<usize> {
+        let cost = self.costs[&cgu.name()];
+        let max_cost = self.costs[&codegen_units.first()?.name()];
+        let candidates = codegen_units
+            .iter()
+            .enumerate()
+            .filter(|(_, candidate)| self.costs[&candidate.name()] + cost <= max_cost)
+            .map(|(index, candidate)| (index, &self.root_symbols[&candidate.name()]));
+        hottest_merge_target(self.profile, &self.root_symbols[&cgu.name()], candidates)
+    }
+
+    /// Records that the CGU named `merged` has been merged into `target`.
+    fn merge(&mut self, merged: Symbol, target: &CodegenUnit<'tcx>) {
+        self.costs.remove(&merged);
+        self.costs.insert(target.name(), self.cost(target));
+        let root_symbols = self.root_symbols.remove(&merged).unwrap();
+        self.root_symbols.get_mut(&target.name()).unwrap().extend(root_symbols);
+    }
+}
+
+/// Returns the index of the candidate that shares the most profiled calls with
+/// the CGU whose root items have the symbols `own_symbols`. The candidates are
+/// given as their index and the symbols of their root items. Of equally hot
+/// candidates, the one with the highest index is returned.
+fn hottest_merge_target<'c, 's>(
+    profile: &CguProfile,
+    own_symbols: &FxHashSet<&str>,
+    candidates: impl Iterator<Item = (usize, &'c FxHashSet<&'s str>)>,
+) -> Option<usize> {
+    let mut calls_with: FxHashMap<&str, u64> = FxHashMap::default();
+    for (caller, callee, count) in &profile.edges {
+        let other = match (own_symbols.contains(&**caller), own_symbols.contains(&**callee)) {
+            (true, false) => callee.as_str(),
+            (false, true) => caller.as_str(),
+            _ => continue,
+        };
+        *calls_with.entry(other).or_default() += count;
+    }
+    if calls_with.is_empty() {
+        return None;
+    }
+
+    candidates
+        .map(|(index, symbols)| {
+            let calls: u64 = symbols.iter().filter_map(|symbol| calls_with.get(*symbol)).sum();
+            (index, calls)
+        })
+        .filter(|&(_, calls)| calls > 0)
+        .max_by_key(|&(index, calls)| (calls, index))
+        .map(|(index, _)| index)
+}
+
+/// Splits the costliest CGU in two as long as its profiled cost is more than
+/// twice the average and there are fewer CGUs than allowed. Each half keeps
+/// the inlined items its root items use. A CGU is not split if that would
+/// leave one of the halves smaller than `min_size` according to the MIR size
+/// estimates.
+fn split_costly_codegen_units<'tcx>(
+    cx: &PartitioningCx<'_, 'tcx>,
+    profiled: &mut ProfiledCgus<'_, 'tcx>,
+    codegen_units: &mut Vec<CodegenUnit<'tcx>>,
+    min_size: usize,
+) {
+    let cgu_name_builder = &mut CodegenUnitNameBuilder::new(cx.tcx);
+    let mut num_splits = 0;
+    while !codegen_units.is_empty() && codegen_units.len() < cx.tcx.sess.codegen_units().as_usize()
+    {
+        let average = profiled.costs.values().sum::<u64>() / codegen_units.len() as u64;
+        let (costliest_index, costliest) = codegen_units
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, cgu)| profiled.costs[&cgu.name()])
+            .unwrap();
+        if profiled.costs[&costliest.name()] <= 2 * average {
+            break;
+        }
+
+        // Sort the root items for `split_costs` to be deterministic.
+        let mut roots: Vec<_> = costliest
+            .items()
+            .keys()
+            .copied()
+            .filter(|mono_item| {
+                matches!(
+                    mono_item.instantiation_mode(cx.tcx),
+                    InstantiationMode::GloballyShared { .. }
+                )
+            })
+            .map(|mono_item| (mono_item, mono_item.symbol_name(cx.tcx).name))
+            .collect();
+        roots.sort_unstable_by_key(|&(_, symbol)| symbol);
+        let costs: Vec<u64> =
+            roots.iter().map(|(mono_item, _)| profiled.item_costs[mono_item]).collect();
+        let moved = split_costs(&costs);
+
+        let root_size = |moved_half: bool| -> usize {
+            roots
+                .iter()
+                .zip(&moved)
+                .filter(|&(_, &is_moved)| is_moved == moved_half)
+                .map(|((mono_item, _), _)| mono_item.size_estimate(cx.tcx))
+                .sum()
+        };
+        if root_size(true) < min_size || root_size(false) < min_size {
+            break;
+        }
+
+        let costliest = &mut codegen_units[costliest_index];
+        num_splits += 1;
+        let name = cgu_name_builder.build_cgu_name_no_mangle(
+            LOCAL_CRATE,
+            &["split"],
+            Some(num_splits.to_string()),
+        );
+        let mut new_cgu = CodegenUnit::new(name);
+
+        // Move the root items, and give both halves the inlined items they use.
+        let (mut kept_inlined, mut moved_inlined) = (FxHashSet::default(), FxHashSet::default());
+        for ((mono_item, _), is_moved) in roots.iter().zip(moved) {
+            if is_moved {
+                let linkage_and_visibility = costliest.items_mut().remove(mono_item).unwrap();
+                new_cgu.items_mut().insert(*mono_item, linkage_and_visibility);
+                get_reachable_inlined_items(cx.tcx, *mono_item, cx.usage_map, &mut moved_inlined);
+            } else {
+                get_reachable_inlined_items(cx.tcx, *mono_item, cx.usage_map, &mut kept_inlined);
+            }
+        }
+        for inlined_item in moved_inlined {
+            new_cgu.items_mut().insert(inlined_item, (Linkage::Internal, Visibility::Default));
+        }
+        costliest.items_mut().retain(|mono_item, _| {
+            kept_inlined.contains(mono_item)
+                || matches!(
+                    mono_item.instantiation_mode(cx.tcx),
+                    InstantiationMode::GloballyShared { .. }
+                )
+        });
+
+        costliest.compute_size_estimate(cx.tcx);
+        new_cgu.compute_size_estimate(cx.tcx);
+        profiled.costs.insert(costliest.name(), profiled.cost(costliest));
+        profiled.costs.insert(new_cgu.name(), profiled.cost(&new_cgu));
+        debug!("CodegenUnit {} split off from CodegenUnit {}", new_cgu.name(), costliest.name());
+        codegen_units.push(new_cgu);
+    }
+}
+
+/// Splits items with the given costs in two halves of about the same total
+/// cost, returning for every item whether it goes into the second half.
+fn split_costs(costs: &[u64]) -> Vec<bool> {
+    let mut order: Vec<usize> = (0..costs.len()).collect();
+    order.sort_by_key(|&index| cmp::Reverse(costs[index]));
+
+    let mut moved = vec![false; costs.len()];
+    let (mut kept_cost, mut moved_cost) = (0, 0);
+    for index in order {
+        if moved_cost < kept_cost {
+            moved[index] = true;
+            moved_cost += costs[index];
+        } else {
+            kept_cost += costs[index];
+        }
+    }
+    moved
 }
 
 fn internalize_symbols<'tcx>(
@@ -657,6 +1076,36 @@ fn compute_codegen_unit_name(
     })
 }
 
+/// The optimization level requested with `#[optimize(level = "...")]` by a
+/// function, if it differs from `-Copt-level`.
+fn requested_opt_level<'tcx>(tcx: TyCtxt<'tcx>, mono_item: MonoItem<'tcx>) -> Option<OptLevel> {
+    let MonoItem::Fn(instance) = mono_item else { return None };
+    match tcx.codegen_fn_attrs(instance.def_id()).optimize {
+        OptimizeAttr::Level(level) => {
+            Some(level.opt_level()).filter(|&opt_level| opt_level != tcx.sess.opts.optimize)
+        }
+        OptimizeAttr::None | OptimizeAttr::Speed | OptimizeAttr::Size => None,
+    }
+}
+
+// The functions optimized at a given `#[optimize(level = "...")]` go into this.
+fn opt_level_cgu_name(
+    name_builder: &mut CodegenUnitNameBuilder<'_>,
+    opt_level: OptLevel,
+) -> Symbol {
+    let level = match opt_level {
+        OptLevel::No => "0",
+        OptLevel::Less => "1",
+        OptLevel::Default => "2",
+        OptLevel::Aggressive => "3",
+        OptLevel::Size => "s",
+        OptLevel::SizeMin => "z",
+    };
+    // Named like the numbered CGUs of non-incremental builds, e.g.
+    // `regex.f10ba03eb5ec7975-cgu.O3`, see `WorkItem::short_description`.
+    name_builder.build_cgu_name(LOCAL_CRATE, &["cgu"], Some(format!("O{level}")))
+}
+
 // Anything we can't find a proper codegen unit for goes into this.
 fn fallback_cgu_name(name_builder: &mut CodegenUnitNameBuilder<'_>) -> Symbol {
     name_builder.build_cgu_name(LOCAL_CRATE, &["fallback"], Some("cgu"))
@@ -1096,6 +1545,12 @@ fn collect_and_partition_mono_items(tcx: TyCtxt<'_>, (): ()) -> (&DefIdSet, &[Co
         }
     }
 
+    if let Some(ref path) = tcx.sess.opts.unstable_opts.mono_bloat_report {
+        if let Err(err) = dump_mono_bloat_report(tcx, &items, &usage_map, codegen_units, path) {
+            tcx.sess.emit_fatal(CouldntWriteMonoBloatReport { path, error: err.to_string() });
+        }
+    }
+
     if tcx.sess.opts.unstable_opts.print_mono_items.is_some() {
         let mut item_to_cgus: FxHashMap<_, Vec<_>> = Default::default();
 
@@ -1227,6 +1682,138 @@ struct MonoItem {
     Ok(())
 }
 
+/// Writes a report attributing every mono item to the definition it was
+/// instantiated from and to the chain of items that caused it to be
+/// instantiated, grouped by the crate of the definition.
+fn dump_mono_bloat_report<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    items: &FxHashSet<MonoItem<'tcx>>,
+    usage_map: &UsageMap<'tcx>,
+    codegen_units: &[CodegenUnit<'tcx>],
+    path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // Chains are cut off after this many items to keep the report readable
+    // for deep call graphs.
+    const MAX_CHAIN_LEN: usize = 16;
+
+    #[derive(serde::Serialize)]
+    struct Instance {
+        name: String,
+        /// The MIR-based estimate also used for partitioning. The report is
+        /// written before codegen, so the size of the LLVM IR isn't known.
+        mir_size_estimate: usize,
+        /// The number of CGUs containing a copy of the item.
+        copies: usize,
+        /// The items through which this one was instantiated, starting with
+        /// the one using it directly.
+        instantiated_by: Vec<String>,
+    }
+
+    #[derive(serde::Serialize)]
+    struct Definition {
+        name: String,
+        total_mir_size_estimate: usize,
+        instances: Vec<Instance>,
+    }
+
+    #[derive(serde::Serialize)]
+    struct Crate {
+        name: String,
+        total_mir_size_estimate: usize,
+        definitions: Vec<Definition>,
+    }
+
+    let names: FxHashMap<MonoItem<'tcx>, String> =
+        items.iter().map(|&item| (item, with_no_trimmed_paths!(item.to_string()))).collect();
+
+    let mut copies: FxHashMap<MonoItem<'tcx>, usize> = Default::default();
+    for cgu in codegen_units {
+        for &mono_item in cgu.items().keys() {
+            *copies.entry(mono_item).or_default() += 1;
+        }
+    }
+
+    // Follow the users of `item` back to a root. An item can have many users;
+    // picking the one with the smallest name keeps the report deterministic.
+    let instantiation_chain = |item: MonoItem<'tcx>| {
+        let mut chain = Vec::new();
+        let mut visited = FxHashSet::from_iter([item]);
+        let mut current = item;
+        while chain.len() < MAX_CHAIN_LEN {
+            let Some(user) = usage_map
+                .get_user_items(current)
+                .unwrap_or_default()
+                .iter()
+                .filter(|user| !visited.contains(*user))
+                .min_by_key(|user| &names[*user])
+            else {
+                break;
+            };
+            visited.insert(*user);
+            chain.push(names[user].clone());
+            current = *user;
+        }
+        chain
+    };
+
+    let mut items_per_def_id: FxHashMap<DefId, Vec<Instance>> = Default::default();
+    for &item in items {
+        let mir_size_estimate = item.size_estimate(tcx);
+        items_per_def_id.entry(item.def_id()).or_default().push(Instance {
+            name: names[&item].clone(),
+            mir_size_estimate,
+            copies: copies.get(&item).copied().unwrap_or(0),
+            instantiated_by: instantiation_chain(item),
+        });
+    }
+
+    let mut definitions_per_crate: FxHashMap<_, Vec<Definition>> = Default::default();
+    for (def_id, mut instances) in items_per_def_id {
+        // Heaviest instances first, ties broken by name for determinism.
+        instances.sort_unstable_by(|a, b| {
+            (b.mir_size_estimate * b.copies)
+                .cmp(&(a.mir_size_estimate * a.copies))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        definitions_per_crate.entry(def_id.krate).or_default().push(Definition {
+            name: with_no_trimmed_paths!(tcx.def_path_str(def_id)),
+            total_mir_size_estimate: instances.iter().map(|i| i.mir_size_estimate * i.copies).sum(),
+            instances,
+        });
+    }
+
+    let mut crates: Vec<_> = definitions_per_crate
+        .into_iter()
+        .map(|(krate, mut definitions)| {
+            definitions.sort_unstable_by(|a, b| {
+                b.total_mir_size_estimate
+                    .cmp(&a.total_mir_size_estimate)
+                    .then_with(|| a.name.cmp(&b.name))
+            });
+            Crate {
+                name: tcx.crate_name(krate).to_string(),
+                total_mir_size_estimate: definitions
+                    .iter()
+                    .map(|d| d.total_mir_size_estimate)
+                    .sum(),
+                definitions,
+            }
+        })
+        .collect();
+    crates.sort_unstable_by(|a, b| {
+        b.total_mir_size_estimate.cmp(&a.total_mir_size_estimate).then_with(|| a.name.cmp(&b.name))
+    });
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(&mut file, &crates)?;
+    file.flush()?;
+
+    Ok(())
+}
+
 fn codegened_and_inlined_items(tcx: TyCtxt<'_>, (): ()) -> &DefIdSet {
     let (items, cgus) = tcx.collect_and_partition_mono_items(());
     let mut visited = DefIdSet::default();
@@ -0,0 +1,75 @@
+use rustc_data_structures::fx::FxHashSet;
+
+use super::{hottest_merge_target, split_costs, CguProfile};
+
+#[test]
+fn parse_profile() {
+    let profile = CguProfile::parse(
+        "# costs\n\
+         _ZN1a3foo 100\n\
+         \n\
+         _ZN1a3bar 20 # trailing comment\n\
+         _ZN1a3foo _ZN1a3bar 7\n",
+    )
+    .unwrap();
+    assert_eq!(profile.costs.len(), 2);
+    assert_eq!(profile.costs["_ZN1a3foo"], 100);
+    assert_eq!(profile.costs["_ZN1a3bar"], 20);
+    assert_eq!(profile.edges, [("_ZN1a3foo".to_owned(), "_ZN1a3bar".to_owned(), 7)]);
+}
+
+#[test]
+fn parse_invalid_profile() {
+    assert_eq!(
+        CguProfile::parse("_ZN1a3foo 1\n_ZN1a3bar lots\n").unwrap_err(),
+        (2, "_ZN1a3bar lots")
+    );
+    assert_eq!(CguProfile::parse("_ZN1a3foo\n").unwrap_err(), (1, "_ZN1a3foo"));
+    assert_eq!(CguProfile::parse("a b 1 2\n").unwrap_err(), (1, "a b 1 2"));
+    assert_eq!(CguProfile::parse("a -1\n").unwrap_err(), (1, "a -1"));
+}
+
+fn symbols<'a>(symbols: &[&'a str]) -> FxHashSet<&'a str> {
+    symbols.iter().copied().collect()
+}
+
+#[test]
+fn merge_into_hottest_candidate() {
+    let profile = CguProfile::parse("a b 5\nc a 10\na d 1\nb c 100\n").unwrap();
+    let own = symbols(&["a"]);
+    let candidates = [symbols(&["b"]), symbols(&["c", "x"]), symbols(&["d"])];
+
+    let target = |indices: &[usize]| {
+        hottest_merge_target(&profile, &own, indices.iter().map(|&i| (i, &candidates[i])))
+    };
+    assert_eq!(target(&[0, 1, 2]), Some(1));
+    // Candidates that would become too big are filtered out by the caller.
+    assert_eq!(target(&[0, 2]), Some(0));
+    assert_eq!(target(&[2]), Some(2));
+    assert_eq!(target(&[]), None);
+}
+
+#[test]
+fn merge_target_ties_and_cold_cgus() {
+    let profile = CguProfile::parse("a b 5\nc a 5\nb c 100\n").unwrap();
+    let candidates = [symbols(&["b"]), symbols(&["c"]), symbols(&["y"])];
+    let all = || candidates.iter().enumerate();
+
+    // Of equally hot candidates, the last and thus smallest one is preferred.
+    assert_eq!(hottest_merge_target(&profile, &symbols(&["a"]), all()), Some(1));
+    // Calls within the CGU don't make it hot with anything.
+    assert_eq!(hottest_merge_target(&profile, &symbols(&["a", "b", "c"]), all()), None);
+    // Calls to items of none of the candidates don't pick a target.
+    assert_eq!(hottest_merge_target(&profile, &symbols(&["z"]), all()), None);
+    assert_eq!(hottest_merge_target(&profile, &symbols(&["a"]), all().skip(2)), None);
+}
+
+#[test]
+fn split_balances_costs() {
+    assert!(split_costs(&[]).is_empty());
+    assert_eq!(split_costs(&[10]), [false]);
+    assert_eq!(split_costs(&[1, 10, 2, 9]), [false, false, true, true]);
+    assert_eq!(split_costs(&[5, 5, 5, 5, 5]), [false, true, false, true, false]);
+    // A single dominant item ends up on its own.
+    assert_eq!(split_costs(&[1, 100, 2, 3]), [true, false, true, true]);
+}
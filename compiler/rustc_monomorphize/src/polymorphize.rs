@@ -9,11 +9,12 @@
 use rustc_middle::mir::{
     self,
     visit::{TyContext, Visitor},
-    Constant, ConstantKind, Local, LocalDecl, Location,
+    Body, CastKind, Constant, ConstantKind, Local, LocalDecl, Location, Rvalue,
 };
 use rustc_middle::query::Providers;
 use rustc_middle::ty::{
     self,
+    adjustment::PointerCast,
     subst::SubstsRef,
     visit::{TypeSuperVisitable, TypeVisitable, TypeVisitableExt, TypeVisitor},
     Const, Ty, TyCtxt, UnusedGenericParams,
@@ -73,7 +74,13 @@ fn unused_generic_params<'tcx>(
         Some(ConstContext::ConstFn) | None => tcx.optimized_mir(def_id),
         Some(_) => tcx.mir_for_ctfe(def_id),
     };
-    let mut vis = MarkUsedGenericParams { tcx, def_id, unused_parameters: &mut unused_parameters };
+    let mut vis = MarkUsedGenericParams {
+        tcx,
+        def_id,
+        body,
+        unused_parameters: &mut unused_parameters,
+        identity_observed: false,
+    };
     vis.visit_body(body);
     debug!(?unused_parameters, "(end)");
 
@@ -224,10 +231,34 @@ fn emit_unused_generic_params_error<'tcx>(
 struct MarkUsedGenericParams<'a, 'tcx> {
     tcx: TyCtxt<'tcx>,
     def_id: DefId,
+    /// The body being visited, either the MIR of `def_id` or one of its promoteds.
+    body: &'tcx Body<'tcx>,
     unused_parameters: &'a mut UnusedGenericParams,
+    /// Whether the types being visited are generic arguments of a callee, which could observe
+    /// their identity (e.g. with `TypeId::of` or `type_name`).
+    identity_observed: bool,
 }
 
 impl<'a, 'tcx> MarkUsedGenericParams<'a, 'tcx> {
+    /// Returns `true` if calling `def_id` with `substs` runs the body of a closure or generator,
+    /// or its drop glue. Unlike other callees, these never observe the identity of the closure or
+    /// generator type they are instantiated with.
+    fn is_closure_body_call(&self, def_id: DefId, substs: SubstsRef<'tcx>) -> bool {
+        let Some(self_ty) = substs.types().next() else { return false };
+        if !matches!(self_ty.kind(), ty::Closure(..) | ty::Generator(..)) {
+            return false;
+        }
+
+        let lang_items = self.tcx.lang_items();
+        if lang_items.drop_in_place_fn() == Some(def_id) {
+            return true;
+        }
+
+        self.tcx.trait_of_item(def_id).is_some_and(|trait_def_id| {
+            self.tcx.is_fn_trait(trait_def_id) || lang_items.gen_trait() == Some(trait_def_id)
+        })
+    }
+
     /// Invoke `unused_generic_params` on a body contained within the current item (e.g.
     /// a closure, generator or constant).
     #[instrument(level = "debug", skip(self, def_id, substs))]
@@ -263,6 +294,20 @@ fn visit_local_decl(&mut self, local: Local, local_decl: &LocalDecl<'tcx>) {
         self.super_local_decl(local, local_decl);
     }
 
+    fn visit_rvalue(&mut self, rvalue: &Rvalue<'tcx>, location: Location) {
+        // Unsizing a closure/generator to a trait object creates a vtable for it, whose methods
+        // (e.g. `Any::type_id`) could observe the identity of the closure/generator type.
+        if let Rvalue::Cast(CastKind::Pointer(PointerCast::Unsize), operand, _) = rvalue {
+            if !self.identity_observed {
+                self.identity_observed = true;
+                operand.ty(self.body, self.tcx).visit_with(self);
+                self.identity_observed = false;
+            }
+        }
+
+        self.super_rvalue(rvalue, location);
+    }
+
     fn visit_constant(&mut self, ct: &Constant<'tcx>, location: Location) {
         match ct.literal {
             ConstantKind::Ty(c) => {
@@ -275,8 +320,10 @@ fn visit_constant(&mut self, ct: &Constant<'tcx>, location: Location) {
                     if self.def_id == def && !self.tcx.generics_of(def).has_self {
                         // If there is a promoted, don't look at the substs - since it will always contain
                         // the generic parameters, instead, traverse the promoted MIR.
-                        let promoted = self.tcx.promoted_mir(def);
-                        self.visit_body(&promoted[p]);
+                        let promoted = &self.tcx.promoted_mir(def)[p];
+                        let body = std::mem::replace(&mut self.body, promoted);
+                        self.visit_body(promoted);
+                        self.body = body;
                     }
                 }
 
@@ -328,11 +375,26 @@ fn visit_ty(&mut self, ty: Ty<'tcx>) -> ControlFlow<Self::BreakTy> {
                     return ControlFlow::Continue(());
                 }
 
+                // If the closure/generator could be told apart from its other instantiations by
+                // a callee, polymorphizing it would make distinct types identical. Consider all
+                // generic parameters of its parent used instead.
+                if self.identity_observed {
+                    return substs.visit_with(self);
+                }
+
                 // Consider any generic parameters used by any closures/generators as used in the
                 // parent.
                 self.visit_child_body(def_id, substs);
                 ControlFlow::Continue(())
             }
+            ty::FnDef(def_id, substs)
+                if !self.identity_observed && !self.is_closure_body_call(def_id, substs) =>
+            {
+                self.identity_observed = true;
+                let result = substs.visit_with(self);
+                self.identity_observed = false;
+                result
+            }
             ty::Param(param) => {
                 debug!(?param);
                 self.unused_parameters.mark_used(param.index);
@@ -478,7 +478,9 @@ fn execute_job_non_incr<Q, Qcx>(
     }
 
     let prof_timer = qcx.dep_context().profiler().query_provider();
+    let total_timer = qcx.dep_context().profiler().query_provider_total(query.name());
     let result = qcx.start_query(job_id, query.depth_limit(), None, || query.compute(qcx, key));
+    drop(total_timer);
     let dep_node_index = qcx.dep_context().dep_graph().next_virtual_depnode_index();
     prof_timer.finish_with_query_invocation_id(dep_node_index.into());
 
@@ -521,6 +523,7 @@ fn execute_job_incr<Q, Qcx>(
     }
 
     let prof_timer = qcx.dep_context().profiler().query_provider();
+    let total_timer = qcx.dep_context().profiler().query_provider_total(query.name());
     let diagnostics = Lock::new(ThinVec::new());
 
     let (result, dep_node_index) =
@@ -544,6 +547,7 @@ fn execute_job_incr<Q, Qcx>(
             )
         });
 
+    drop(total_timer);
     prof_timer.finish_with_query_invocation_id(dep_node_index.into());
 
     let diagnostics = diagnostics.into_inner();
@@ -629,10 +633,12 @@ fn try_load_from_disk_and_cache_in_memory<Q, Qcx>(
     // We could not load a result from the on-disk cache, so
     // recompute.
     let prof_timer = qcx.dep_context().profiler().query_provider();
+    let total_timer = qcx.dep_context().profiler().query_provider_total(query.name());
 
     // The dep-graph for this computation is already in-place.
     let result = qcx.dep_context().dep_graph().with_ignore(|| query.compute(qcx, *key));
 
+    drop(total_timer);
     prof_timer.finish_with_query_invocation_id(dep_node_index.into());
 
     // Verify that re-running the query produced a result with the expected hash
@@ -67,6 +67,15 @@ session_invalid_num_literal_suffix = invalid suffix `{$suffix}` for number liter
 
 session_linker_plugin_lto_windows_not_supported = linker plugin based LTO is not supported together with `-C prefer-dynamic` when targeting Windows-like targets
 
+session_nondeterministic_build = the output of this compilation is not reproducible
+    .help = remove these sources of nondeterminism, move the source files under the working directory, or remap the paths they embed with `--remap-path-prefix`
+
+session_nondeterministic_env_var = `{$macro_name}!` embeds the value of `{$var}`, which differs between machines and sessions
+
+session_nondeterministic_file_macro = `file!()` expands to the host path `{$path}`, which isn't covered by `--remap-path-prefix`
+
+session_nondeterministic_source_path = the host path `{$path}` is embedded in the output, but isn't covered by `--remap-path-prefix`
+
 session_not_circumvent_feature = `-Zunleash-the-miri-inside-of-you` may not be used to circumvent feature gates, except when testing error paths in the CTFE engine
 
 session_not_supported = not supported
@@ -232,6 +232,10 @@ pub struct LinkSelfContained {
 
     /// The components that are enabled.
     components: LinkSelfContainedComponents,
+
+    /// The components that were explicitly disabled on the CLI, to tell them apart from the ones
+    /// that were never mentioned and use the target's default.
+    disabled_components: LinkSelfContainedComponents,
 }
 
 bitflags::bitflags! {
@@ -278,12 +282,16 @@ pub(crate) fn handle_cli_component(&mut self, component: &str) -> Result<(), ()>
         // set in bulk with its historical values, then manually setting a component clears that
         // `explicitly_set` state.
         if let Some(component_to_enable) = component.strip_prefix("+") {
+            let component_to_enable = component_to_enable.parse()?;
             self.explicitly_set = None;
-            self.components.insert(component_to_enable.parse()?);
+            self.components.insert(component_to_enable);
+            self.disabled_components.remove(component_to_enable);
             Ok(())
         } else if let Some(component_to_disable) = component.strip_prefix("-") {
+            let component_to_disable = component_to_disable.parse()?;
             self.explicitly_set = None;
-            self.components.remove(component_to_disable.parse()?);
+            self.components.remove(component_to_disable);
+            self.disabled_components.insert(component_to_disable);
             Ok(())
         } else {
             Err(())
@@ -299,6 +307,7 @@ pub(crate) fn set_all_explicitly(&mut self, enabled: bool) {
         } else {
             LinkSelfContainedComponents::empty()
         };
+        self.disabled_components = self.components.complement();
     }
 
     /// Helper creating a fully enabled `LinkSelfContained` instance. Used in tests.
@@ -320,6 +329,12 @@ fn are_unstable_variants_set(&self) -> bool {
     pub fn linker(&self) -> bool {
         self.components.contains(LinkSelfContainedComponents::LINKER)
     }
+
+    /// Returns whether the self-contained linker component was explicitly disabled, e.g. with
+    /// `-Clink-self-contained=-linker` or `-Clink-self-contained=no`.
+    pub fn is_linker_disabled(&self) -> bool {
+        self.disabled_components.contains(LinkSelfContainedComponents::LINKER)
+    }
 }
 
 /// Used with `-Z assert-incr-state`.
@@ -2500,6 +2515,12 @@ fn parse_remap_path_prefix(
             Ok(cwd) => mapping.push((cwd, to.clone())),
             Err(_) => (),
         },
+        // `-Zdeterministic` normalizes the paths under the working directory to relative paths,
+        // with the lowest precedence so that any `--remap-path-prefix` still applies first.
+        None if unstable_opts.deterministic => match std::env::current_dir() {
+            Ok(cwd) => mapping.insert(0, (cwd, PathBuf::new())),
+            Err(_) => (),
+        },
         None => (),
     };
     mapping
@@ -2690,6 +2711,13 @@ pub fn build_session_options(
 
     let prints = collect_print_requests(handler, &mut cg, &mut unstable_opts, matches);
 
+    // Don't let anything derived from the `-C metadata` values, like the symbol hashes or the
+    // dependency tracking hash, depend on the order in which they were passed.
+    if unstable_opts.deterministic {
+        cg.metadata.sort();
+        cg.metadata.dedup();
+    }
+
     let cg = cg;
 
     let sysroot_opt = matches.opt_str("sysroot").map(|m| PathBuf::from(&m));
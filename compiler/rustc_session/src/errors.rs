@@ -231,6 +231,35 @@ pub enum UnleashedFeatureHelp {
     },
 }
 
+#[derive(Diagnostic)]
+#[diag(session_nondeterministic_build)]
+#[help]
+pub struct NondeterministicBuild {
+    #[subdiagnostic]
+    pub sources: Vec<NondeterminismSource>,
+}
+
+/// A source of nondeterminism in the output of the compilation, which `-Zdeterministic` can't
+/// normalize away.
+#[derive(Clone, Debug, Subdiagnostic)]
+pub enum NondeterminismSource {
+    #[note(session_nondeterministic_env_var)]
+    EnvVar {
+        #[primary_span]
+        span: Span,
+        macro_name: &'static str,
+        var: Symbol,
+    },
+    #[note(session_nondeterministic_file_macro)]
+    FileMacro {
+        #[primary_span]
+        span: Span,
+        path: String,
+    },
+    #[note(session_nondeterministic_source_path)]
+    SourcePath { path: String },
+}
+
 #[derive(Diagnostic)]
 #[diag(session_invalid_literal_suffix)]
 pub(crate) struct InvalidLiteralSuffix<'a> {
@@ -365,7 +365,7 @@ mod desc {
     pub const parse_number: &str = "a number";
     pub const parse_opt_number: &str = parse_number;
     pub const parse_threads: &str = parse_number;
-    pub const parse_time_passes_format: &str = "`text` (default) or `json`";
+    pub const parse_time_passes_format: &str = "`text` (default), `json` or `json-tree`";
     pub const parse_passes: &str = "a space-separated list of passes, or `all`";
     pub const parse_panic_strategy: &str = "either `unwind` or `abort`";
     pub const parse_opt_panic_strategy: &str = parse_panic_strategy;
@@ -411,7 +411,7 @@ mod desc {
         "one of supported split-debuginfo modes (`off`, `packed`, or `unpacked`)";
     pub const parse_split_dwarf_kind: &str =
         "one of supported split dwarf modes (`split` or `single`)";
-    pub const parse_gcc_ld: &str = "one of: no value, `lld`";
+    pub const parse_gcc_ld: &str = "one of: no value, `lld`, `mold`";
     pub const parse_link_self_contained: &str = "one of: `y`, `yes`, `on`, `n`, `no`, `off`, or a list of enabled (`+` prefix) and disabled (`-` prefix) \
         components: `crto`, `libc`, `unwind`, `linker`, `sanitizers`, `mingw`";
     pub const parse_stack_protector: &str =
@@ -858,6 +858,10 @@ pub(crate) fn parse_time_passes_format(slot: &mut TimePassesFormat, v: Option<&s
                 *slot = TimePassesFormat::Text;
                 true
             }
+            Some("json-tree") => {
+                *slot = TimePassesFormat::JsonTree;
+                true
+            }
             Some(_) => false,
         }
     }
@@ -1186,6 +1190,7 @@ pub(crate) fn parse_gcc_ld(slot: &mut Option<LdImpl>, v: Option<&str>) -> bool {
         match v {
             None => *slot = None,
             Some("lld") => *slot = Some(LdImpl::Lld),
+            Some("mold") => *slot = Some(LdImpl::Mold),
             _ => return false,
         }
         true
@@ -1415,6 +1420,9 @@ pub(crate) fn parse_dump_solver_proof_tree(
         "set options for branch target identification and pointer authentication on AArch64"),
     cf_protection: CFProtection = (CFProtection::None, parse_cfprotection, [TRACKED],
         "instrument control-flow architecture protection"),
+    cgu_partitioning_profile: Option<PathBuf> = (None, parse_opt_pathbuf, [TRACKED],
+        "balance and merge codegen units using per-symbol costs and call counts \
+        from a previous compilation, read from the given file"),
     codegen_backend: Option<String> = (None, parse_opt_string, [TRACKED],
         "the backend to use"),
     combine_cgu: bool = (false, parse_bool, [TRACKED],
@@ -1433,6 +1441,9 @@ pub(crate) fn parse_dump_solver_proof_tree(
     dep_tasks: bool = (false, parse_bool, [UNTRACKED],
         "print tasks that execute and the color their dep node gets (requires debug build) \
         (default: no)"),
+    deterministic: bool = (false, parse_bool, [TRACKED],
+        "enforce reproducible output: remap the working directory, sort archive members and \
+        reject the remaining sources of nondeterminism (default: no)"),
     diagnostic_width: Option<usize> = (None, parse_opt_number, [UNTRACKED],
         "set the current output width for diagnostic truncation"),
     dont_buffer_diagnostics: bool = (false, parse_bool, [UNTRACKED],
@@ -1487,6 +1498,9 @@ pub(crate) fn parse_dump_solver_proof_tree(
         "version of DWARF debug information to emit (default: 2 or 4, depending on platform)"),
     dylib_lto: bool = (false, parse_bool, [UNTRACKED],
         "enables LTO for dylib crate type"),
+    emit_size_map: Option<PathBuf> = (None, parse_opt_pathbuf, [UNTRACKED],
+        "after linking, write a JSON report to the given file with the size of every symbol, \
+        attributed to its crate and the generic definition it was instantiated from"),
     emit_stack_sizes: bool = (false, parse_bool, [UNTRACKED],
         "emit a section containing stack size metadata (default: no)"),
     emit_thin_lto: bool = (true, parse_bool, [TRACKED],
@@ -1613,6 +1627,10 @@ pub(crate) fn parse_dump_solver_proof_tree(
     #[rustc_lint_opt_deny_field_access("use `Session::mir_opt_level` instead of this field")]
     mir_opt_level: Option<usize> = (None, parse_opt_number, [TRACKED],
         "MIR optimization level (0-4; default: 1 in non optimized builds and 2 in optimized builds)"),
+    mono_bloat_report: Option<PathBuf> = (None, parse_opt_pathbuf, [UNTRACKED],
+        "write a JSON report to the given file attributing every monomorphized item to its \
+        definition and the chain of items that instantiated it, grouped by crate, with MIR-based \
+        size estimates"),
     move_size_limit: Option<usize> = (None, parse_opt_number, [TRACKED],
         "the size at which the `large_assignments` lint starts to be emitted"),
     mutable_noalias: bool = (true, parse_bool, [TRACKED],
@@ -1662,7 +1680,7 @@ pub(crate) fn parse_dump_solver_proof_tree(
     polonius: bool = (false, parse_bool, [TRACKED],
         "enable polonius-based borrow-checker (default: no)"),
     polymorphize: bool = (false, parse_bool, [TRACKED],
-          "perform polymorphization analysis"),
+          "perform polymorphization analysis (default: no)"),
     pre_link_arg: (/* redirected to pre_link_args */) = ((), parse_string_push, [UNTRACKED],
         "a single extra argument to prepend the linker invocation (can be used several times)"),
     pre_link_args: Vec<String> = (Vec::new(), parse_list, [UNTRACKED],
@@ -1772,6 +1790,9 @@ pub(crate) fn parse_dump_solver_proof_tree(
     #[rustc_lint_opt_deny_field_access("use `Session::stack_protector` instead of this field")]
     stack_protector: StackProtector = (StackProtector::None, parse_stack_protector, [TRACKED],
         "control stack smash protection strategy (`rustc --print stack-protector-strategies` for details)"),
+    stack_usage_report: Option<PathBuf> = (None, parse_opt_pathbuf, [UNTRACKED],
+        "write the worst-case stack usage of every function, computed from the call graph, \
+        to the given JSON file"),
     staticlib_allow_rdylib_deps: bool = (false, parse_bool, [TRACKED],
         "allow staticlibs to have rust dylib dependencies"),
     staticlib_prefer_dynamic: bool = (false, parse_bool, [TRACKED],
@@ -1807,7 +1828,7 @@ pub(crate) fn parse_dump_solver_proof_tree(
     time_passes: bool = (false, parse_bool, [UNTRACKED],
         "measure time of each rustc pass (default: no)"),
     time_passes_format: TimePassesFormat = (TimePassesFormat::Text, parse_time_passes_format, [UNTRACKED],
-        "the format to use for -Z time-passes (`text` (default) or `json`)"),
+        "the format to use for -Z time-passes (`text` (default), `json` or `json-tree`)"),
     tiny_const_eval_limit: bool = (false, parse_bool, [TRACKED],
         "sets a tiny, non-configurable limit for const eval; useful for compiler tests"),
     #[rustc_lint_opt_deny_field_access("use `Session::tls_model` instead of this field")]
@@ -1893,4 +1914,5 @@ pub enum WasiExecModel {
 #[derive(Clone, Copy, Hash)]
 pub enum LdImpl {
     Lld,
+    Mold,
 }
@@ -239,6 +239,7 @@
         NonZeroU8,
         None,
         Ok,
+        OnceLock,
         Option,
         Ord,
         Ordering,
@@ -366,6 +367,7 @@
         append_const_msg,
         arbitrary_enum_discriminant,
         arbitrary_self_types,
+        arch,
         args,
         arith_offset,
         arm,
@@ -784,6 +786,7 @@
         generic_const_exprs,
         generic_param_attrs,
         get_context,
+        get_or_init,
         global_allocator,
         global_asm,
         globs,
@@ -851,6 +854,8 @@
         intra_doc_pointers,
         intrinsics,
         irrefutable_let_patterns,
+        is_aarch64_feature_detected,
+        is_x86_feature_detected,
         isa_attribute,
         isize,
         issue,
@@ -878,6 +883,7 @@
         len,
         let_chains,
         let_else,
+        level,
         lhs,
         lib,
         libc,
@@ -1492,6 +1498,7 @@
         target,
         target_abi,
         target_arch,
+        target_clones,
         target_endian,
         target_env,
         target_family,
@@ -1601,6 +1608,7 @@
         unsafe_cell,
         unsafe_cell_from_mut,
         unsafe_no_drop_flag,
+        unsafe_op_in_unsafe_fn,
         unsafe_pin_internals,
         unsize,
         unsized_fn_params,
@@ -132,6 +132,9 @@ pub enum LinkSelfContainedDefault {
     True,
     Musl,
     Mingw,
+    /// Only the linker (`rust-lld`) is used from the rustc distribution, CRT objects and
+    /// libraries still come from the system.
+    Linker,
 }
 
 impl FromStr for LinkSelfContainedDefault {
@@ -143,6 +146,7 @@ fn from_str(s: &str) -> Result<LinkSelfContainedDefault, ()> {
             "true" | "wasm" => LinkSelfContainedDefault::True,
             "musl" => LinkSelfContainedDefault::Musl,
             "mingw" => LinkSelfContainedDefault::Mingw,
+            "linker" => LinkSelfContainedDefault::Linker,
             _ => return Err(()),
         })
     }
@@ -155,6 +159,7 @@ fn to_json(&self) -> Json {
             LinkSelfContainedDefault::True => "true",
             LinkSelfContainedDefault::Musl => "musl",
             LinkSelfContainedDefault::Mingw => "mingw",
+            LinkSelfContainedDefault::Linker => "linker",
         }
         .to_json()
     }
@@ -163,6 +163,9 @@ pub enum LinkerFlavor {
 pub enum LinkerFlavorCli {
     // New (unstable) flavors, with direct counterparts in `LinkerFlavor`.
     Gnu(Cc, Lld),
+    /// GNU-compatible `mold` linker, invoked directly or through a C/C++ compiler. It maps to the
+    /// internal `LinkerFlavor::Gnu(cc, Lld::No)` flavor, and is only valid on the command line.
+    GnuMold(Cc),
     Darwin(Cc, Lld),
     WasmLld(Cc),
     Unix(Cc),
@@ -186,6 +189,7 @@ impl LinkerFlavorCli {
     pub fn is_unstable(&self) -> bool {
         match self {
             LinkerFlavorCli::Gnu(..)
+            | LinkerFlavorCli::GnuMold(..)
             | LinkerFlavorCli::Darwin(..)
             | LinkerFlavorCli::WasmLld(..)
             | LinkerFlavorCli::Unix(..)
@@ -202,6 +206,11 @@ pub fn is_unstable(&self) -> bool {
             | LinkerFlavorCli::Em => false,
         }
     }
+
+    /// Returns whether this `-C linker-flavor` option asks for the `mold` linker.
+    pub fn uses_mold(&self) -> bool {
+        matches!(self, LinkerFlavorCli::GnuMold(..))
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -247,6 +256,7 @@ impl LinkerFlavor {
     fn from_cli_json(cli: LinkerFlavorCli, lld_flavor: LldFlavor, is_gnu: bool) -> LinkerFlavor {
         match cli {
             LinkerFlavorCli::Gnu(cc, lld) => LinkerFlavor::Gnu(cc, lld),
+            LinkerFlavorCli::GnuMold(cc) => LinkerFlavor::Gnu(cc, Lld::No),
             LinkerFlavorCli::Darwin(cc, lld) => LinkerFlavor::Darwin(cc, lld),
             LinkerFlavorCli::WasmLld(cc) => LinkerFlavor::WasmLld(cc),
             LinkerFlavorCli::Unix(cc) => LinkerFlavor::Unix(cc),
@@ -279,6 +289,9 @@ fn from_cli_json(cli: LinkerFlavorCli, lld_flavor: LldFlavor, is_gnu: bool) -> L
 
     fn to_cli(self) -> LinkerFlavorCli {
         match self {
+            // `gcc` would lose the `lld` part of these flavors, use their direct counterparts.
+            LinkerFlavor::Gnu(Cc::Yes, Lld::Yes) => LinkerFlavorCli::Gnu(Cc::Yes, Lld::Yes),
+            LinkerFlavor::Darwin(Cc::Yes, Lld::Yes) => LinkerFlavorCli::Darwin(Cc::Yes, Lld::Yes),
             LinkerFlavor::Gnu(Cc::Yes, _)
             | LinkerFlavor::Darwin(Cc::Yes, _)
             | LinkerFlavor::WasmLld(Cc::Yes)
@@ -302,6 +315,7 @@ fn infer_cli_hints(cli: LinkerFlavorCli) -> (Option<Cc>, Option<Lld>) {
             LinkerFlavorCli::Gnu(cc, lld) | LinkerFlavorCli::Darwin(cc, lld) => {
                 (Some(cc), Some(lld))
             }
+            LinkerFlavorCli::GnuMold(cc) => (Some(cc), Some(Lld::No)),
             LinkerFlavorCli::WasmLld(cc) => (Some(cc), Some(Lld::Yes)),
             LinkerFlavorCli::Unix(cc) => (Some(cc), None),
             LinkerFlavorCli::Msvc(lld) => (Some(Cc::No), Some(lld)),
@@ -344,7 +358,13 @@ fn infer_linker_hints(linker_stem: &str) -> (Option<Cc>, Option<Lld>) {
             || stem == "lld-link"
         {
             (Some(Cc::No), Some(Lld::Yes))
-        } else if stem == "ld" || stem.ends_with("-ld") || stem == "link" {
+        } else if stem == "ld"
+            || stem.ends_with("-ld")
+            || stem == "link"
+            // mold is a drop-in replacement for GNU ld.
+            || stem == "mold"
+            || stem == "ld.mold"
+        {
             (Some(Cc::No), Some(Lld::No))
         } else {
             (None, None)
@@ -380,6 +400,7 @@ pub fn check_compatibility(self, cli: LinkerFlavorCli) -> Option<String> {
             // 1. they are counterparts: they have the same principal flavor.
             match (self, cli) {
                 (LinkerFlavor::Gnu(..), LinkerFlavorCli::Gnu(..))
+                | (LinkerFlavor::Gnu(..), LinkerFlavorCli::GnuMold(..))
                 | (LinkerFlavor::Darwin(..), LinkerFlavorCli::Darwin(..))
                 | (LinkerFlavor::WasmLld(..), LinkerFlavorCli::WasmLld(..))
                 | (LinkerFlavor::Unix(..), LinkerFlavorCli::Unix(..))
@@ -490,6 +511,8 @@ pub fn desc(self) -> &'static str {
     (LinkerFlavorCli::Gnu(Cc::No, Lld::Yes)) "gnu-lld"
     (LinkerFlavorCli::Gnu(Cc::Yes, Lld::No)) "gnu-cc"
     (LinkerFlavorCli::Gnu(Cc::Yes, Lld::Yes)) "gnu-lld-cc"
+    (LinkerFlavorCli::GnuMold(Cc::No)) "gnu-mold"
+    (LinkerFlavorCli::GnuMold(Cc::Yes)) "gnu-mold-cc"
     (LinkerFlavorCli::Darwin(Cc::No, Lld::No)) "darwin"
     (LinkerFlavorCli::Darwin(Cc::No, Lld::Yes)) "darwin-lld"
     (LinkerFlavorCli::Darwin(Cc::Yes, Lld::No)) "darwin-cc"
@@ -2552,6 +2575,12 @@ macro_rules! key {
                 let name = $json_name;
                 obj.remove(name).and_then(|o| o.as_str().and_then(|s| {
                     match LinkerFlavorCli::from_str(s) {
+                        // The `mold` part of these flavors has no internal counterpart and
+                        // wouldn't survive a roundtrip, they can only be used on the command line.
+                        Some(linker_flavor) if linker_flavor.uses_mold() => {
+                            return Some(Err(format!("linker flavor '{}' is only supported with \
+                                                     `-C linker-flavor`", s)));
+                        }
                         Some(linker_flavor) => base.$key_name = linker_flavor,
                         _ => return Some(Err(format!("'{}' is not a valid value for linker-flavor. \
                                                       Use {}", s, LinkerFlavorCli::one_of()))),
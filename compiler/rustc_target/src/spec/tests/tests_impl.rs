@@ -97,7 +97,10 @@ fn check_consistency(&self) {
             );
         }
 
-        if self.link_self_contained == LinkSelfContainedDefault::False {
+        if matches!(
+            self.link_self_contained,
+            LinkSelfContainedDefault::False | LinkSelfContainedDefault::Linker
+        ) {
             assert!(
                 self.pre_link_objects_self_contained.is_empty()
                     && self.post_link_objects_self_contained.is_empty()
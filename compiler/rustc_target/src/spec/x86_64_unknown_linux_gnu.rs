@@ -1,3 +1,4 @@
+use crate::spec::crt_objects::LinkSelfContainedDefault;
 use crate::spec::{Cc, LinkerFlavor, Lld, SanitizerSet, StackProbeType, Target};
 
 pub fn target() -> Target {
@@ -16,6 +17,12 @@ pub fn target() -> Target {
         | SanitizerSet::THREAD;
     base.supports_xray = true;
 
+    // When the rustc distribution ships `rust-lld`, use it by default instead of the system linker.
+    if option_env!("CFG_USE_SELF_CONTAINED_LINKER").is_some() {
+        base.linker_flavor = LinkerFlavor::Gnu(Cc::Yes, Lld::Yes);
+        base.link_self_contained = LinkSelfContainedDefault::Linker;
+    }
+
     Target {
         llvm_target: "x86_64-unknown-linux-gnu".into(),
         pointer_width: 64,
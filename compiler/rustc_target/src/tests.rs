@@ -1,4 +1,6 @@
-use crate::spec::Target;
+use crate::json::ToJson;
+use crate::spec::crt_objects::LinkSelfContainedDefault;
+use crate::spec::{Cc, LinkerFlavor, LinkerFlavorCli, Lld, Target};
 
 #[test]
 fn report_unused_fields() {
@@ -55,3 +57,57 @@ fn no_warnings_for_valid_target() {
     let warnings = Target::from_json(json).unwrap().1;
     assert_eq!(warnings.warning_messages().len(), 0);
 }
+
+#[test]
+fn self_contained_lld_linker_roundtrip() {
+    let json = serde_json::from_str(
+        r#"
+    {
+        "arch": "x86_64",
+        "data-layout": "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128",
+        "llvm-target": "x86_64-unknown-linux-gnu",
+        "target-pointer-width": "64",
+        "linker-flavor": "gnu-lld-cc",
+        "crt-objects-fallback": "linker"
+    }
+    "#,
+    )
+    .unwrap();
+    let target = Target::from_json(json).unwrap().0;
+    assert_eq!(target.linker_flavor, LinkerFlavor::Gnu(Cc::Yes, Lld::Yes));
+    assert_eq!(target.link_self_contained, LinkSelfContainedDefault::Linker);
+
+    let recycled_target = Target::from_json(target.to_json()).unwrap().0;
+    assert_eq!(recycled_target.linker_flavor, LinkerFlavor::Gnu(Cc::Yes, Lld::Yes));
+    assert_eq!(recycled_target.link_self_contained, LinkSelfContainedDefault::Linker);
+}
+
+#[test]
+fn mold_linker_flavors() {
+    let target = LinkerFlavor::Gnu(Cc::Yes, Lld::Yes);
+    for cc in [Cc::No, Cc::Yes] {
+        let cli = LinkerFlavorCli::GnuMold(cc);
+        assert!(cli.is_unstable());
+        assert!(cli.uses_mold());
+        assert_eq!(target.with_cli_hints(cli), LinkerFlavor::Gnu(cc, Lld::No));
+        assert_eq!(target.check_compatibility(cli), None);
+    }
+    assert_eq!(LinkerFlavorCli::from_str("gnu-mold-cc"), Some(LinkerFlavorCli::GnuMold(Cc::Yes)));
+    assert!(!LinkerFlavorCli::Gnu(Cc::Yes, Lld::No).uses_mold());
+
+    // The mold flavors have no internal counterpart, and are rejected in target specs.
+    let json = serde_json::from_str(
+        r#"
+    {
+        "arch": "x86_64",
+        "data-layout": "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128",
+        "llvm-target": "x86_64-unknown-linux-gnu",
+        "target-pointer-width": "64",
+        "linker-flavor": "gnu-mold-cc"
+    }
+    "#,
+    )
+    .unwrap();
+    let error = Target::from_json(json).unwrap_err();
+    assert!(error.contains("only supported with `-C linker-flavor`"));
+}
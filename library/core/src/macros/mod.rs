@@ -1564,6 +1564,34 @@ macro_rules! trace_macros {
         /* compiler built-in */
     }
 
+    /// Attribute macro applied to a function to compile it once for each of the given sets of
+    /// target features, and to call the best version supported by the CPU at runtime.
+    ///
+    /// Each argument is a comma-separated list of target features, as in
+    /// `#[target_feature(enable = "...")]`, and exactly one of them must be `"default"`: the
+    /// version compiled without additional target features. The first listed version whose
+    /// target features are all detected is selected when the function is first called, and used
+    /// for all the subsequent calls.
+    ///
+    /// ```ignore (requires-x86_64)
+    /// #![feature(target_clones)]
+    ///
+    /// #[target_clones("avx2,fma", "sse4.1", "default")]
+    /// fn dot(a: &[f32], b: &[f32]) -> f32 {
+    ///     a.iter().zip(b).map(|(a, b)| a * b).sum()
+    /// }
+    /// ```
+    ///
+    /// The function can't be `async`, `const`, use `impl Trait` or be generic over anything but
+    /// lifetimes used by its arguments, and the runtime detection requires `std`.
+    #[cfg(not(bootstrap))]
+    #[unstable(feature = "target_clones", issue = "none")]
+    #[allow_internal_unsafe]
+    #[rustc_builtin_macro]
+    pub macro target_clones($item:item) {
+        /* compiler built-in */
+    }
+
     /// Unstable placeholder for type ascription.
     #[rustc_builtin_macro]
     #[unstable(
@@ -97,6 +97,10 @@
 )]
 pub use crate::macros::builtin::cfg_eval;
 
+#[cfg(not(bootstrap))]
+#[unstable(feature = "target_clones", issue = "none")]
+pub use crate::macros::builtin::target_clones;
+
 #[unstable(
     feature = "type_ascription",
     issue = "23416",
@@ -613,6 +613,187 @@ pub fn try_clone(&self) -> io::Result<File> {
         Ok(File { inner: self.inner.duplicate()? })
     }
 
+    /// Acquires an exclusive advisory lock on the file, blocking until it can be acquired.
+    ///
+    /// While the lock is held, no other file handle to this file can acquire a shared or an
+    /// exclusive lock with [`lock`], [`lock_shared`], [`try_lock`] or [`try_lock_shared`]. The
+    /// lock doesn't prevent other processes from reading or writing the file on Unix. On Windows,
+    /// it is mandatory: reads and writes through other handles fail while it is held.
+    ///
+    /// The lock belongs to the open file, not to this `File`: handles created with
+    /// [`try_clone`], and file descriptors inherited by child processes, share it. It is
+    /// released by [`unlock`], or when all of these handles are closed.
+    ///
+    /// If this file, or a handle sharing its lock, already holds a lock, the behavior is platform
+    /// specific: on Unix, the existing lock is converted, which isn't atomic, while on Windows,
+    /// this function deadlocks.
+    ///
+    /// [`lock`]: File::lock
+    /// [`lock_shared`]: File::lock_shared
+    /// [`try_lock`]: File::try_lock
+    /// [`try_lock_shared`]: File::try_lock_shared
+    /// [`try_clone`]: File::try_clone
+    /// [`unlock`]: File::unlock
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function with `LOCK_EX` on Unix and
+    /// the `LockFileEx` function with `LOCKFILE_EXCLUSIVE_LOCK` on Windows. Note that, this
+    /// [may change in the future][changes].
+    ///
+    /// AIX, illumos and Solaris don't have `flock`, so the `fcntl` function with `F_SETLKW` and
+    /// an `F_WRLCK` lock covering the whole file is used instead. These locks belong to the
+    /// process rather than to the open file: they don't conflict with the other locks of the
+    /// process, they are released when any handle to the file is closed, and exclusive locks
+    /// require the file to be opened for writing (shared locks, for reading).
+    ///
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Errors
+    ///
+    /// This function returns an [`Unsupported`] error on platforms without file locking. It may
+    /// also return an error in other os-specific unspecified cases.
+    ///
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("foo.lock")?;
+    ///     f.lock()?;
+    ///     // ... update the files guarded by the lock ...
+    ///     f.unlock()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    #[doc(alias = "flock")]
+    #[doc(alias = "LockFileEx")]
+    pub fn lock(&self) -> io::Result<()> {
+        self.inner.lock()
+    }
+
+    /// Acquires a shared advisory lock on the file, blocking until it can be acquired.
+    ///
+    /// Several handles can hold a shared lock on the same file at once, but not while one of
+    /// them holds an exclusive lock. Apart from that, shared locks behave like the exclusive
+    /// locks of [`lock`], see its documentation for their semantics.
+    ///
+    /// [`lock`]: File::lock
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function with `LOCK_SH` on Unix and
+    /// the `LockFileEx` function on Windows. Note that, this
+    /// [may change in the future][changes]. On AIX, illumos and Solaris, it uses `fcntl` with an
+    /// `F_RDLCK` lock, see [`lock`] for the differences.
+    ///
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::open("foo.lock")?;
+    ///     f.lock_shared()?;
+    ///     // ... read the files guarded by the lock ...
+    ///     f.unlock()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.inner.lock_shared()
+    }
+
+    /// Tries to acquire an exclusive advisory lock on the file, without blocking.
+    ///
+    /// Returns `Ok(false)` if another handle to the file holds a lock. Otherwise, this behaves
+    /// like [`lock`], see its documentation for the semantics of the lock.
+    ///
+    /// [`lock`]: File::lock
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function with `LOCK_EX | LOCK_NB` on
+    /// Unix and the `LockFileEx` function with `LOCKFILE_EXCLUSIVE_LOCK` and
+    /// `LOCKFILE_FAIL_IMMEDIATELY` on Windows. Note that, this
+    /// [may change in the future][changes]. On AIX, illumos and Solaris, it uses `fcntl` with
+    /// `F_SETLK`, see [`lock`] for the differences.
+    ///
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("foo.lock")?;
+    ///     if !f.try_lock()? {
+    ///         println!("waiting for the lock on `foo.lock`");
+    ///         f.lock()?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.inner.try_lock()
+    }
+
+    /// Tries to acquire a shared advisory lock on the file, without blocking.
+    ///
+    /// Returns `Ok(false)` if another handle to the file holds an exclusive lock. Otherwise,
+    /// this behaves like [`lock_shared`].
+    ///
+    /// [`lock`]: File::lock
+    /// [`lock_shared`]: File::lock_shared
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function with `LOCK_SH | LOCK_NB` on
+    /// Unix and the `LockFileEx` function with `LOCKFILE_FAIL_IMMEDIATELY` on Windows. Note
+    /// that, this [may change in the future][changes]. On AIX, illumos and Solaris, it uses
+    /// `fcntl` with `F_SETLK`, see [`lock`] for the differences.
+    ///
+    /// [changes]: io#platform-specific-behavior
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.inner.try_lock_shared()
+    }
+
+    /// Releases the lock held on the file, by this handle or a handle sharing its lock.
+    ///
+    /// Locks are also released when all the handles sharing them are closed, see [`lock`].
+    ///
+    /// [`lock`]: File::lock
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function with `LOCK_UN` on Unix and
+    /// the `UnlockFile` function on Windows. Note that, this
+    /// [may change in the future][changes]. On AIX, illumos and Solaris, it uses `fcntl` with
+    /// `F_UNLCK`.
+    ///
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Errors
+    ///
+    /// On Windows, this function returns an error if the file isn't locked.
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn unlock(&self) -> io::Result<()> {
+        self.inner.unlock()
+    }
+
     /// Changes the permissions on the underlying file.
     ///
     /// # Platform-specific behavior
@@ -756,6 +937,10 @@ fn read_buf(&mut self, cursor: BorrowedCursor<'_>) -> io::Result<()> {
         self.inner.read_buf(cursor)
     }
 
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        self.inner.read_buf_vectored(cursors)
+    }
+
     #[inline]
     fn is_read_vectored(&self) -> bool {
         self.inner.is_read_vectored()
@@ -810,6 +995,10 @@ fn read_buf(&mut self, cursor: BorrowedCursor<'_>) -> io::Result<()> {
         self.inner.read_buf(cursor)
     }
 
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        self.inner.read_buf_vectored(cursors)
+    }
+
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.inner.read_vectored(bufs)
     }
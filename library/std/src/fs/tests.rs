@@ -419,6 +419,27 @@ fn file_test_read_buf() {
     check!(fs::remove_file(filename));
 }
 
+#[test]
+#[cfg(unix)]
+fn file_test_read_buf_vectored() {
+    let tmpdir = tmpdir();
+    let filename = &tmpdir.join("test");
+    check!(fs::write(filename, &[1, 2, 3, 4, 5, 6]));
+
+    let mut first: [MaybeUninit<u8>; 4] = MaybeUninit::uninit_array();
+    let mut first = BorrowedBuf::from(first.as_mut_slice());
+    let mut second: [MaybeUninit<u8>; 128] = MaybeUninit::uninit_array();
+    let mut second = BorrowedBuf::from(second.as_mut_slice());
+    let mut file = check!(File::open(filename));
+    check!(file.read_buf_vectored(&mut [first.unfilled(), second.unfilled()]));
+    assert_eq!(first.filled(), &[1, 2, 3, 4]);
+    assert_eq!(second.filled(), &[5, 6]);
+    // File::read_buf_vectored should omit buffer initialization.
+    assert_eq!(second.init_len(), 2);
+
+    check!(fs::remove_file(filename));
+}
+
 #[test]
 fn file_test_stat_is_correct_on_is_file() {
     let tmpdir = tmpdir();
@@ -1705,3 +1726,81 @@ fn test_file_times() {
         assert_eq!(metadata.created().unwrap(), created);
     }
 }
+
+#[test]
+fn file_lock_multiple_shared() {
+    let tmp = tmpdir();
+    let filename = &tmp.join("file_lock_multiple_shared.txt");
+    let f1 = check!(File::create(filename));
+    let f2 = check!(OpenOptions::new().write(true).open(filename));
+
+    match f1.lock_shared() {
+        // Allow unsupported errors on platforms without file locking.
+        Err(e) if e.kind() == ErrorKind::Unsupported => return,
+        result => check!(result),
+    }
+    check!(f2.lock_shared());
+    check!(f1.unlock());
+    check!(f2.unlock());
+    assert!(check!(f1.try_lock_shared()));
+    assert!(check!(f2.try_lock_shared()));
+}
+
+#[test]
+#[cfg_attr(
+    any(target_os = "aix", target_os = "illumos", target_os = "solaris"),
+    ignore = "fcntl locks never conflict within a process"
+)]
+fn file_lock_blocking() {
+    let tmp = tmpdir();
+    let filename = &tmp.join("file_lock_blocking.txt");
+    let f1 = check!(File::create(filename));
+    let f2 = check!(OpenOptions::new().write(true).open(filename));
+
+    match f1.lock() {
+        // Allow unsupported errors on platforms without file locking.
+        Err(e) if e.kind() == ErrorKind::Unsupported => return,
+        result => check!(result),
+    }
+    assert!(!check!(f2.try_lock()));
+    assert!(!check!(f2.try_lock_shared()));
+    check!(f1.unlock());
+    assert!(check!(f2.try_lock_shared()));
+    assert!(!check!(f1.try_lock()));
+    check!(f2.unlock());
+
+    // A blocked `lock` returns once the lock is released.
+    check!(f1.lock());
+    let handle = thread::spawn(move || {
+        check!(f2.lock());
+        check!(f2.unlock());
+    });
+    thread::sleep(Duration::from_millis(10));
+    check!(f1.unlock());
+    handle.join().unwrap();
+}
+
+#[test]
+#[cfg_attr(
+    any(target_os = "aix", target_os = "illumos", target_os = "solaris"),
+    ignore = "fcntl locks never conflict within a process"
+)]
+fn file_lock_released_on_close() {
+    let tmp = tmpdir();
+    let filename = &tmp.join("file_lock_released_on_close.txt");
+    let f1 = check!(File::create(filename));
+    let f2 = check!(OpenOptions::new().write(true).open(filename));
+
+    match f1.lock() {
+        // Allow unsupported errors on platforms without file locking.
+        Err(e) if e.kind() == ErrorKind::Unsupported => return,
+        result => check!(result),
+    }
+    // The lock is shared with the handles duplicated from `f1`, so it is only released once all
+    // of them are closed.
+    let f1_clone = check!(f1.try_clone());
+    drop(f1);
+    assert!(!check!(f2.try_lock()));
+    drop(f1_clone);
+    assert!(check!(f2.try_lock()));
+}
@@ -308,6 +308,14 @@ fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
         crate::io::default_read_exact(self, buf)
     }
 
+    fn read_buf_exact(&mut self, mut cursor: BorrowedCursor<'_>) -> io::Result<()> {
+        if self.buf.consume_with(cursor.capacity(), |claimed| cursor.append(claimed)) {
+            return Ok(());
+        }
+
+        crate::io::default_read_buf_exact(self, cursor)
+    }
+
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         let total_len = bufs.iter().map(|b| b.len()).sum::<usize>();
         if self.buf.pos() == self.buf.filled() && total_len >= self.capacity() {
@@ -322,6 +330,20 @@ fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         Ok(nread)
     }
 
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        let total_capacity = cursors.iter().map(|c| c.capacity()).sum::<usize>();
+        if self.buf.pos() == self.buf.filled() && total_capacity >= self.capacity() {
+            self.discard_buffer();
+            return self.inner.read_buf_vectored(cursors);
+        }
+        let written = |cursors: &[BorrowedCursor<'_>]| cursors.iter().map(|c| c.written()).sum();
+        let prev: usize = written(cursors);
+        let mut rem = self.fill_buf()?;
+        rem.read_buf_vectored(cursors)?;
+        self.consume(written(cursors) - prev);
+        Ok(())
+    }
+
     fn is_read_vectored(&self) -> bool {
         self.inner.is_read_vectored()
     }
@@ -54,6 +54,109 @@
 /// [changes]: crate::io#platform-specific-behavior
 #[stable(feature = "rust1", since = "1.0.0")]
 pub fn copy<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W) -> Result<u64>
+where
+    R: Read,
+    W: Write,
+{
+    copy_with_strategy(reader, writer).map(|(copied, _)| copied)
+}
+
+/// Copies at most `limit` bytes from a reader into a writer.
+///
+/// This is like [`copy`] with the reader wrapped in [`Read::take`], and it
+/// uses the same syscalls to move data directly between file descriptors
+/// when possible. It stops after copying `limit` bytes, or earlier when
+/// `reader` returns EOF.
+///
+/// On success, the total number of bytes that were copied from
+/// `reader` to `writer` is returned.
+///
+/// # Errors
+///
+/// This function will return an error immediately if any call to [`read`] or
+/// [`write`] returns an error. All instances of [`ErrorKind::Interrupted`] are
+/// handled by this function and the underlying operation is retried.
+///
+/// [`read`]: Read::read
+/// [`write`]: Write::write
+///
+/// # Examples
+///
+/// ```
+/// #![feature(io_copy_n)]
+/// use std::io;
+///
+/// fn main() -> io::Result<()> {
+///     let mut reader: &[u8] = b"hello world";
+///     let mut writer: Vec<u8> = vec![];
+///
+///     assert_eq!(io::copy_n(&mut reader, &mut writer, 5)?, 5);
+///
+///     assert_eq!(&b"hello"[..], &writer[..]);
+///     assert_eq!(&b" world"[..], reader);
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "io_copy_n", issue = "none")]
+pub fn copy_n<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W, limit: u64) -> Result<u64>
+where
+    R: Read,
+    W: Write,
+{
+    copy(&mut Read::take(reader, limit), writer)
+}
+
+/// How [`copy_with_strategy`] moved the data from the reader to the writer.
+#[unstable(feature = "io_copy_strategy", issue = "none")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CopyStrategy {
+    /// The data was read into a buffer in userspace, then written from it.
+    ReadWrite,
+    /// The data was copied between regular files by `copy_file_range(2)`.
+    CopyFileRange,
+    /// The data was copied from a file by `sendfile(2)`.
+    Sendfile,
+    /// The data was moved to or from a pipe by `splice(2)`.
+    Splice,
+}
+
+/// Copies the entire contents of a reader into a writer like [`copy`], and
+/// also returns how the data was copied.
+///
+/// This is meant for diagnostics, e.g. to check that a server sending files
+/// to sockets doesn't copy the data through userspace. Which strategy is
+/// picked may change between releases and kernel versions.
+///
+/// If several strategies were used, e.g. because a syscall stopped working
+/// partway through, the last one that copied any data is returned. Data
+/// written from the buffers of wrappers like [`BufReader`] isn't taken into
+/// account.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(io_copy_strategy)]
+/// use std::fs::File;
+/// use std::io::{self, CopyStrategy};
+/// use std::net::TcpStream;
+///
+/// fn main() -> io::Result<()> {
+///     let mut file = File::open("index.html")?;
+///     let mut stream = TcpStream::connect("127.0.0.1:8080")?;
+///
+///     let (copied, strategy) = io::copy_with_strategy(&mut file, &mut stream)?;
+///     if strategy == CopyStrategy::ReadWrite {
+///         eprintln!("{copied} bytes were copied through userspace");
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "io_copy_strategy", issue = "none")]
+pub fn copy_with_strategy<R: ?Sized, W: ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<(u64, CopyStrategy)>
 where
     R: Read,
     W: Write,
@@ -62,7 +165,7 @@ pub fn copy<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W) -> Result<u64>
         if #[cfg(any(target_os = "linux", target_os = "android"))] {
             crate::sys::kernel_copy::copy_spec(reader, writer)
         } else {
-            generic_copy(reader, writer)
+            generic_copy(reader, writer).map(|copied| (copied, CopyStrategy::ReadWrite))
         }
     }
 }
@@ -11,6 +11,31 @@ fn copy_copies() {
     assert_eq!(copy(&mut r as &mut dyn Read, &mut w as &mut dyn Write).unwrap(), 1 << 17);
 }
 
+#[test]
+fn copy_n_copies() {
+    let mut r = repeat(0).take(4);
+    let mut w = Vec::new();
+    assert_eq!(copy_n(&mut r, &mut w, 3).unwrap(), 3);
+    assert_eq!(r.limit(), 1);
+    assert_eq!(copy_n(&mut r, &mut w, 3).unwrap(), 1);
+    assert_eq!(copy_n(&mut r, &mut w, 3).unwrap(), 0);
+    assert_eq!(w, [0; 4]);
+
+    let mut r = BufReader::with_capacity(4, &b"abcdefgh"[..]);
+    r.fill_buf().unwrap();
+    let mut w = Vec::new();
+    assert_eq!(copy_n(&mut r, &mut w, 6).unwrap(), 6);
+    assert_eq!(w, b"abcdef");
+    assert_eq!(r.buffer(), b"gh");
+}
+
+#[test]
+fn copy_with_strategy_without_fds() {
+    let mut r = repeat(0).take(1 << 17);
+    let mut w = sink();
+    assert_eq!(copy_with_strategy(&mut r, &mut w).unwrap(), (1 << 17, CopyStrategy::ReadWrite));
+}
+
 struct ShortReader {
     cap: usize,
     read_size: usize,
@@ -333,6 +333,18 @@ fn read_buf(&mut self, mut cursor: BorrowedCursor<'_>) -> io::Result<()> {
         Ok(())
     }
 
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        for cursor in cursors {
+            let capacity = cursor.capacity();
+            let prev_written = cursor.written();
+            self.read_buf(cursor.reborrow())?;
+            if cursor.written() - prev_written < capacity {
+                break;
+            }
+        }
+        Ok(())
+    }
+
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         let mut nread = 0;
         for buf in bufs {
@@ -355,6 +367,13 @@ fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
         self.pos += n as u64;
         Ok(())
     }
+
+    fn read_buf_exact(&mut self, cursor: BorrowedCursor<'_>) -> io::Result<()> {
+        let n = cursor.capacity();
+        Read::read_buf_exact(&mut self.remaining_slice(), cursor)?;
+        self.pos += n as u64;
+        Ok(())
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -26,6 +26,16 @@ fn read_buf(&mut self, cursor: BorrowedCursor<'_>) -> io::Result<()> {
         (**self).read_buf(cursor)
     }
 
+    #[inline]
+    fn read_buf_exact(&mut self, cursor: BorrowedCursor<'_>) -> io::Result<()> {
+        (**self).read_buf_exact(cursor)
+    }
+
+    #[inline]
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        (**self).read_buf_vectored(cursors)
+    }
+
     #[inline]
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         (**self).read_vectored(bufs)
@@ -130,6 +140,16 @@ fn read_buf(&mut self, cursor: BorrowedCursor<'_>) -> io::Result<()> {
         (**self).read_buf(cursor)
     }
 
+    #[inline]
+    fn read_buf_exact(&mut self, cursor: BorrowedCursor<'_>) -> io::Result<()> {
+        (**self).read_buf_exact(cursor)
+    }
+
+    #[inline]
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        (**self).read_buf_vectored(cursors)
+    }
+
     #[inline]
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         (**self).read_vectored(bufs)
@@ -260,6 +280,18 @@ fn read_buf(&mut self, mut cursor: BorrowedCursor<'_>) -> io::Result<()> {
         Ok(())
     }
 
+    #[inline]
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        for cursor in cursors {
+            self.read_buf(cursor.reborrow())?;
+            if self.is_empty() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
     #[inline]
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         let mut nread = 0;
@@ -301,6 +333,22 @@ fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
         Ok(())
     }
 
+    #[inline]
+    fn read_buf_exact(&mut self, mut cursor: BorrowedCursor<'_>) -> io::Result<()> {
+        if cursor.capacity() > self.len() {
+            return Err(io::const_io_error!(
+                ErrorKind::UnexpectedEof,
+                "failed to fill whole buffer"
+            ));
+        }
+        let (a, b) = self.split_at(cursor.capacity());
+
+        cursor.append(a);
+
+        *self = b;
+        Ok(())
+    }
+
     #[inline]
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
         buf.extend_from_slice(*self);
@@ -282,6 +282,10 @@
     util::{empty, repeat, sink, Empty, Repeat, Sink},
 };
 
+#[unstable(feature = "io_copy_n", issue = "none")]
+pub use self::copy::copy_n;
+#[unstable(feature = "io_copy_strategy", issue = "none")]
+pub use self::copy::{copy_with_strategy, CopyStrategy};
 #[unstable(feature = "read_buf", issue = "78485")]
 pub use self::readbuf::{BorrowedBuf, BorrowedCursor};
 pub(crate) use error::const_io_error;
@@ -493,6 +497,39 @@ pub(crate) fn default_read_buf<F>(read: F, mut cursor: BorrowedCursor<'_>) -> Re
     Ok(())
 }
 
+pub(crate) fn default_read_buf_exact<R: Read + ?Sized>(
+    this: &mut R,
+    mut cursor: BorrowedCursor<'_>,
+) -> Result<()> {
+    while cursor.capacity() > 0 {
+        let prev_written = cursor.written();
+        match this.read_buf(cursor.reborrow()) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+
+        if cursor.written() == prev_written {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "failed to fill buffer"));
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn default_read_buf_vectored<F>(
+    read_buf: F,
+    cursors: &mut [BorrowedCursor<'_>],
+) -> Result<()>
+where
+    F: FnOnce(BorrowedCursor<'_>) -> Result<()>,
+{
+    match cursors.iter_mut().find(|c| c.capacity() > 0) {
+        Some(cursor) => read_buf(cursor.reborrow()),
+        None => Ok(()),
+    }
+}
+
 /// The `Read` trait allows for reading bytes from a source.
 ///
 /// Implementors of the `Read` trait are called 'readers'.
@@ -855,21 +892,26 @@ fn read_buf(&mut self, buf: BorrowedCursor<'_>) -> Result<()> {
     ///
     /// If this function returns an error, all bytes read will be appended to `cursor`.
     #[unstable(feature = "read_buf", issue = "78485")]
-    fn read_buf_exact(&mut self, mut cursor: BorrowedCursor<'_>) -> Result<()> {
-        while cursor.capacity() > 0 {
-            let prev_written = cursor.written();
-            match self.read_buf(cursor.reborrow()) {
-                Ok(()) => {}
-                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
-                Err(e) => return Err(e),
-            }
-
-            if cursor.written() == prev_written {
-                return Err(Error::new(ErrorKind::UnexpectedEof, "failed to fill buffer"));
-            }
-        }
+    fn read_buf_exact(&mut self, cursor: BorrowedCursor<'_>) -> Result<()> {
+        default_read_buf_exact(self, cursor)
+    }
 
-        Ok(())
+    /// Like [`read_buf`](Read::read_buf), except that it reads into a slice of cursors.
+    ///
+    /// Data is copied to fill each cursor in order, with the final cursor written to possibly
+    /// being only partially filled. The bytes read into each cursor are appended to its existing
+    /// contents, and the number of bytes read can be computed from the [`written`] counts of the
+    /// cursors.
+    ///
+    /// This is equivalent to the [`read_vectored`](Read::read_vectored) method, except that the
+    /// buffers may be uninitialized.
+    ///
+    /// The default implementation calls `read_buf` with the first cursor which isn't full.
+    ///
+    /// [`written`]: BorrowedCursor::written
+    #[unstable(feature = "read_buf", issue = "78485")]
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> Result<()> {
+        default_read_buf_vectored(|cursor| self.read_buf(cursor), cursors)
     }
 
     /// Creates a "by reference" adaptor for this instance of `Read`.
@@ -2425,6 +2467,33 @@ fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> Result<usize> {
         }
         self.second.read_vectored(bufs)
     }
+
+    fn read_buf(&mut self, mut buf: BorrowedCursor<'_>) -> Result<()> {
+        if !self.done_first {
+            let written = buf.written();
+            self.first.read_buf(buf.reborrow())?;
+            if buf.written() != written || buf.capacity() == 0 {
+                return Ok(());
+            }
+            self.done_first = true;
+        }
+        self.second.read_buf(buf)
+    }
+
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> Result<()> {
+        if !self.done_first {
+            let written = |cursors: &[BorrowedCursor<'_>]| -> usize {
+                cursors.iter().map(|c| c.written()).sum()
+            };
+            let prev_written = written(cursors);
+            self.first.read_buf_vectored(cursors)?;
+            if written(cursors) != prev_written || cursors.iter().all(|c| c.capacity() == 0) {
+                return Ok(());
+            }
+            self.done_first = true;
+        }
+        self.second.read_buf_vectored(cursors)
+    }
 }
 
 #[stable(feature = "chain_bufread", since = "1.9.0")]
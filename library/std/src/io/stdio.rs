@@ -101,6 +101,10 @@ fn read_buf(&mut self, buf: BorrowedCursor<'_>) -> io::Result<()> {
         handle_ebadf(self.0.read_buf(buf), ())
     }
 
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        handle_ebadf(self.0.read_buf_vectored(cursors), ())
+    }
+
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         handle_ebadf(self.0.read_vectored(bufs), 0)
     }
@@ -425,6 +429,9 @@ fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
     fn read_buf(&mut self, buf: BorrowedCursor<'_>) -> io::Result<()> {
         self.lock().read_buf(buf)
     }
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        self.lock().read_buf_vectored(cursors)
+    }
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.lock().read_vectored(bufs)
     }
@@ -461,6 +468,10 @@ fn read_buf(&mut self, buf: BorrowedCursor<'_>) -> io::Result<()> {
         self.inner.read_buf(buf)
     }
 
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        self.inner.read_buf_vectored(cursors)
+    }
+
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.inner.read_vectored(bufs)
     }
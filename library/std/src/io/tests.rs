@@ -179,6 +179,55 @@ fn read_buf_exact() {
     assert_eq!(c.read_buf_exact(buf.unfilled()).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
 }
 
+#[test]
+fn read_buf_vectored() {
+    let mut first = [MaybeUninit::uninit(); 3];
+    let mut second = [MaybeUninit::uninit(); 4];
+    let mut first: BorrowedBuf<'_> = first.as_mut_slice().into();
+    let mut second: BorrowedBuf<'_> = second.as_mut_slice().into();
+
+    let mut c = Cursor::new(&b"12345"[..]);
+    c.read_buf_vectored(&mut [first.unfilled(), second.unfilled()]).unwrap();
+    assert_eq!(first.filled(), b"123");
+    assert_eq!(second.filled(), b"45");
+    assert_eq!(c.position(), 5);
+
+    first.clear();
+    second.clear();
+
+    let mut r = BufReader::with_capacity(2, &b"abcdefgh"[..]).chain(&b"ij"[..]);
+    r.read_buf_vectored(&mut [first.unfilled(), second.unfilled()]).unwrap();
+    assert_eq!(first.filled(), b"abc");
+    assert_eq!(second.filled(), b"defg");
+    first.clear();
+    r.read_buf_vectored(&mut [first.unfilled()]).unwrap();
+    assert_eq!(first.filled(), b"h");
+    first.clear();
+    r.read_buf_vectored(&mut [first.unfilled()]).unwrap();
+    assert_eq!(first.filled(), b"ij");
+    first.clear();
+    r.read_buf_vectored(&mut [first.unfilled()]).unwrap();
+    assert_eq!(first.filled(), b"");
+}
+
+#[test]
+fn chain_read_buf() {
+    let buf: &mut [_] = &mut [MaybeUninit::uninit(); 4];
+    let mut buf: BorrowedBuf<'_> = buf.into();
+
+    let mut c = (&b"12"[..]).chain(&b""[..]).chain(&b"345"[..]);
+    c.read_buf(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), b"12");
+    c.read_buf(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), b"1234");
+    buf.clear();
+    c.read_buf(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), b"5");
+    buf.clear();
+    c.read_buf(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), b"");
+}
+
 #[test]
 fn take_eof() {
     struct R;
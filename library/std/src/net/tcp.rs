@@ -10,7 +10,7 @@
 use crate::iter::FusedIterator;
 use crate::net::{Shutdown, SocketAddr, ToSocketAddrs};
 use crate::sys_common::net as net_imp;
-use crate::sys_common::{AsInner, FromInner, IntoInner};
+use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
 use crate::time::Duration;
 
 /// A TCP stream between a local and a remote socket.
@@ -110,6 +110,33 @@ pub struct IntoIncoming {
     listener: TcpListener,
 }
 
+/// A builder for TCP sockets that need options set before they are bound or
+/// connected.
+///
+/// Some socket options, such as `SO_REUSEADDR`, `SO_REUSEPORT`,
+/// `IPV6_V6ONLY` or the buffer sizes used to negotiate the TCP window, only
+/// have an effect if they are set before the socket is bound or connected.
+/// [`TcpListener::bind`] and [`TcpStream::connect`] don't leave a window to do
+/// that, so a `TcpBuilder` collects the options first and applies them to
+/// every socket it creates.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(net_socket_options)]
+///
+/// use std::net::TcpBuilder;
+///
+/// let listener = TcpBuilder::new()
+///     .reuse_address(true)
+///     .recv_buffer_size(256 * 1024)
+///     .listen("127.0.0.1:8080")
+///     .expect("couldn't bind listener");
+/// ```
+#[derive(Clone)]
+#[unstable(feature = "net_socket_options", issue = "none")]
+pub struct TcpBuilder(net_imp::TcpBuilder);
+
 impl TcpStream {
     /// Opens a TCP connection to a remote host.
     ///
@@ -503,6 +530,207 @@ pub fn nodelay(&self) -> io::Result<bool> {
         self.0.nodelay()
     }
 
+    /// Sets the value of the `SO_KEEPALIVE` option on this socket, along with
+    /// the idle time before the first keepalive probe is sent.
+    ///
+    /// If `None` is specified, keepalive probes are disabled. Otherwise the
+    /// connection is probed once it has been idle for the given duration,
+    /// rounded up to whole seconds and to at least one second. The interval
+    /// between probes and the number of unanswered probes before the
+    /// connection is dropped can be tuned with
+    /// [`TcpStream::set_keepalive_interval`] and
+    /// [`TcpStream::set_keepalive_retries`].
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On platforms that only allow the idle time to be configured system-wide,
+    /// such as OpenBSD, only `SO_KEEPALIVE` is set and the given duration is
+    /// ignored. [`TcpStream::keepalive`] then fails with
+    /// [`io::ErrorKind::Unsupported`] while keepalive probes are enabled.
+    ///
+    /// This is also the case on Windows versions older than Windows 10 version
+    /// 1709, which don't have the per-socket `TCP_KEEPIDLE` and `TCP_KEEPINTVL`
+    /// options: the system-wide idle time, two hours by default, is used.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_options)]
+    ///
+    /// use std::net::TcpStream;
+    /// use std::time::Duration;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// stream.set_keepalive(Some(Duration::from_secs(60))).expect("set_keepalive call failed");
+    /// ```
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn set_keepalive(&self, keepalive: Option<Duration>) -> io::Result<()> {
+        self.0.set_keepalive(keepalive)
+    }
+
+    /// Gets the keepalive idle time of this socket, or `None` if the
+    /// `SO_KEEPALIVE` option is not set.
+    ///
+    /// For more information about this option, see [`TcpStream::set_keepalive`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_options)]
+    ///
+    /// use std::net::TcpStream;
+    /// use std::time::Duration;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// stream.set_keepalive(Some(Duration::from_secs(60))).expect("set_keepalive call failed");
+    /// assert_eq!(stream.keepalive().unwrap(), Some(Duration::from_secs(60)));
+    /// ```
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+        self.0.keepalive()
+    }
+
+    /// Sets the value of the `TCP_KEEPINTVL` option on this socket.
+    ///
+    /// This is the time between two keepalive probes when the previous one
+    /// went unanswered, rounded up to whole seconds and to at least one second.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This fails with [`io::ErrorKind::Unsupported`] on platforms that only
+    /// allow the interval to be configured system-wide, such as OpenBSD, and on
+    /// Windows versions older than Windows 10 version 1709.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_options)]
+    ///
+    /// use std::net::TcpStream;
+    /// use std::time::Duration;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// stream.set_keepalive_interval(Duration::from_secs(10))
+    ///       .expect("set_keepalive_interval call failed");
+    /// ```
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()> {
+        self.0.set_keepalive_interval(interval)
+    }
+
+    /// Gets the value of the `TCP_KEEPINTVL` option on this socket.
+    ///
+    /// For more information about this option, see
+    /// [`TcpStream::set_keepalive_interval`].
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn keepalive_interval(&self) -> io::Result<Duration> {
+        self.0.keepalive_interval()
+    }
+
+    /// Sets the value of the `TCP_KEEPCNT` option on this socket.
+    ///
+    /// This is the number of unanswered keepalive probes after which the
+    /// connection is considered dead.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This fails with [`io::ErrorKind::Unsupported`] on platforms that only
+    /// allow the number of probes to be configured system-wide, such as
+    /// OpenBSD, and on Windows versions older than Windows 10 version 1703.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_options)]
+    ///
+    /// use std::net::TcpStream;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// stream.set_keepalive_retries(5).expect("set_keepalive_retries call failed");
+    /// ```
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn set_keepalive_retries(&self, retries: u32) -> io::Result<()> {
+        self.0.set_keepalive_retries(retries)
+    }
+
+    /// Gets the value of the `TCP_KEEPCNT` option on this socket.
+    ///
+    /// For more information about this option, see
+    /// [`TcpStream::set_keepalive_retries`].
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn keepalive_retries(&self) -> io::Result<u32> {
+        self.0.keepalive_retries()
+    }
+
+    /// Sets the value of the `SO_SNDBUF` option on this socket.
+    ///
+    /// This is a hint for the size of the kernel buffer holding data that has
+    /// been written but not yet acknowledged by the peer. The operating system
+    /// may round or scale the value; Linux, for example, doubles it to account
+    /// for bookkeeping overhead, which is then reported back by
+    /// [`TcpStream::send_buffer_size`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_options)]
+    ///
+    /// use std::net::TcpStream;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// stream.set_send_buffer_size(64 * 1024).expect("set_send_buffer_size call failed");
+    /// ```
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_send_buffer_size(size)
+    }
+
+    /// Gets the value of the `SO_SNDBUF` option on this socket.
+    ///
+    /// For more information about this option, see
+    /// [`TcpStream::set_send_buffer_size`].
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.0.send_buffer_size()
+    }
+
+    /// Sets the value of the `SO_RCVBUF` option on this socket.
+    ///
+    /// This is a hint for the size of the kernel buffer holding received data
+    /// that has not been read yet, and bounds the TCP receive window. As with
+    /// [`TcpStream::set_send_buffer_size`], the operating system may adjust the
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_options)]
+    ///
+    /// use std::net::TcpStream;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// stream.set_recv_buffer_size(64 * 1024).expect("set_recv_buffer_size call failed");
+    /// ```
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_recv_buffer_size(size)
+    }
+
+    /// Gets the value of the `SO_RCVBUF` option on this socket.
+    ///
+    /// For more information about this option, see
+    /// [`TcpStream::set_recv_buffer_size`].
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.0.recv_buffer_size()
+    }
+
     /// Sets the value for the `IP_TTL` option on this socket.
     ///
     /// This value sets the time-to-live field that is used in every packet sent
@@ -623,6 +851,10 @@ fn read_buf(&mut self, buf: BorrowedCursor<'_>) -> io::Result<()> {
         self.0.read_buf(buf)
     }
 
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        self.0.read_buf_vectored(cursors)
+    }
+
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.0.read_vectored(bufs)
     }
@@ -661,6 +893,10 @@ fn read_buf(&mut self, buf: BorrowedCursor<'_>) -> io::Result<()> {
         self.0.read_buf(buf)
     }
 
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        self.0.read_buf_vectored(cursors)
+    }
+
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.0.read_vectored(bufs)
     }
@@ -1067,3 +1303,172 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
+
+impl TcpBuilder {
+    /// Creates a new builder with the platform's default options.
+    ///
+    /// Without further configuration, [`TcpBuilder::listen`] behaves like
+    /// [`TcpListener::bind`] and [`TcpBuilder::connect`] like
+    /// [`TcpStream::connect`].
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    #[must_use]
+    pub fn new() -> TcpBuilder {
+        TcpBuilder(net_imp::TcpBuilder::new())
+    }
+
+    /// Sets the `SO_REUSEADDR` option for sockets created by this builder.
+    ///
+    /// On Unix-like platforms, listeners set this by default so that a
+    /// restarted server can rebind its port while connections from a previous
+    /// run are still in `TIME_WAIT`.
+    ///
+    /// On Windows, `SO_REUSEADDR` allows another socket to bind to a port that
+    /// is actively in use, so it is off by default. Enabling it on a listener
+    /// exposes it to socket hijacking: any other socket that also sets
+    /// `SO_REUSEADDR`, including one in another process, can then bind the
+    /// same address and port and receive the incoming connections meant for
+    /// this listener.
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn reuse_address(&mut self, reuse_address: bool) -> &mut Self {
+        self.0.reuse_address(reuse_address);
+        self
+    }
+
+    /// Sets the `SO_REUSEPORT` option for sockets created by this builder.
+    ///
+    /// This allows several sockets to bind to the same address and port, and
+    /// is off by default.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// Creating a socket fails with [`io::ErrorKind::Unsupported`] if this is
+    /// enabled on a platform without `SO_REUSEPORT`, such as Windows.
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn reuse_port(&mut self, reuse_port: bool) -> &mut Self {
+        self.0.reuse_port(reuse_port);
+        self
+    }
+
+    /// Sets the `IPV6_V6ONLY` option for IPv6 sockets created by this builder.
+    ///
+    /// If set, an IPv6 listener bound to the unspecified address `[::]` only
+    /// accepts IPv6 connections instead of also accepting IPv4 ones. The
+    /// option is ignored for IPv4 addresses. If not set, the platform default
+    /// is used.
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn only_v6(&mut self, only_v6: bool) -> &mut Self {
+        self.0.only_v6(only_v6);
+        self
+    }
+
+    /// Sets the `SO_SNDBUF` option for sockets created by this builder.
+    ///
+    /// For more information about this option, see
+    /// [`TcpStream::set_send_buffer_size`].
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn send_buffer_size(&mut self, size: usize) -> &mut Self {
+        self.0.send_buffer_size(size);
+        self
+    }
+
+    /// Sets the `SO_RCVBUF` option for sockets created by this builder.
+    ///
+    /// Since the TCP window scale is negotiated during the handshake, a large
+    /// receive buffer has to be requested here rather than after the
+    /// connection has been established. Sockets accepted from a listener
+    /// inherit the listener's receive buffer size.
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn recv_buffer_size(&mut self, size: usize) -> &mut Self {
+        self.0.recv_buffer_size(size);
+        self
+    }
+
+    /// Sets the maximum number of pending connections a listener created by
+    /// this builder will queue.
+    ///
+    /// The operating system may silently cap the value.
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn backlog(&mut self, backlog: u32) -> &mut Self {
+        self.0.backlog(backlog);
+        self
+    }
+
+    /// Sets the local address that streams created by
+    /// [`TcpBuilder::connect`] are bound to before connecting.
+    ///
+    /// This does not affect [`TcpBuilder::listen`].
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn bind(&mut self, addr: SocketAddr) -> &mut Self {
+        self.0.bind(addr);
+        self
+    }
+
+    /// Creates a new [`TcpListener`] bound to the specified address, with the
+    /// options of this builder applied before binding.
+    ///
+    /// If `addr` yields multiple addresses, they are tried in order like in
+    /// [`TcpListener::bind`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_options)]
+    ///
+    /// use std::net::TcpBuilder;
+    ///
+    /// let listener = TcpBuilder::new()
+    ///     .only_v6(true)
+    ///     .backlog(1024)
+    ///     .listen("[::]:8080")
+    ///     .expect("couldn't bind listener");
+    /// ```
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn listen<A: ToSocketAddrs>(&self, addr: A) -> io::Result<TcpListener> {
+        super::each_addr(addr, |addr| self.0.listen(addr)).map(TcpListener)
+    }
+
+    /// Opens a TCP connection to a remote host, with the options of this
+    /// builder applied before connecting.
+    ///
+    /// If `addr` yields multiple addresses, they are tried in order like in
+    /// [`TcpStream::connect`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_options)]
+    ///
+    /// use std::net::{SocketAddr, TcpBuilder};
+    ///
+    /// let stream = TcpBuilder::new()
+    ///     .bind(SocketAddr::from(([192, 168, 0, 2], 0)))
+    ///     .send_buffer_size(1024 * 1024)
+    ///     .connect("192.168.0.1:8080")
+    ///     .expect("couldn't connect to the server");
+    /// ```
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn connect<A: ToSocketAddrs>(&self, addr: A) -> io::Result<TcpStream> {
+        super::each_addr(addr, |addr| self.0.connect(addr)).map(TcpStream)
+    }
+}
+
+#[unstable(feature = "net_socket_options", issue = "none")]
+impl Default for TcpBuilder {
+    fn default() -> TcpBuilder {
+        TcpBuilder::new()
+    }
+}
+
+impl AsInnerMut<net_imp::TcpBuilder> for TcpBuilder {
+    #[inline]
+    fn as_inner_mut(&mut self) -> &mut net_imp::TcpBuilder {
+        &mut self.0
+    }
+}
+
+#[unstable(feature = "net_socket_options", issue = "none")]
+impl fmt::Debug for TcpBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -316,6 +316,31 @@ fn read_buf() {
     })
 }
 
+#[test]
+#[cfg(unix)]
+fn read_buf_vectored() {
+    each_ip(&mut |addr| {
+        let srv = t!(TcpListener::bind(&addr));
+        let t = thread::spawn(move || {
+            let mut s = t!(TcpStream::connect(&addr));
+            s.write_all(&[1, 2, 3, 4]).unwrap();
+        });
+
+        let mut s = t!(srv.accept()).0;
+        let mut first: [MaybeUninit<u8>; 2] = MaybeUninit::uninit_array();
+        let mut first = BorrowedBuf::from(first.as_mut_slice());
+        let mut second: [MaybeUninit<u8>; 128] = MaybeUninit::uninit_array();
+        let mut second = BorrowedBuf::from(second.as_mut_slice());
+        t!(s.read_buf_vectored(&mut [first.unfilled(), second.unfilled()]));
+        assert_eq!(first.filled(), &[1, 2]);
+        assert_eq!(second.filled(), &[3, 4]);
+        // TcpStream::read_buf_vectored should omit buffer initialization.
+        assert_eq!(second.init_len(), 2);
+
+        t.join().ok().expect("thread panicked");
+    })
+}
+
 #[test]
 fn read_vectored() {
     each_ip(&mut |addr| {
@@ -836,6 +861,90 @@ fn nodelay() {
     assert_eq!(false, t!(stream.nodelay()));
 }
 
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn keepalive() {
+    let addr = next_test_ip4();
+    let _listener = t!(TcpListener::bind(&addr));
+
+    let stream = t!(TcpStream::connect(&("localhost", addr.port())));
+
+    assert_eq!(None, t!(stream.keepalive()));
+    if cfg!(any(target_os = "android", target_os = "linux", target_os = "macos")) {
+        t!(stream.set_keepalive(Some(Duration::from_millis(1500))));
+        assert_eq!(Some(Duration::from_secs(2)), t!(stream.keepalive()));
+        t!(stream.set_keepalive(Some(Duration::ZERO)));
+        assert_eq!(Some(Duration::from_secs(1)), t!(stream.keepalive()));
+        t!(stream.set_keepalive_interval(Duration::from_secs(3)));
+        assert_eq!(Duration::from_secs(3), t!(stream.keepalive_interval()));
+        t!(stream.set_keepalive_retries(4));
+        assert_eq!(4, t!(stream.keepalive_retries()));
+    }
+    t!(stream.set_keepalive(None));
+    assert_eq!(None, t!(stream.keepalive()));
+}
+
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn buffer_sizes() {
+    let addr = next_test_ip4();
+    let _listener = t!(TcpListener::bind(&addr));
+
+    let stream = t!(TcpStream::connect(&("localhost", addr.port())));
+
+    // The kernel may round up or scale the requested sizes.
+    t!(stream.set_send_buffer_size(64 * 1024));
+    assert!(t!(stream.send_buffer_size()) >= 64 * 1024);
+    t!(stream.set_recv_buffer_size(64 * 1024));
+    assert!(t!(stream.recv_buffer_size()) >= 64 * 1024);
+}
+
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn builder_listen_connect() {
+    each_ip(&mut |addr| {
+        let listener = t!(TcpBuilder::new()
+            .reuse_address(true)
+            .only_v6(addr.is_ipv6())
+            .recv_buffer_size(128 * 1024)
+            .backlog(16)
+            .listen(&addr));
+        assert_eq!(addr, t!(listener.local_addr()));
+        if addr.is_ipv6() {
+            assert_eq!(true, t!(listener.only_v6()));
+        }
+
+        let local = SocketAddr::new(addr.ip(), 0);
+        let mut stream =
+            t!(TcpBuilder::new().bind(local).send_buffer_size(32 * 1024).connect(&addr));
+        assert_eq!(addr.ip(), t!(stream.local_addr()).ip());
+        assert!(t!(stream.send_buffer_size()) >= 32 * 1024);
+
+        let (mut accepted, peer) = t!(listener.accept());
+        assert_eq!(peer, t!(stream.local_addr()));
+        t!(stream.write_all(&[42]));
+        let mut buf = [0];
+        t!(accepted.read_exact(&mut buf));
+        assert_eq!(buf, [42]);
+    })
+}
+
+// On Windows, SO_REUSEADDR has different semantics.
+#[test]
+#[cfg(unix)]
+fn builder_rebind_after_close() {
+    let addr = next_test_ip4();
+    let listener = t!(TcpBuilder::new().reuse_address(true).listen(&addr));
+    let stream = t!(TcpStream::connect(&addr));
+    let (accepted, _) = t!(listener.accept());
+    drop(accepted);
+    drop(stream);
+    drop(listener);
+
+    // The server side closed first, leaving its end in TIME_WAIT.
+    t!(TcpBuilder::new().reuse_address(true).listen(&addr));
+}
+
 #[test]
 #[cfg_attr(target_env = "sgx", ignore)]
 fn ttl() {
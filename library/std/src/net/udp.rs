@@ -5,7 +5,7 @@
 use crate::io::{self, ErrorKind};
 use crate::net::{Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs};
 use crate::sys_common::net as net_imp;
-use crate::sys_common::{AsInner, FromInner, IntoInner};
+use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
 use crate::time::Duration;
 
 /// A UDP socket.
@@ -57,6 +57,35 @@
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct UdpSocket(net_imp::UdpSocket);
 
+/// A builder for UDP sockets that need options set before they are bound.
+///
+/// Like [`TcpBuilder`], it collects options such as `SO_REUSEADDR`,
+/// `SO_REUSEPORT` or `IPV6_V6ONLY`, which only have an effect if they are set
+/// before the socket is bound, and applies them to every socket it creates.
+/// This allows, for example, several processes to receive the datagrams sent
+/// to the same multicast group and port.
+///
+/// [`TcpBuilder`]: crate::net::TcpBuilder
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(net_socket_options)]
+///
+/// use std::net::{Ipv4Addr, UdpBuilder};
+///
+/// let socket = UdpBuilder::new()
+///     .reuse_address(true)
+///     .bind("0.0.0.0:5353")
+///     .expect("couldn't bind socket");
+/// socket
+///     .join_multicast_v4(&Ipv4Addr::new(224, 0, 0, 251), &Ipv4Addr::UNSPECIFIED)
+///     .expect("couldn't join multicast group");
+/// ```
+#[derive(Clone)]
+#[unstable(feature = "net_socket_options", issue = "none")]
+pub struct UdpBuilder(net_imp::UdpBuilder);
+
 impl UdpSocket {
     /// Creates a UDP socket from the given address.
     ///
@@ -416,6 +445,67 @@ pub fn broadcast(&self) -> io::Result<bool> {
         self.0.broadcast()
     }
 
+    /// Sets the value of the `SO_SNDBUF` option for this socket.
+    ///
+    /// This is a hint for the size of the kernel buffer holding datagrams
+    /// that have been sent but not yet transmitted. The operating system may
+    /// round or scale the value; Linux, for example, doubles it to account for
+    /// bookkeeping overhead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_options)]
+    ///
+    /// use std::net::UdpSocket;
+    ///
+    /// let socket = UdpSocket::bind("127.0.0.1:34254").expect("couldn't bind to address");
+    /// socket.set_send_buffer_size(64 * 1024).expect("set_send_buffer_size call failed");
+    /// ```
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_send_buffer_size(size)
+    }
+
+    /// Gets the value of the `SO_SNDBUF` option for this socket.
+    ///
+    /// For more information about this option, see
+    /// [`UdpSocket::set_send_buffer_size`].
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.0.send_buffer_size()
+    }
+
+    /// Sets the value of the `SO_RCVBUF` option for this socket.
+    ///
+    /// This is a hint for the size of the kernel buffer holding received
+    /// datagrams that have not been read yet. Datagrams arriving while the
+    /// buffer is full are dropped, so bursty receivers may want to raise it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_options)]
+    ///
+    /// use std::net::UdpSocket;
+    ///
+    /// let socket = UdpSocket::bind("127.0.0.1:34254").expect("couldn't bind to address");
+    /// socket.set_recv_buffer_size(1024 * 1024).expect("set_recv_buffer_size call failed");
+    /// ```
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_recv_buffer_size(size)
+    }
+
+    /// Gets the value of the `SO_RCVBUF` option for this socket.
+    ///
+    /// For more information about this option, see
+    /// [`UdpSocket::set_recv_buffer_size`].
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.0.recv_buffer_size()
+    }
+
     /// Sets the value of the `IP_MULTICAST_LOOP` option for this socket.
     ///
     /// If enabled, multicast packets will be looped back to the local socket.
@@ -821,3 +911,120 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
+
+impl UdpBuilder {
+    /// Creates a new builder with the platform's default options.
+    ///
+    /// Without further configuration, [`UdpBuilder::bind`] behaves like
+    /// [`UdpSocket::bind`].
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    #[must_use]
+    pub fn new() -> UdpBuilder {
+        UdpBuilder(net_imp::UdpBuilder::new())
+    }
+
+    /// Sets the `SO_REUSEADDR` option for sockets created by this builder.
+    ///
+    /// Unlike with [`TcpBuilder`], this is off by default on all platforms.
+    /// It allows several sockets to bind the same multicast address and port
+    /// on most platforms; on Windows, it allows binding any address and port
+    /// actively in use.
+    ///
+    /// [`TcpBuilder`]: crate::net::TcpBuilder
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn reuse_address(&mut self, reuse_address: bool) -> &mut Self {
+        self.0.reuse_address(reuse_address);
+        self
+    }
+
+    /// Sets the `SO_REUSEPORT` option for sockets created by this builder.
+    ///
+    /// This allows several sockets to bind to the same address and port, and
+    /// is off by default.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// Creating a socket fails with [`io::ErrorKind::Unsupported`] if this is
+    /// enabled on a platform without `SO_REUSEPORT`, such as Windows.
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn reuse_port(&mut self, reuse_port: bool) -> &mut Self {
+        self.0.reuse_port(reuse_port);
+        self
+    }
+
+    /// Sets the `IPV6_V6ONLY` option for IPv6 sockets created by this builder.
+    ///
+    /// If set, an IPv6 socket bound to the unspecified address `[::]` only
+    /// receives IPv6 datagrams instead of also receiving IPv4 ones. The option
+    /// is ignored for IPv4 addresses. If not set, the platform default is used.
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn only_v6(&mut self, only_v6: bool) -> &mut Self {
+        self.0.only_v6(only_v6);
+        self
+    }
+
+    /// Sets the `SO_SNDBUF` option for sockets created by this builder.
+    ///
+    /// For more information about this option, see
+    /// [`UdpSocket::set_send_buffer_size`].
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn send_buffer_size(&mut self, size: usize) -> &mut Self {
+        self.0.send_buffer_size(size);
+        self
+    }
+
+    /// Sets the `SO_RCVBUF` option for sockets created by this builder.
+    ///
+    /// For more information about this option, see
+    /// [`UdpSocket::set_recv_buffer_size`].
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn recv_buffer_size(&mut self, size: usize) -> &mut Self {
+        self.0.recv_buffer_size(size);
+        self
+    }
+
+    /// Creates a new [`UdpSocket`] bound to the specified address, with the
+    /// options of this builder applied before binding.
+    ///
+    /// If `addr` yields multiple addresses, they are tried in order like in
+    /// [`UdpSocket::bind`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_options)]
+    ///
+    /// use std::net::UdpBuilder;
+    ///
+    /// let socket = UdpBuilder::new()
+    ///     .only_v6(true)
+    ///     .recv_buffer_size(1024 * 1024)
+    ///     .bind("[::]:34254")
+    ///     .expect("couldn't bind socket");
+    /// ```
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    pub fn bind<A: ToSocketAddrs>(&self, addr: A) -> io::Result<UdpSocket> {
+        super::each_addr(addr, |addr| self.0.bind(addr)).map(UdpSocket)
+    }
+}
+
+#[unstable(feature = "net_socket_options", issue = "none")]
+impl Default for UdpBuilder {
+    fn default() -> UdpBuilder {
+        UdpBuilder::new()
+    }
+}
+
+impl AsInnerMut<net_imp::UdpBuilder> for UdpBuilder {
+    #[inline]
+    fn as_inner_mut(&mut self) -> &mut net_imp::UdpBuilder {
+        &mut self.0
+    }
+}
+
+#[unstable(feature = "net_socket_options", issue = "none")]
+impl fmt::Debug for UdpBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
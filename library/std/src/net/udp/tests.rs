@@ -345,6 +345,19 @@ fn ttl() {
     assert_eq!(ttl, t!(stream.ttl()));
 }
 
+#[test]
+fn buffer_sizes() {
+    let addr = next_test_ip4();
+
+    let socket = t!(UdpSocket::bind(&addr));
+
+    // The kernel may round up or scale the requested sizes.
+    t!(socket.set_send_buffer_size(64 * 1024));
+    assert!(t!(socket.send_buffer_size()) >= 64 * 1024);
+    t!(socket.set_recv_buffer_size(64 * 1024));
+    assert!(t!(socket.recv_buffer_size()) >= 64 * 1024);
+}
+
 #[test]
 fn set_nonblocking() {
     each_ip(&mut |addr, _| {
@@ -366,3 +379,42 @@ fn set_nonblocking() {
         }
     })
 }
+
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn builder_bind() {
+    each_ip(&mut |server_ip, client_ip| {
+        let server = t!(UdpBuilder::new()
+            .only_v6(server_ip.is_ipv6())
+            .recv_buffer_size(128 * 1024)
+            .bind(&server_ip));
+        assert_eq!(server_ip, t!(server.local_addr()));
+        assert!(t!(server.recv_buffer_size()) >= 128 * 1024);
+
+        let client = t!(UdpBuilder::new().send_buffer_size(32 * 1024).bind(&client_ip));
+        assert!(t!(client.send_buffer_size()) >= 32 * 1024);
+        t!(client.send_to(&[42], &server_ip));
+        let mut buf = [0];
+        let (len, src) = t!(server.recv_from(&mut buf));
+        assert_eq!((len, src, buf), (1, client_ip, [42]));
+    })
+}
+
+// Other platforms only allow duplicate bindings of multicast addresses with
+// `SO_REUSEADDR`.
+#[test]
+#[cfg(target_os = "linux")]
+fn builder_reuse_address() {
+    let addr = next_test_ip4();
+    let _first = t!(UdpBuilder::new().reuse_address(true).bind(&addr));
+    t!(UdpBuilder::new().reuse_address(true).bind(&addr));
+    assert!(UdpBuilder::new().bind(&addr).is_err());
+}
+
+#[test]
+fn builder_debug() {
+    assert_eq!(
+        format!("{:?}", UdpBuilder::new().reuse_address(true).recv_buffer_size(1024)),
+        "UdpBuilder { reuse_address: true, recv_buffer_size: 1024 }",
+    );
+}
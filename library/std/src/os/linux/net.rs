@@ -5,5 +5,9 @@
 #[stable(feature = "unix_socket_abstract", since = "1.70.0")]
 pub use crate::os::net::linux_ext::addr::SocketAddrExt;
 
+#[unstable(feature = "net_socket_options", issue = "none")]
+pub use crate::os::net::linux_ext::tcp::TcpBuilderExt;
 #[unstable(feature = "tcp_quickack", issue = "96256")]
 pub use crate::os::net::linux_ext::tcp::TcpStreamExt;
+#[unstable(feature = "net_socket_options", issue = "none")]
+pub use crate::os::net::linux_ext::udp::UdpBuilderExt;
@@ -6,11 +6,13 @@
 
 use crate::io::Result;
 use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
-use crate::process;
+use crate::process::{self, ExitStatus};
 use crate::sealed::Sealed;
+use crate::sys;
 #[cfg(not(doc))]
 use crate::sys::fd::FileDesc;
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
+use crate::time::Duration;
 
 #[cfg(doc)]
 struct FileDesc;
@@ -51,6 +53,80 @@ pub struct PidFd {
     inner: FileDesc,
 }
 
+impl PidFd {
+    /// Forces the process to exit, by sending it a `SIGKILL`.
+    ///
+    /// Unlike [`Child::kill`], this can't kill an unrelated process which reused the PID of the
+    /// child after it was reaped: the signal is sent to the process the pidfd refers to, or the
+    /// call fails with an error if that process was already reaped.
+    ///
+    /// This corresponds to calling [`pidfd_send_signal(2)`] with `SIGKILL`.
+    ///
+    /// [`Child::kill`]: process::Child::kill
+    /// [`pidfd_send_signal(2)`]: https://man7.org/linux/man-pages/man2/pidfd_send_signal.2.html
+    pub fn kill(&self) -> Result<()> {
+        self.send_signal(libc::SIGKILL)
+    }
+
+    /// Sends the signal `signal` to the process.
+    ///
+    /// See [`kill`](Self::kill) for how the process is designated.
+    ///
+    /// This corresponds to calling [`pidfd_send_signal(2)`].
+    ///
+    /// [`pidfd_send_signal(2)`]: https://man7.org/linux/man-pages/man2/pidfd_send_signal.2.html
+    pub fn send_signal(&self, signal: i32) -> Result<()> {
+        sys::process::pidfd::send_signal(self.as_fd(), signal)
+    }
+
+    /// Waits for the process to exit, reaps it, and returns its exit status.
+    ///
+    /// The process must be a child of the current process. Once it is reaped, waiting for it
+    /// again, with this function or through the [`Child`] it was created for, returns an error.
+    /// Use [`ChildExt::into_pidfd`] to give up the [`Child`] so that the process can only be
+    /// waited for through the pidfd.
+    ///
+    /// This corresponds to calling [`waitid(2)`] with `P_PIDFD`, which requires Linux 5.4: on
+    /// older kernels, this returns an error, while [`Child::wait`] falls back to waiting on the
+    /// PID.
+    ///
+    /// [`Child`]: process::Child
+    /// [`Child::wait`]: process::Child::wait
+    /// [`waitid(2)`]: https://man7.org/linux/man-pages/man2/waitid.2.html
+    pub fn wait(&self) -> Result<ExitStatus> {
+        let status = sys::process::pidfd::wait(self.as_fd(), true)?
+            .expect("waitid() without WNOHANG returned before the process exited");
+        Ok(ExitStatus::from_inner(status))
+    }
+
+    /// Reaps the process if it has exited, and returns its exit status, or returns `None` right
+    /// away if it is still running.
+    ///
+    /// See [`wait`](Self::wait) for the requirements on the process.
+    ///
+    /// This corresponds to calling [`waitid(2)`] with `P_PIDFD` and `WNOHANG`.
+    ///
+    /// [`waitid(2)`]: https://man7.org/linux/man-pages/man2/waitid.2.html
+    pub fn try_wait(&self) -> Result<Option<ExitStatus>> {
+        Ok(sys::process::pidfd::wait(self.as_fd(), false)?.map(ExitStatus::from_inner))
+    }
+
+    /// Waits until the process exits, or until `timeout` elapses if it isn't `None`, and returns
+    /// whether it exited.
+    ///
+    /// Unlike [`wait`](Self::wait), this doesn't reap the process, and works with any process,
+    /// not only children of the current process. A timeout of [`Duration::ZERO`] checks whether
+    /// the process exited without blocking.
+    ///
+    /// This polls the pidfd with [`poll(2)`], which reports it readable once the process exits.
+    /// The pidfd can also be registered in an event loop, like `epoll`, in the same way.
+    ///
+    /// [`poll(2)`]: https://man7.org/linux/man-pages/man2/poll.2.html
+    pub fn poll_exit(&self, timeout: Option<Duration>) -> Result<bool> {
+        sys::process::pidfd::poll_exit(self.as_fd(), timeout)
+    }
+}
+
 impl AsInner<FileDesc> for PidFd {
     #[inline]
     fn as_inner(&self) -> &FileDesc {
@@ -136,6 +212,34 @@ pub trait ChildExt: Sealed {
     /// [`create_pidfd`]: CommandExt::create_pidfd
     /// [`Child`]: process::Child
     fn take_pidfd(&mut self) -> Result<PidFd>;
+
+    /// Converts this [`Child`] into its [`PidFd`], or returns it unchanged if no pidfd is
+    /// available, see [`pidfd`].
+    ///
+    /// The handles to the standard streams of the child are closed. Afterwards, the process can
+    /// only be signaled and waited for through the pidfd, which isn't subject to PID reuse, so
+    /// this is the race-free way to manage a child with a pidfd.
+    ///
+    /// [`Child`]: process::Child
+    /// [`pidfd`]: fn@ChildExt::pidfd
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(linux_pidfd)]
+    /// use std::os::linux::process::{ChildExt, CommandExt};
+    /// use std::process::Command;
+    ///
+    /// let child = Command::new("sleep").arg("60").create_pidfd(true).spawn()?;
+    /// let pidfd = child.into_pidfd().expect("failed to create a pidfd");
+    /// pidfd.kill()?;
+    /// let status = pidfd.wait()?;
+    /// assert!(!status.success());
+    /// # std::io::Result::Ok(())
+    /// ```
+    fn into_pidfd(self) -> crate::result::Result<PidFd, Self>
+    where
+        Self: Sized;
 }
 
 /// Os-specific extensions for [`Command`]
@@ -8,5 +8,8 @@
 #[unstable(feature = "tcp_quickack", issue = "96256")]
 pub(crate) mod tcp;
 
+#[unstable(feature = "net_socket_options", issue = "none")]
+pub(crate) mod udp;
+
 #[cfg(test)]
 mod tests;
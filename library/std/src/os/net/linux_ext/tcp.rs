@@ -5,7 +5,7 @@
 use crate::io;
 use crate::net;
 use crate::sealed::Sealed;
-use crate::sys_common::AsInner;
+use crate::sys_common::{AsInner, AsInnerMut};
 
 /// Os-specific extensions for [`TcpStream`]
 ///
@@ -68,3 +68,46 @@ fn quickack(&self) -> io::Result<bool> {
         self.as_inner().as_inner().quickack()
     }
 }
+
+/// Os-specific extensions for [`TcpBuilder`]
+///
+/// [`TcpBuilder`]: net::TcpBuilder
+#[unstable(feature = "net_socket_options", issue = "none")]
+pub trait TcpBuilderExt: Sealed {
+    /// Binds sockets created by this builder to the network interface named
+    /// `interface`, using the `SO_BINDTODEVICE` option.
+    ///
+    /// Packets are then only sent and received through that interface,
+    /// regardless of the routing table. The name is given without a trailing
+    /// NUL byte, e.g. `b"eth0"`. Setting this option usually requires the
+    /// `CAP_NET_RAW` capability.
+    ///
+    /// See [`man 7 socket`](https://man7.org/linux/man-pages/man7/socket.7.html)
+    /// for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_options)]
+    /// use std::net::TcpBuilder;
+    /// use std::os::linux::net::TcpBuilderExt;
+    ///
+    /// let stream = TcpBuilder::new()
+    ///     .bind_device(b"eth0")
+    ///     .connect("192.168.0.1:8080")
+    ///     .expect("Couldn't connect to the server...");
+    /// ```
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    fn bind_device(&mut self, interface: &[u8]) -> &mut Self;
+}
+
+#[unstable(feature = "net_socket_options", issue = "none")]
+impl Sealed for net::TcpBuilder {}
+
+#[unstable(feature = "net_socket_options", issue = "none")]
+impl TcpBuilderExt for net::TcpBuilder {
+    fn bind_device(&mut self, interface: &[u8]) -> &mut Self {
+        self.as_inner_mut().bind_device(interface);
+        self
+    }
+}
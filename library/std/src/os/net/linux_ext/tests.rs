@@ -26,3 +26,18 @@ macro_rules! t {
     t!(stream.set_quickack(false));
     assert_eq!(false, t!(stream.quickack()));
 }
+
+#[test]
+fn builder_bind_device_debug() {
+    use crate::net::{TcpBuilder, UdpBuilder};
+    use crate::os::net::linux_ext::{tcp::TcpBuilderExt, udp::UdpBuilderExt};
+
+    assert_eq!(
+        format!("{:?}", TcpBuilder::new().bind_device(b"lo")),
+        "TcpBuilder { bind_device: \"lo\", backlog: 128 }",
+    );
+    assert_eq!(
+        format!("{:?}", UdpBuilder::new().bind_device(b"lo")),
+        "UdpBuilder { bind_device: \"lo\" }",
+    );
+}
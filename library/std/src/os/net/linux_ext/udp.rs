@@ -0,0 +1,47 @@
+//! Linux and Android-specific udp extensions to primitives in the [`std::net`] module.
+//!
+//! [`std::net`]: crate::net
+
+use crate::net;
+use crate::sealed::Sealed;
+use crate::sys_common::AsInnerMut;
+
+/// Os-specific extensions for [`UdpBuilder`]
+///
+/// [`UdpBuilder`]: net::UdpBuilder
+#[unstable(feature = "net_socket_options", issue = "none")]
+pub trait UdpBuilderExt: Sealed {
+    /// Binds sockets created by this builder to the network interface named
+    /// `interface`, using the `SO_BINDTODEVICE` option.
+    ///
+    /// For more information about this option, see
+    /// [`TcpBuilderExt::bind_device`].
+    ///
+    /// [`TcpBuilderExt::bind_device`]: crate::os::linux::net::TcpBuilderExt::bind_device
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket_options)]
+    /// use std::net::UdpBuilder;
+    /// use std::os::linux::net::UdpBuilderExt;
+    ///
+    /// let socket = UdpBuilder::new()
+    ///     .bind_device(b"eth0")
+    ///     .bind("0.0.0.0:34254")
+    ///     .expect("couldn't bind socket");
+    /// ```
+    #[unstable(feature = "net_socket_options", issue = "none")]
+    fn bind_device(&mut self, interface: &[u8]) -> &mut Self;
+}
+
+#[unstable(feature = "net_socket_options", issue = "none")]
+impl Sealed for net::UdpBuilder {}
+
+#[unstable(feature = "net_socket_options", issue = "none")]
+impl UdpBuilderExt for net::UdpBuilder {
+    fn bind_device(&mut self, interface: &[u8]) -> &mut Self {
+        self.as_inner_mut().bind_device(interface);
+        self
+    }
+}
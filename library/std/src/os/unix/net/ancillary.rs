@@ -3,12 +3,16 @@
 use super::{sockaddr_un, SocketAddr};
 use crate::io::{self, IoSlice, IoSliceMut};
 use crate::marker::PhantomData;
-use crate::mem::{size_of, zeroed};
-use crate::os::unix::io::RawFd;
+use crate::mem::{self, size_of, zeroed};
+use crate::os::unix::io::{BorrowedFd, FromRawFd, OwnedFd, RawFd};
 use crate::path::Path;
 use crate::ptr::{eq, read_unaligned};
 use crate::slice::from_raw_parts;
 use crate::sys::net::Socket;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::sys_common::FromInner;
+#[cfg(any(doc, target_os = "android", target_os = "linux"))]
+use crate::time::SystemTime;
 
 // FIXME(#43348): Make libc adapt #[doc(cfg(...))] so we don't need these fake definitions here?
 #[cfg(all(
@@ -24,6 +28,7 @@ mod libc {
     pub struct ucred;
     pub struct cmsghdr;
     pub struct sockcred2;
+    pub struct timeval;
     pub type pid_t = i32;
     pub type gid_t = u32;
     pub type uid_t = u32;
@@ -34,6 +39,10 @@ pub(super) fn recv_vectored_with_ancillary_from(
     bufs: &mut [IoSliceMut<'_>],
     ancillary: &mut SocketAncillary<'_>,
 ) -> io::Result<(usize, bool, io::Result<SocketAddr>)> {
+    // Any file descriptors received by a previous call are closed here rather
+    // than being silently overwritten.
+    ancillary.clear();
+
     unsafe {
         let mut msg_name: libc::sockaddr_un = zeroed();
         let mut msg: libc::msghdr = zeroed();
@@ -51,6 +60,7 @@ pub(super) fn recv_vectored_with_ancillary_from(
 
         ancillary.length = msg.msg_controllen as usize;
         ancillary.truncated = msg.msg_flags & libc::MSG_CTRUNC == libc::MSG_CTRUNC;
+        ancillary.received_len = ancillary.length;
 
         let truncated = msg.msg_flags & libc::MSG_TRUNC == libc::MSG_TRUNC;
         let addr = SocketAddr::from_parts(msg_name, msg.msg_namelen);
@@ -390,6 +400,12 @@ pub fn get_gid(&self) -> libc::gid_t {
 /// This control message contains file descriptors.
 ///
 /// The level is equal to `SOL_SOCKET` and the type is equal to `SCM_RIGHTS`.
+///
+/// File descriptors received by a recv operation are owned by the [`SocketAncillary`] they were
+/// received into, and are closed when it is cleared, reused for another recv operation, or
+/// dropped. The raw file descriptors yielded by this iterator must therefore not be closed or
+/// wrapped into an owning type such as `File`; use [`SocketAncillary::take_fds`] to take
+/// ownership of them instead.
 #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
 pub struct ScmRights<'a>(AncillaryDataIter<'a, RawFd>);
 
@@ -463,6 +479,19 @@ pub enum AncillaryData<'a> {
         target_os = "freebsd"
     ))]
     ScmCredentials(ScmCredentials<'a>),
+    /// The security context of the sending peer, received on sockets with
+    /// `SO_PASSSEC` enabled.
+    ///
+    /// The level is equal to `SOL_SOCKET` and the type is equal to `SCM_SECURITY`. A trailing NUL
+    /// byte, if any, is not included.
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    ScmSecurity(&'a [u8]),
+    /// The time at which the message was received by the kernel, received on sockets with
+    /// `SO_TIMESTAMP` enabled.
+    ///
+    /// The level is equal to `SOL_SOCKET` and the type is equal to `SCM_TIMESTAMP`.
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    ScmTimestamp(SystemTime),
 }
 
 impl<'a> AncillaryData<'a> {
@@ -497,6 +526,44 @@ unsafe fn as_credentials(data: &'a [u8]) -> Self {
         AncillaryData::ScmCredentials(scm_credentials)
     }
 
+    /// Create an `AncillaryData::ScmSecurity` variant.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    fn as_security(data: &'a [u8]) -> Self {
+        let label = match data.split_last() {
+            Some((0, label)) => label,
+            _ => data,
+        };
+        AncillaryData::ScmSecurity(label)
+    }
+
+    /// Create an `AncillaryData::ScmTimestamp` variant.
+    ///
+    /// # Safety
+    ///
+    /// `data` must contain a valid control message and the control message must be type of
+    /// `SOL_SOCKET` and level of `SCM_TIMESTAMP`.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    unsafe fn as_timestamp(data: &'a [u8]) -> Result<Self, AncillaryError> {
+        if data.len() < size_of::<libc::timeval>() {
+            return Err(AncillaryError::Unknown {
+                cmsg_level: libc::SOL_SOCKET,
+                cmsg_type: libc::SCM_TIMESTAMP,
+            });
+        }
+        let tv: libc::timeval = read_unaligned(data.as_ptr().cast());
+        // `time_t` may be 32 bits wide, and is negative for times before the epoch, in which case
+        // `tv_usec` still counts forward from `tv_sec`.
+        let usec = i64::from(tv.tv_usec);
+        if !(0..1_000_000).contains(&usec) {
+            return Err(AncillaryError::Unknown {
+                cmsg_level: libc::SOL_SOCKET,
+                cmsg_type: libc::SCM_TIMESTAMP,
+            });
+        }
+        let time = crate::sys::time::SystemTime::new(i64::from(tv.tv_sec), usec * 1000);
+        Ok(AncillaryData::ScmTimestamp(SystemTime::from_inner(time)))
+    }
+
     fn try_from_cmsghdr(cmsg: &'a libc::cmsghdr) -> Result<Self, AncillaryError> {
         unsafe {
             let cmsg_len_zero = libc::CMSG_LEN(0) as usize;
@@ -509,6 +576,10 @@ fn try_from_cmsghdr(cmsg: &'a libc::cmsghdr) -> Result<Self, AncillaryError> {
                     libc::SCM_RIGHTS => Ok(AncillaryData::as_rights(data)),
                     #[cfg(any(target_os = "android", target_os = "linux",))]
                     libc::SCM_CREDENTIALS => Ok(AncillaryData::as_credentials(data)),
+                    #[cfg(any(target_os = "android", target_os = "linux",))]
+                    libc::SCM_SECURITY => Ok(AncillaryData::as_security(data)),
+                    #[cfg(any(target_os = "android", target_os = "linux",))]
+                    libc::SCM_TIMESTAMP => AncillaryData::as_timestamp(data),
                     #[cfg(target_os = "freebsd")]
                     libc::SCM_CREDS2 => Ok(AncillaryData::as_credentials(data)),
                     #[cfg(target_os = "netbsd")]
@@ -602,6 +673,10 @@ pub struct SocketAncillary<'a> {
     buffer: &'a mut [u8],
     length: usize,
     truncated: bool,
+    // Length of the prefix of `buffer` holding the control messages of the last recv operation
+    // whose `SCM_RIGHTS` file descriptors have not been taken yet, and must be closed when
+    // discarded. Messages added after it were not received and are never closed.
+    received_len: usize,
 }
 
 impl<'a> SocketAncillary<'a> {
@@ -618,7 +693,31 @@ impl<'a> SocketAncillary<'a> {
     /// ```
     #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
     pub fn new(buffer: &'a mut [u8]) -> Self {
-        SocketAncillary { buffer, length: 0, truncated: false }
+        SocketAncillary { buffer, length: 0, truncated: false, received_len: 0 }
+    }
+
+    /// Returns the number of bytes needed to hold a control message passing `count` file
+    /// descriptors, including alignment padding.
+    ///
+    /// The buffer given to [`SocketAncillary::new`] should be at least the sum of this for
+    /// every message that will be added or received.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::os::unix::net::SocketAncillary;
+    ///
+    /// let mut ancillary_buffer = vec![0; SocketAncillary::space_for_fds(3)];
+    /// let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    /// assert!(ancillary.add_fds(&[0, 1, 2]));
+    /// ```
+    #[must_use]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn space_for_fds(count: usize) -> usize {
+        let len = count.checked_mul(size_of::<RawFd>()).expect("too many file descriptors");
+        let len = u32::try_from(len).expect("too many file descriptors");
+        unsafe { libc::CMSG_SPACE(len) as usize }
     }
 
     /// Returns the capacity of the buffer.
@@ -650,6 +749,10 @@ pub fn messages(&self) -> Messages<'_> {
 
     /// Is `true` if during a recv operation the ancillary was truncated.
     ///
+    /// This happens when the buffer was too small to hold all control messages. Any file
+    /// descriptors that did not fit were closed by the operating system, and the last message
+    /// may be incomplete.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -717,6 +820,95 @@ pub fn add_fds(&mut self, fds: &[RawFd]) -> bool {
         )
     }
 
+    /// Add borrowed file descriptors to the ancillary data.
+    ///
+    /// This is like [`SocketAncillary::add_fds`], but the borrows ensure that the file
+    /// descriptors stay open until the ancillary data has been sent. The receiver gets its own
+    /// duplicates, so the file descriptors remain owned by the sender.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::fs::File;
+    /// use std::os::unix::io::AsFd;
+    /// use std::os::unix::net::{SocketAncillary, UnixStream};
+    /// use std::io::IoSlice;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let sock = UnixStream::connect("/tmp/sock")?;
+    ///     let file = File::open("/etc/hostname")?;
+    ///
+    ///     let mut ancillary_buffer = [0; 128];
+    ///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    ///     ancillary.add_borrowed_fds(&[file.as_fd()]);
+    ///
+    ///     sock.send_vectored_with_ancillary(&[IoSlice::new(b"file")], &mut ancillary)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn add_borrowed_fds(&mut self, fds: &[BorrowedFd<'a>]) -> bool {
+        self.truncated = false;
+        add_to_ancillary_data(
+            &mut self.buffer,
+            &mut self.length,
+            fds,
+            libc::SOL_SOCKET,
+            libc::SCM_RIGHTS,
+        )
+    }
+
+    /// Takes ownership of the file descriptors received by the last recv operation.
+    ///
+    /// File descriptors received with `SCM_RIGHTS` belong to this `SocketAncillary` until they
+    /// are taken. Any that have not been taken are closed when the ancillary data is cleared,
+    /// reused for another recv operation, or dropped, so they can't leak.
+    ///
+    /// The file descriptors are returned in the order they were received. Calling this again
+    /// returns an empty `Vec`; [`SocketAncillary::messages`] still reports the raw values.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::fs::File;
+    /// use std::os::unix::net::{SocketAncillary, UnixStream};
+    /// use std::io::IoSliceMut;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let sock = UnixStream::connect("/tmp/sock")?;
+    ///
+    ///     let mut ancillary_buffer = [0; 128];
+    ///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    ///
+    ///     let mut buf = [0; 8];
+    ///     sock.recv_vectored_with_ancillary(&mut [IoSliceMut::new(&mut buf)], &mut ancillary)?;
+    ///
+    ///     for fd in ancillary.take_fds() {
+    ///         let file = File::from(fd);
+    ///         println!("received {file:?}");
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn take_fds(&mut self) -> Vec<OwnedFd> {
+        let received_len = mem::take(&mut self.received_len);
+        let received = Messages { buffer: &self.buffer[..received_len], current: None };
+        // SAFETY: the kernel installed the file descriptors of these messages for this process
+        // when they were received, and `received_len` being reset ensures they are handed out
+        // only once. File descriptors added by the caller are past `received_len`.
+        received
+            .filter_map(|message| match message {
+                Ok(AncillaryData::ScmRights(scm_rights)) => Some(scm_rights),
+                _ => None,
+            })
+            .flatten()
+            .map(|fd| unsafe { OwnedFd::from_raw_fd(fd) })
+            .collect()
+    }
+
     /// Add credentials to the ancillary data.
     ///
     /// The function returns `true` if there is enough space in the buffer.
@@ -750,6 +942,9 @@ pub fn add_creds(&mut self, creds: &[SocketCred]) -> bool {
 
     /// Clears the ancillary data, removing all values.
     ///
+    /// Received file descriptors that have not been taken with [`SocketAncillary::take_fds`]
+    /// are closed.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -792,7 +987,15 @@ pub fn add_creds(&mut self, creds: &[SocketCred]) -> bool {
     /// ```
     #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
     pub fn clear(&mut self) {
+        drop(self.take_fds());
         self.length = 0;
         self.truncated = false;
     }
 }
+
+#[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+impl Drop for SocketAncillary<'_> {
+    fn drop(&mut self) {
+        drop(self.take_fds());
+    }
+}
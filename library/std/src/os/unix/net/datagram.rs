@@ -865,6 +865,84 @@ pub fn passcred(&self) -> io::Result<bool> {
         self.0.passcred()
     }
 
+    /// Enables or disables receiving the security context of the sending peer in
+    /// [`SocketAncillary`].
+    ///
+    /// If enabled, received messages carry an [`AncillaryData::ScmSecurity`] control message
+    /// with the peer's label from the active Linux security module, such as SELinux or
+    /// AppArmor. Set the socket option `SO_PASSSEC`.
+    ///
+    /// [`AncillaryData::ScmSecurity`]: super::AncillaryData::ScmSecurity
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::os::unix::net::UnixDatagram;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let sock = UnixDatagram::unbound()?;
+    ///     sock.set_passsec(true).expect("set_passsec function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn set_passsec(&self, passsec: bool) -> io::Result<()> {
+        self.0.set_passsec(passsec)
+    }
+
+    /// Get the current value of the socket for passing the peer's security context in
+    /// [`SocketAncillary`]. This value can be changed by [`set_passsec`].
+    ///
+    /// Get the socket option `SO_PASSSEC`.
+    ///
+    /// [`set_passsec`]: UnixDatagram::set_passsec
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn passsec(&self) -> io::Result<bool> {
+        self.0.passsec()
+    }
+
+    /// Enables or disables receiving the time each datagram arrived in [`SocketAncillary`].
+    ///
+    /// If enabled, received messages carry an [`AncillaryData::ScmTimestamp`] control message.
+    /// Set the socket option `SO_TIMESTAMP`.
+    ///
+    /// [`AncillaryData::ScmTimestamp`]: super::AncillaryData::ScmTimestamp
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::os::unix::net::UnixDatagram;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let sock = UnixDatagram::unbound()?;
+    ///     sock.set_timestamp(true).expect("set_timestamp function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn set_timestamp(&self, timestamp: bool) -> io::Result<()> {
+        self.0.set_timestamp(timestamp)
+    }
+
+    /// Get the current value of the socket for receiving timestamps in [`SocketAncillary`].
+    /// This value can be changed by [`set_timestamp`].
+    ///
+    /// Get the socket option `SO_TIMESTAMP`.
+    ///
+    /// [`set_timestamp`]: UnixDatagram::set_timestamp
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn timestamp(&self) -> io::Result<bool> {
+        self.0.timestamp()
+    }
+
     /// Set the id of the socket for network filtering purpose
     ///
     #[cfg_attr(
@@ -2,7 +2,7 @@
 use super::{recv_vectored_with_ancillary_from, send_vectored_with_ancillary_to, SocketAncillary};
 use super::{sockaddr_un, SocketAddr};
 use crate::fmt;
-use crate::io::{self, IoSlice, IoSliceMut};
+use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut};
 use crate::net::Shutdown;
 use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
 #[cfg(any(
@@ -457,6 +457,74 @@ pub fn passcred(&self) -> io::Result<bool> {
         self.0.passcred()
     }
 
+    /// Enables or disables receiving the security context of the sending peer in
+    /// [`SocketAncillary`].
+    ///
+    /// If enabled, received messages carry an [`AncillaryData::ScmSecurity`] control message
+    /// with the peer's label from the active Linux security module, such as SELinux or
+    /// AppArmor. Set the socket option `SO_PASSSEC`.
+    ///
+    /// [`AncillaryData::ScmSecurity`]: super::AncillaryData::ScmSecurity
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::os::unix::net::UnixStream;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let sock = UnixStream::connect("/tmp/sock")?;
+    ///     sock.set_passsec(true).expect("set_passsec function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn set_passsec(&self, passsec: bool) -> io::Result<()> {
+        self.0.set_passsec(passsec)
+    }
+
+    /// Get the current value of the socket for passing the peer's security context in
+    /// [`SocketAncillary`]. This value can be changed by [`set_passsec`].
+    ///
+    /// Get the socket option `SO_PASSSEC`.
+    ///
+    /// [`set_passsec`]: UnixStream::set_passsec
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn passsec(&self) -> io::Result<bool> {
+        self.0.passsec()
+    }
+
+    /// Returns the security context of the peer of this socket.
+    ///
+    /// This is the label the active Linux security module, such as SELinux or AppArmor,
+    /// assigned to the peer when the connection was established, without a trailing NUL byte.
+    /// Get the socket option `SO_PEERSEC`.
+    ///
+    /// Fails with `ENOPROTOOPT` if no security module providing labels is active.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::os::unix::net::UnixStream;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixStream::connect("/tmp/sock")?;
+    ///     let context = socket.peer_security_context()?;
+    ///     println!("peer runs as {}", String::from_utf8_lossy(&context));
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn peer_security_context(&self) -> io::Result<Vec<u8>> {
+        self.0.peer_sec()
+    }
+
     /// Set the id of the socket for network filtering purpose
     ///
     #[cfg_attr(
@@ -651,10 +719,18 @@ fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         io::Read::read(&mut &*self, buf)
     }
 
+    fn read_buf(&mut self, buf: BorrowedCursor<'_>) -> io::Result<()> {
+        io::Read::read_buf(&mut &*self, buf)
+    }
+
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         io::Read::read_vectored(&mut &*self, bufs)
     }
 
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        io::Read::read_buf_vectored(&mut &*self, cursors)
+    }
+
     #[inline]
     fn is_read_vectored(&self) -> bool {
         io::Read::is_read_vectored(&&*self)
@@ -667,10 +743,18 @@ fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.read(buf)
     }
 
+    fn read_buf(&mut self, buf: BorrowedCursor<'_>) -> io::Result<()> {
+        self.0.read_buf(buf)
+    }
+
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.0.read_vectored(bufs)
     }
 
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        self.0.read_buf_vectored(cursors)
+    }
+
     #[inline]
     fn is_read_vectored(&self) -> bool {
         self.0.is_read_vectored()
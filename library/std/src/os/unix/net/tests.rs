@@ -2,7 +2,7 @@
 use crate::io::prelude::*;
 use crate::io::{self, ErrorKind, IoSlice, IoSliceMut};
 #[cfg(any(target_os = "android", target_os = "linux"))]
-use crate::os::unix::io::AsRawFd;
+use crate::os::unix::io::{AsFd, AsRawFd};
 use crate::sys_common::io::test::tmpdir;
 use crate::thread;
 use crate::time::Duration;
@@ -638,12 +638,12 @@ fn test_send_vectored_fds_unix_stream() {
     if let AncillaryData::ScmRights(scm_rights) = ancillary_data_vec.pop().unwrap().unwrap() {
         let fd_vec = Vec::from_iter(scm_rights);
         assert_eq!(fd_vec.len(), 1);
-        unsafe {
-            libc::close(fd_vec[0]);
-        }
     } else {
         unreachable!("must be ScmRights");
     }
+    // The received file descriptor is owned by `ancillary2`, which would close it if it wasn't
+    // taken.
+    assert_eq!(ancillary2.take_fds().len(), 1);
 }
 
 #[cfg(any(target_os = "android", target_os = "linux", target_os = "freebsd"))]
@@ -751,10 +751,220 @@ fn test_send_vectored_with_ancillary_unix_datagram() {
     if let AncillaryData::ScmRights(scm_rights) = ancillary_data_vec.pop().unwrap().unwrap() {
         let fd_vec = Vec::from_iter(scm_rights);
         assert_eq!(fd_vec.len(), 1);
-        unsafe {
-            libc::close(fd_vec[0]);
-        }
     } else {
         unreachable!("must be ScmRights");
     }
+    // The received file descriptor is owned by `ancillary2`, which would close it if it wasn't
+    // taken.
+    assert_eq!(ancillary2.take_fds().len(), 1);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_send_borrowed_fds_take_owned() {
+    let (s1, s2) = or_panic!(UnixStream::pair());
+    let (passed, mut peer) = or_panic!(UnixStream::pair());
+
+    let mut ancillary1_buffer = [0; 128];
+    let mut ancillary1 = SocketAncillary::new(&mut ancillary1_buffer[..]);
+    assert!(ancillary1.add_borrowed_fds(&[passed.as_fd()][..]));
+    or_panic!(s1.send_vectored_with_ancillary(&[IoSlice::new(b"x")], &mut ancillary1));
+    drop(ancillary1);
+    drop(passed);
+
+    let mut buf = [0; 1];
+    let mut ancillary2_buffer = [0; 128];
+    let mut ancillary2 = SocketAncillary::new(&mut ancillary2_buffer[..]);
+    or_panic!(s2.recv_vectored_with_ancillary(&mut [IoSliceMut::new(&mut buf)], &mut ancillary2));
+    assert_eq!(ancillary2.truncated(), false);
+
+    let mut fds = ancillary2.take_fds();
+    assert_eq!(fds.len(), 1);
+    assert!(ancillary2.take_fds().is_empty());
+    drop(ancillary2);
+
+    // The taken descriptor outlives the ancillary data and still refers to the passed socket.
+    let mut received = UnixStream::from(fds.pop().unwrap());
+    or_panic!(received.write_all(b"hello"));
+    let mut buf = [0; 5];
+    or_panic!(peer.read_exact(&mut buf));
+    assert_eq!(&buf, b"hello");
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_ancillary_only_closes_received_fds() {
+    let (s1, s2) = or_panic!(UnixStream::pair());
+    let (kept, mut peer) = or_panic!(UnixStream::pair());
+
+    let mut ancillary1_buffer = [0; 128];
+    let mut ancillary1 = SocketAncillary::new(&mut ancillary1_buffer[..]);
+    assert!(ancillary1.add_fds(&[s1.as_raw_fd()][..]));
+    or_panic!(s1.send_vectored_with_ancillary(&[IoSlice::new(b"x")], &mut ancillary1));
+
+    let mut buf = [0; 1];
+    let mut ancillary2_buffer = [0; 128];
+    let mut ancillary2 = SocketAncillary::new(&mut ancillary2_buffer[..]);
+    or_panic!(s2.recv_vectored_with_ancillary(&mut [IoSliceMut::new(&mut buf)], &mut ancillary2));
+
+    // Descriptors added after a recv operation stay owned by the caller, whether the ancillary data
+    // is cleared or dropped.
+    assert!(ancillary2.add_fds(&[kept.as_raw_fd()][..]));
+    assert!(ancillary2.add_borrowed_fds(&[kept.as_fd()][..]));
+    assert_eq!(ancillary2.take_fds().len(), 1);
+    ancillary2.clear();
+    assert!(ancillary2.add_fds(&[kept.as_raw_fd()][..]));
+    drop(ancillary2);
+
+    let mut kept = kept;
+    or_panic!(kept.write_all(b"hello"));
+    let mut buf = [0; 5];
+    or_panic!(peer.read_exact(&mut buf));
+    assert_eq!(&buf, b"hello");
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_ancillary_space_for_fds() {
+    let mut buffer = vec![0; SocketAncillary::space_for_fds(2)];
+    let mut ancillary = SocketAncillary::new(&mut buffer[..]);
+    assert!(ancillary.add_fds(&[0, 1][..]));
+    assert_eq!(ancillary.len(), ancillary.capacity());
+    assert!(!ancillary.add_fds(&[2][..]));
+
+    let mut buffer = vec![0; SocketAncillary::space_for_fds(1)];
+    let mut ancillary = SocketAncillary::new(&mut buffer[..]);
+    assert!(!ancillary.add_fds(&[0, 1][..]));
+    assert!(ancillary.is_empty());
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_unix_datagram_timestamp() {
+    let (sock1, sock2) = or_panic!(UnixDatagram::pair());
+    or_panic!(sock2.set_timestamp(true));
+    assert_eq!(or_panic!(sock2.timestamp()), true);
+
+    let before = crate::time::SystemTime::now();
+    or_panic!(sock1.send(b"ping"));
+
+    let mut buf = [0; 4];
+    let mut ancillary_buffer = [0; 128];
+    let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    or_panic!(sock2.recv_vectored_with_ancillary(&mut [IoSliceMut::new(&mut buf)], &mut ancillary));
+
+    let mut timestamps = ancillary.messages().filter_map(|message| match message {
+        Ok(AncillaryData::ScmTimestamp(time)) => Some(time),
+        _ => None,
+    });
+    let time = timestamps.next().expect("no timestamp received");
+    // SO_TIMESTAMP only has microsecond resolution.
+    assert!(time >= before - Duration::from_micros(1));
+    assert!(timestamps.next().is_none());
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_unix_seqpacket_pair_preserves_boundaries() {
+    let (s1, s2) = or_panic!(UnixSeqpacket::pair());
+
+    or_panic!(s1.send(b"hello"));
+    or_panic!(s1.send_vectored(&[IoSlice::new(b"wor"), IoSlice::new(b"ld!")]));
+    or_panic!(s1.send(b""));
+
+    let mut buf = [0; 16];
+    assert_eq!(or_panic!(s2.peek(&mut buf)), (5, false));
+    assert_eq!(or_panic!(s2.recv(&mut buf)), (5, false));
+    assert_eq!(&buf[..5], b"hello");
+    assert_eq!(or_panic!(s2.recv(&mut buf)), (6, false));
+    assert_eq!(&buf[..6], b"world!");
+    assert_eq!(or_panic!(s2.recv(&mut buf)), (0, false));
+
+    // Bytes that do not fit into the buffer are discarded with the message.
+    or_panic!(s1.send(b"truncated"));
+    or_panic!(s1.send(b"next"));
+    let mut small = [0; 3];
+    assert_eq!(or_panic!(s2.peek(&mut small)), (3, true));
+    assert_eq!(or_panic!(s2.recv(&mut small)), (3, true));
+    assert_eq!(&small, b"tru");
+    assert_eq!(or_panic!(s2.recv(&mut buf)), (4, false));
+    assert_eq!(&buf[..4], b"next");
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_unix_seqpacket_listener() {
+    let dir = tmpdir();
+    let socket_path = dir.path().join("sock");
+
+    let listener = or_panic!(UnixSeqpacketListener::bind(&socket_path));
+    assert_eq!(or_panic!(listener.local_addr()).as_pathname(), Some(&*socket_path));
+
+    let thread = thread::spawn(move || {
+        let (conn, _addr) = or_panic!(listener.accept());
+        let mut buf = [0; 16];
+        let (len, _) = or_panic!(conn.recv(&mut buf));
+        or_panic!(conn.send(&buf[..len]));
+    });
+
+    let client = or_panic!(UnixSeqpacket::connect(&socket_path));
+    assert_eq!(or_panic!(client.peer_addr()).as_pathname(), Some(&*socket_path));
+
+    let cred = or_panic!(client.peer_cred());
+    assert_eq!(cred.uid, unsafe { libc::getuid() });
+    assert_eq!(cred.pid, Some(crate::process::id() as libc::pid_t));
+
+    or_panic!(client.set_read_timeout(Some(Duration::from_secs(10))));
+    or_panic!(client.send(b"echo"));
+    let mut buf = [0; 16];
+    assert_eq!(or_panic!(client.recv(&mut buf)), (4, false));
+    assert_eq!(&buf[..4], b"echo");
+
+    thread.join().unwrap();
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_abstract_unix_seqpacket_connect_addr() {
+    let addr = or_panic!(SocketAddr::from_abstract_name(b"seqpacket"));
+    let listener = or_panic!(UnixSeqpacketListener::bind_addr(&addr));
+    assert_eq!(or_panic!(listener.local_addr()).as_abstract_name(), Some(&b"seqpacket"[..]));
+
+    let client = or_panic!(UnixSeqpacket::connect_addr(&addr));
+    let (server, _) = or_panic!(listener.accept());
+    assert_eq!(or_panic!(client.peer_addr()).as_abstract_name(), Some(&b"seqpacket"[..]));
+
+    or_panic!(server.send(b"hi"));
+    let mut buf = [0; 2];
+    assert_eq!(or_panic!(client.recv(&mut buf)), (2, false));
+    assert_eq!(&buf, b"hi");
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_send_vectored_with_ancillary_unix_seqpacket() {
+    let (s1, s2) = or_panic!(UnixSeqpacket::pair());
+    let (passed, mut peer) = or_panic!(UnixStream::pair());
+
+    let mut ancillary1_buffer = [0; 128];
+    let mut ancillary1 = SocketAncillary::new(&mut ancillary1_buffer[..]);
+    assert!(ancillary1.add_borrowed_fds(&[passed.as_fd()][..]));
+    or_panic!(s1.send_vectored_with_ancillary(&[IoSlice::new(b"fd!")], &mut ancillary1));
+
+    let mut buf = [0; 2];
+    let mut ancillary2_buffer = [0; 128];
+    let mut ancillary2 = SocketAncillary::new(&mut ancillary2_buffer[..]);
+    let (size, truncated) = or_panic!(
+        s2.recv_vectored_with_ancillary(&mut [IoSliceMut::new(&mut buf)], &mut ancillary2)
+    );
+    assert_eq!(size, 2);
+    assert!(truncated);
+
+    let mut fds = ancillary2.take_fds();
+    assert_eq!(fds.len(), 1);
+    let mut received = UnixStream::from(fds.pop().unwrap());
+    or_panic!(received.write_all(b"x"));
+    let mut buf = [0; 1];
+    or_panic!(peer.read_exact(&mut buf));
+    assert_eq!(&buf, b"x");
 }
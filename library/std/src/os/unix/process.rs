@@ -187,6 +187,137 @@ fn arg0<S>(&mut self, arg: S) -> &mut process::Command
     /// ```
     #[stable(feature = "process_set_process_group", since = "1.64.0")]
     fn process_group(&mut self, pgroup: i32) -> &mut process::Command;
+
+    /// Runs the child process in a new session, of which it is the leader. Equivalent to a
+    /// `setsid` call in the child process.
+    ///
+    /// The child process is also the leader of a new process group, so this can't be combined
+    /// with [`process_group`]: spawning the child process fails with an
+    /// [`InvalidInput`](io::ErrorKind::InvalidInput) error. The child process has no controlling
+    /// terminal until one is set with [`controlling_terminal`].
+    ///
+    /// [`process_group`]: CommandExt::process_group
+    /// [`controlling_terminal`]: CommandExt::controlling_terminal
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_spawn_attributes)]
+    /// use std::process::Command;
+    /// use std::os::unix::process::CommandExt;
+    ///
+    /// // The daemon keeps running when the terminal of this process is closed.
+    /// Command::new("my-daemon").setsid(true).spawn()?;
+    /// #
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[unstable(feature = "process_spawn_attributes", issue = "none")]
+    fn setsid(&mut self, setsid: bool) -> &mut process::Command;
+
+    /// Makes the terminal which is the standard input of the child process its controlling
+    /// terminal. Equivalent to a `TIOCSCTTY` `ioctl` call in the child process.
+    ///
+    /// This requires the child process to be a session leader without a controlling terminal,
+    /// see [`setsid`], and is typically used to run a process on the slave side of a
+    /// pseudoterminal. Spawning the process fails with [`io::ErrorKind::Unsupported`] on
+    /// platforms without `TIOCSCTTY`.
+    ///
+    /// [`setsid`]: CommandExt::setsid
+    #[unstable(feature = "process_spawn_attributes", issue = "none")]
+    fn controlling_terminal(&mut self, controlling_terminal: bool) -> &mut process::Command;
+
+    /// Sets the signal mask of the child process to `signals`.
+    ///
+    /// By default, the child process inherits the signal mask of the thread spawning it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_spawn_attributes)]
+    /// use std::process::Command;
+    /// use std::os::unix::process::CommandExt;
+    ///
+    /// // Unblock every signal, even if this thread blocked some.
+    /// Command::new("ls").signal_mask(&[]).status()?;
+    /// #
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[unstable(feature = "process_spawn_attributes", issue = "none")]
+    fn signal_mask(&mut self, signals: &[i32]) -> &mut process::Command;
+
+    /// Resets the disposition of `signals` to the default one (`SIG_DFL`) in the child process.
+    /// Can be called several times to reset more signals.
+    ///
+    /// Signals handled by this process are always reset, since the handlers don't exist in the
+    /// new program, but ignored signals stay ignored in the child process unless reset. Unless
+    /// `#[unix_sigpipe]` is used, `SIGPIPE` is always reset.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_spawn_attributes)]
+    /// use std::process::Command;
+    /// use std::os::unix::process::CommandExt;
+    ///
+    /// const SIGINT: i32 = 2;
+    ///
+    /// // Let Ctrl-C interrupt the child process even if this process ignores `SIGINT`.
+    /// Command::new("sleep").arg("10").default_signals(&[SIGINT]).status()?;
+    /// #
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[unstable(feature = "process_spawn_attributes", issue = "none")]
+    fn default_signals(&mut self, signals: &[i32]) -> &mut process::Command;
+
+    /// Sets the `soft` and `hard` limits of `resource` (e.g. `RLIMIT_NOFILE`) for the child
+    /// process. Equivalent to a `setrlimit` call in the child process. A limit of [`u64::MAX`]
+    /// means no limit (`RLIM_INFINITY`).
+    ///
+    /// The limits are set before changing the user and group of the child process, see
+    /// [`uid`](CommandExt::uid), so a privileged process can raise them.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_spawn_attributes)]
+    /// use std::process::Command;
+    /// use std::os::unix::process::CommandExt;
+    ///
+    /// const RLIMIT_CORE: i32 = 4;
+    ///
+    /// // Don't let the child process dump core.
+    /// Command::new("untrusted").rlimit(RLIMIT_CORE, 0, 0).status()?;
+    /// #
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[unstable(feature = "process_spawn_attributes", issue = "none")]
+    fn rlimit(&mut self, resource: i32, soft: u64, hard: u64) -> &mut process::Command;
+
+    /// Closes all the file descriptors except the standard streams and `keep` in the child
+    /// process, including the ones this process didn't open with `O_CLOEXEC`.
+    ///
+    /// The descriptors in `keep` are inherited even if they are close-on-exec in this process.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_spawn_attributes)]
+    /// use std::os::fd::AsRawFd;
+    /// use std::os::unix::process::CommandExt;
+    /// use std::process::Command;
+    ///
+    /// let (reader, writer) = std::os::unix::net::UnixStream::pair()?;
+    /// // Only `writer` and the standard streams are passed to the child process.
+    /// Command::new("worker")
+    ///     .arg(writer.as_raw_fd().to_string())
+    ///     .close_fds_except(&[writer.as_raw_fd()])
+    ///     .spawn()?;
+    /// # drop(reader);
+    /// #
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[unstable(feature = "process_spawn_attributes", issue = "none")]
+    fn close_fds_except(&mut self, keep: &[RawFd]) -> &mut process::Command;
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -232,6 +363,36 @@ fn process_group(&mut self, pgroup: i32) -> &mut process::Command {
         self.as_inner_mut().pgroup(pgroup);
         self
     }
+
+    fn setsid(&mut self, setsid: bool) -> &mut process::Command {
+        self.as_inner_mut().setsid(setsid);
+        self
+    }
+
+    fn controlling_terminal(&mut self, controlling_terminal: bool) -> &mut process::Command {
+        self.as_inner_mut().controlling_terminal(controlling_terminal);
+        self
+    }
+
+    fn signal_mask(&mut self, signals: &[i32]) -> &mut process::Command {
+        self.as_inner_mut().signal_mask(signals);
+        self
+    }
+
+    fn default_signals(&mut self, signals: &[i32]) -> &mut process::Command {
+        self.as_inner_mut().default_signals(signals);
+        self
+    }
+
+    fn rlimit(&mut self, resource: i32, soft: u64, hard: u64) -> &mut process::Command {
+        self.as_inner_mut().rlimit(resource, soft, hard);
+        self
+    }
+
+    fn close_fds_except(&mut self, keep: &[RawFd]) -> &mut process::Command {
+        self.as_inner_mut().close_fds_except(keep);
+        self
+    }
 }
 
 /// Unix-specific extensions to [`process::ExitStatus`] and
@@ -43,11 +43,10 @@ pub struct UCred {
 pub mod impl_linux {
     use super::UCred;
     use crate::os::unix::io::AsRawFd;
-    use crate::os::unix::net::UnixStream;
     use crate::{io, mem};
     use libc::{c_void, getsockopt, socklen_t, ucred, SOL_SOCKET, SO_PEERCRED};
 
-    pub fn peer_cred(socket: &UnixStream) -> io::Result<UCred> {
+    pub fn peer_cred(socket: &impl AsRawFd) -> io::Result<UCred> {
         let ucred_size = mem::size_of::<ucred>();
 
         // Trivial sanity checks.
@@ -86,9 +85,8 @@ pub mod impl_bsd {
     use super::UCred;
     use crate::io;
     use crate::os::unix::io::AsRawFd;
-    use crate::os::unix::net::UnixStream;
 
-    pub fn peer_cred(socket: &UnixStream) -> io::Result<UCred> {
+    pub fn peer_cred(socket: &impl AsRawFd) -> io::Result<UCred> {
         let mut cred = UCred { uid: 1, gid: 1, pid: None };
         unsafe {
             let ret = libc::getpeereid(socket.as_raw_fd(), &mut cred.uid, &mut cred.gid);
@@ -102,11 +100,10 @@ pub fn peer_cred(socket: &UnixStream) -> io::Result<UCred> {
 pub mod impl_mac {
     use super::UCred;
     use crate::os::unix::io::AsRawFd;
-    use crate::os::unix::net::UnixStream;
     use crate::{io, mem};
     use libc::{c_void, getpeereid, getsockopt, pid_t, socklen_t, LOCAL_PEERPID, SOL_LOCAL};
 
-    pub fn peer_cred(socket: &UnixStream) -> io::Result<UCred> {
+    pub fn peer_cred(socket: &impl AsRawFd) -> io::Result<UCred> {
         let mut cred = UCred { uid: 1, gid: 1, pid: None };
         unsafe {
             let ret = getpeereid(socket.as_raw_fd(), &mut cred.uid, &mut cred.gid);
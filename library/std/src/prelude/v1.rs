@@ -83,6 +83,11 @@
 )]
 pub use core::prelude::v1::cfg_eval;
 
+// Do not `doc(no_inline)` either.
+#[cfg(not(bootstrap))]
+#[unstable(feature = "target_clones", issue = "none")]
+pub use core::prelude::v1::target_clones;
+
 // Do not `doc(no_inline)` either.
 #[unstable(
     feature = "type_ascription",
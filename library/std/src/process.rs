@@ -360,6 +360,10 @@ fn read_buf(&mut self, buf: BorrowedCursor<'_>) -> io::Result<()> {
         self.inner.read_buf(buf)
     }
 
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        self.inner.read_buf_vectored(cursors)
+    }
+
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.inner.read_vectored(bufs)
     }
@@ -430,6 +434,10 @@ fn read_buf(&mut self, buf: BorrowedCursor<'_>) -> io::Result<()> {
         self.inner.read_buf(buf)
     }
 
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        self.inner.read_buf_vectored(cursors)
+    }
+
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.inner.read_vectored(bufs)
     }
@@ -151,6 +151,29 @@ fn child_stdout_read_buf() {
     };
 }
 
+#[test]
+#[cfg(unix)]
+fn child_stdout_read_buf_vectored() {
+    let mut cmd = shell_cmd();
+    cmd.arg("-c").arg("echo abc");
+    cmd.stdin(Stdio::null());
+    cmd.stdout(Stdio::piped());
+    let mut child = cmd.spawn().unwrap();
+
+    let mut stdout = child.stdout.take().unwrap();
+    let mut first: [MaybeUninit<u8>; 2] = MaybeUninit::uninit_array();
+    let mut first = BorrowedBuf::from(first.as_mut_slice());
+    let mut second: [MaybeUninit<u8>; 128] = MaybeUninit::uninit_array();
+    let mut second = BorrowedBuf::from(second.as_mut_slice());
+    assert!(child.wait().unwrap().success());
+    stdout.read_buf_vectored(&mut [first.unfilled(), second.unfilled()]).unwrap();
+
+    // ChildStdout::read_buf_vectored should go through readv and omit buffer initialization.
+    assert_eq!(first.filled(), b"ab");
+    assert_eq!(second.filled(), b"c\n");
+    assert_eq!(second.init_len(), 2);
+}
+
 #[test]
 #[cfg_attr(any(target_os = "vxworks"), ignore)]
 fn test_process_status() {
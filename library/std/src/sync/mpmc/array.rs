@@ -409,6 +409,42 @@ pub(crate) fn recv(&self, deadline: Option<Instant>) -> Result<T, RecvTimeoutErr
         }
     }
 
+    /// Returns `true` if a send operation would not block.
+    pub(crate) fn is_ready_to_send(&self) -> bool {
+        !self.is_full() || self.is_disconnected()
+    }
+
+    /// Registers a send operation waiting to become ready.
+    ///
+    /// Returns `true` if the operation is already ready.
+    pub(crate) fn watch_send(&self, oper: Operation, cx: &Context) -> bool {
+        self.senders.watch(oper, cx);
+        self.is_ready_to_send()
+    }
+
+    /// Unregisters a send operation previously registered with `watch_send`.
+    pub(crate) fn unwatch_send(&self, oper: Operation) {
+        self.senders.unwatch(oper);
+    }
+
+    /// Returns `true` if a receive operation would not block.
+    pub(crate) fn is_ready_to_recv(&self) -> bool {
+        !self.is_empty() || self.is_disconnected()
+    }
+
+    /// Registers a receive operation waiting to become ready.
+    ///
+    /// Returns `true` if the operation is already ready.
+    pub(crate) fn watch_recv(&self, oper: Operation, cx: &Context) -> bool {
+        self.receivers.watch(oper, cx);
+        self.is_ready_to_recv()
+    }
+
+    /// Unregisters a receive operation previously registered with `watch_recv`.
+    pub(crate) fn unwatch_recv(&self, oper: Operation) {
+        self.receivers.unwatch(oper);
+    }
+
     /// Returns the current number of messages inside the channel.
     pub(crate) fn len(&self) -> usize {
         loop {
@@ -1,3 +1,12 @@
+use super::context::Context;
+use super::error::{ReadyTimeoutError, TryReadyError};
+use super::utils::{self, Backoff};
+use super::{Receiver, Sender};
+
+use crate::fmt;
+use crate::thread;
+use crate::time::{Duration, Instant};
+
 /// Temporary data that gets initialized during a blocking operation, and is consumed by
 /// `read` or `write`.
 ///
@@ -69,3 +78,319 @@ fn into(self) -> usize {
         }
     }
 }
+
+/// A channel endpoint whose readiness can be waited on by a [`Select`].
+pub(crate) trait SelectHandle {
+    /// Returns `true` if the operation would not block.
+    fn is_ready(&self) -> bool;
+
+    /// Registers an operation waiting to become ready.
+    ///
+    /// Returns `true` if the operation is already ready.
+    fn watch(&self, oper: Operation, cx: &Context) -> bool;
+
+    /// Unregisters an operation previously registered with `watch`.
+    fn unwatch(&self, oper: Operation);
+}
+
+/// How long a readiness check is allowed to block.
+#[derive(Clone, Copy)]
+enum Timeout {
+    /// Don't block at all.
+    Now,
+
+    /// Block until an operation becomes ready.
+    Never,
+
+    /// Block until an operation becomes ready or the deadline is reached.
+    At(Instant),
+}
+
+/// Waits until one of the operations becomes ready and returns its index.
+///
+/// Returns `None` if the timeout elapsed before any operation became ready.
+fn run_ready(handles: &mut [(&dyn SelectHandle, usize)], timeout: Timeout) -> Option<usize> {
+    if handles.is_empty() {
+        // Wait until the timeout and return.
+        match timeout {
+            Timeout::Now => return None,
+            Timeout::Never => panic!("no operations have been added to `Select`"),
+            Timeout::At(when) => {
+                let now = Instant::now();
+                if now < when {
+                    thread::sleep(when - now);
+                }
+                return None;
+            }
+        }
+    }
+
+    // Shuffle the operations for fairness.
+    utils::shuffle(handles);
+
+    loop {
+        let backoff = Backoff::new();
+        loop {
+            // Check operations for readiness.
+            for &(handle, i) in handles.iter() {
+                if handle.is_ready() {
+                    return Some(i);
+                }
+            }
+
+            if backoff.is_completed() {
+                break;
+            } else {
+                backoff.spin_heavy();
+            }
+        }
+
+        // Check for timeout.
+        match timeout {
+            Timeout::Now => return None,
+            Timeout::Never => {}
+            Timeout::At(when) => {
+                if Instant::now() >= when {
+                    return None;
+                }
+            }
+        }
+
+        let res = Context::with(|cx| {
+            let mut sel = Selected::Waiting;
+
+            // Register all operations.
+            for (handle, _) in handles.iter_mut() {
+                let oper = Operation::hook::<&dyn SelectHandle>(handle);
+
+                // If registration returns `true`, the operation has just become ready.
+                if handle.watch(oper, cx) {
+                    sel = match cx.try_select(Selected::Operation(oper)) {
+                        Ok(()) => Selected::Operation(oper),
+                        Err(s) => s,
+                    };
+                    break;
+                }
+
+                // If another thread has already chosen one of the operations, stop registration.
+                sel = cx.selected();
+                if sel != Selected::Waiting {
+                    break;
+                }
+            }
+
+            if sel == Selected::Waiting {
+                let deadline = match timeout {
+                    Timeout::Now => unreachable!(),
+                    Timeout::Never => None,
+                    Timeout::At(when) => Some(when),
+                };
+                sel = cx.wait_until(deadline);
+            }
+
+            // Unwatch all operations.
+            for (handle, _) in handles.iter_mut() {
+                handle.unwatch(Operation::hook::<&dyn SelectHandle>(handle));
+            }
+
+            match sel {
+                Selected::Waiting => unreachable!(),
+                Selected::Aborted | Selected::Disconnected => None,
+                Selected::Operation(_) => handles.iter_mut().find_map(|(handle, i)| {
+                    let oper = Operation::hook::<&dyn SelectHandle>(handle);
+                    (sel == Selected::Operation(oper)).then_some(*i)
+                }),
+            }
+        });
+
+        // If the wait was aborted, go around again so that readiness is checked one last time
+        // before reporting the timeout.
+        if res.is_some() {
+            return res;
+        }
+    }
+}
+
+/// Waits on the readiness of multiple channel operations.
+///
+/// Operations are added with [`send`] and [`recv`], each returning an index that identifies the
+/// operation. Methods like [`ready`] then block until one of the operations becomes ready and
+/// return its index.
+///
+/// An operation is considered ready if it doesn't have to block. Note that it is ready even when
+/// it will simply return an error because the channel is disconnected.
+///
+/// Readiness is only a hint: by the time the selected operation is attempted, another thread may
+/// have already completed it, so it should be performed with a non-blocking method such as
+/// [`Receiver::try_recv`] and retried if it would block. If multiple operations are ready at the
+/// same time, a random one is chosen.
+///
+/// Waiting for a zero-capacity channel to become ready on one side only succeeds if another
+/// thread is blocked in a matching operation on the other side, rather than also waiting on a
+/// `Select`.
+///
+/// [`send`]: Select::send
+/// [`recv`]: Select::recv
+/// [`ready`]: Select::ready
+///
+/// # Examples
+///
+/// ```
+/// #![feature(mpmc_channel)]
+///
+/// use std::sync::mpmc::{self, Select, TryRecvError};
+/// use std::thread;
+///
+/// let (s1, r1) = mpmc::channel();
+/// let (s2, r2) = mpmc::channel();
+///
+/// thread::spawn(move || s1.send(10).unwrap());
+/// thread::spawn(move || s2.send(20).unwrap());
+///
+/// let mut sel = Select::new();
+/// let oper1 = sel.recv(&r1);
+/// let oper2 = sel.recv(&r2);
+///
+/// // Both receivers will eventually be ready; handle whichever comes first.
+/// let msg = loop {
+///     let index = sel.ready();
+///     let res = match index {
+///         i if i == oper1 => r1.try_recv(),
+///         i if i == oper2 => r2.try_recv(),
+///         _ => unreachable!(),
+///     };
+///
+///     // Another thread might have taken the message, so try again.
+///     if let Err(TryRecvError::Empty) = res {
+///         continue;
+///     }
+///     break res.unwrap();
+/// };
+///
+/// assert!(msg == 10 || msg == 20);
+/// ```
+#[unstable(feature = "mpmc_channel", issue = "none")]
+pub struct Select<'a> {
+    /// A list of senders and receivers participating in selection.
+    handles: Vec<(&'a dyn SelectHandle, usize)>,
+
+    /// The next index to assign to an operation.
+    next_index: usize,
+}
+
+impl<'a> Select<'a> {
+    /// Creates an empty list of channel operations.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    #[must_use]
+    pub fn new() -> Select<'a> {
+        Select { handles: Vec::with_capacity(4), next_index: 0 }
+    }
+
+    /// Adds a send operation and returns its index.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn send<T>(&mut self, s: &'a Sender<T>) -> usize {
+        let i = self.next_index;
+        self.handles.push((s, i));
+        self.next_index += 1;
+        i
+    }
+
+    /// Adds a receive operation and returns its index.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn recv<T>(&mut self, r: &'a Receiver<T>) -> usize {
+        let i = self.next_index;
+        self.handles.push((r, i));
+        self.next_index += 1;
+        i
+    }
+
+    /// Removes a previously added operation.
+    ///
+    /// This is useful when an operation is selected because the channel got disconnected and we
+    /// want to try again to select a different operation instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is invalid or the operation has already been removed.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn remove(&mut self, index: usize) {
+        assert!(index < self.next_index, "index out of bounds; {index} >= {}", self.next_index);
+
+        let i = self
+            .handles
+            .iter()
+            .position(|&(_, i)| i == index)
+            .expect("no operation with this index");
+
+        self.handles.swap_remove(i);
+    }
+
+    /// Attempts to find a ready operation without blocking.
+    ///
+    /// If an operation is ready, its index is returned. If no operation is ready, an error is
+    /// returned.
+    ///
+    /// Like with [`Select::ready`], the operation may no longer be ready once it is attempted.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn try_ready(&mut self) -> Result<usize, TryReadyError> {
+        run_ready(&mut self.handles, Timeout::Now).ok_or(TryReadyError)
+    }
+
+    /// Blocks until one of the operations becomes ready and returns its index.
+    ///
+    /// Selecting an operation doesn't perform it or reserve it for the current thread. Between
+    /// the return of this method and the following call to, say, [`Receiver::try_recv`], another
+    /// thread receiving from the same channel can take the message, or another thread sending to
+    /// it can fill its last free slot. The operation then fails with [`TryRecvError::Empty`] or
+    /// [`TrySendError::Full`], and should be selected again, as shown in the [`Select`] example.
+    /// Disconnection, on the other hand, is permanent: once an operation is ready because its
+    /// channel is disconnected, it stays ready.
+    ///
+    /// [`TryRecvError::Empty`]: super::TryRecvError::Empty
+    /// [`TrySendError::Full`]: super::TrySendError::Full
+    ///
+    /// # Panics
+    ///
+    /// Panics if no operations have been added to `Select`.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn ready(&mut self) -> usize {
+        run_ready(&mut self.handles, Timeout::Never).unwrap()
+    }
+
+    /// Blocks for a limited time until one of the operations becomes ready and returns its index.
+    ///
+    /// If no operation becomes ready before the timeout elapses, an error is returned. Like with
+    /// [`Select::ready`], the operation may no longer be ready once it is attempted.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn ready_timeout(&mut self, timeout: Duration) -> Result<usize, ReadyTimeoutError> {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.ready_deadline(deadline),
+            // So far in the future that it's practically the same as waiting indefinitely.
+            None => Ok(self.ready()),
+        }
+    }
+
+    /// Blocks until a given deadline, or until one of the operations becomes ready, and returns
+    /// its index.
+    ///
+    /// If no operation becomes ready before the deadline, an error is returned. Like with
+    /// [`Select::ready`], the operation may no longer be ready once it is attempted.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn ready_deadline(&mut self, deadline: Instant) -> Result<usize, ReadyTimeoutError> {
+        run_ready(&mut self.handles, Timeout::At(deadline)).ok_or(ReadyTimeoutError)
+    }
+}
+
+#[unstable(feature = "mpmc_channel", issue = "none")]
+impl<'a> Default for Select<'a> {
+    fn default() -> Select<'a> {
+        Select::new()
+    }
+}
+
+#[unstable(feature = "mpmc_channel", issue = "none")]
+impl fmt::Debug for Select<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("Select { .. }")
+    }
+}
@@ -0,0 +1,90 @@
+use super::*;
+use crate::thread;
+use crate::time::Duration;
+
+#[test]
+fn smoke_multi_consumer() {
+    let (tx, rx) = channel::<usize>();
+    let receivers: Vec<_> = (0..4)
+        .map(|_| {
+            let rx = rx.clone();
+            thread::spawn(move || {
+                let mut count = 0;
+                while rx.recv().is_ok() {
+                    count += 1;
+                }
+                count
+            })
+        })
+        .collect();
+    drop(rx);
+
+    for i in 0..100 {
+        tx.send(i).unwrap();
+    }
+    drop(tx);
+
+    let total: usize = receivers.into_iter().map(|t| t.join().unwrap()).sum();
+    assert_eq!(total, 100);
+}
+
+#[test]
+fn select_shared_receiver() {
+    let (tx1, rx1) = sync_channel::<i32>(1);
+    let (tx2, rx2) = channel::<i32>();
+
+    // Several threads select over clones of the same receivers; every message
+    // must be taken exactly once.
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let (rx1, rx2) = (rx1.clone(), rx2.clone());
+            thread::spawn(move || {
+                let mut sel = Select::new();
+                let oper1 = sel.recv(&rx1);
+                let oper2 = sel.recv(&rx2);
+                let mut sum = 0;
+                let mut open = 2;
+                while open > 0 {
+                    let i = sel.ready();
+                    let res = if i == oper1 { rx1.try_recv() } else { rx2.try_recv() };
+                    match res {
+                        Ok(n) => sum += n,
+                        Err(TryRecvError::Empty) => {}
+                        Err(TryRecvError::Disconnected) => {
+                            sel.remove(i);
+                            open -= 1;
+                        }
+                    }
+                }
+                sum
+            })
+        })
+        .collect();
+    drop((rx1, rx2));
+
+    for i in 1..=50 {
+        tx1.send(i).unwrap();
+        tx2.send(i).unwrap();
+    }
+    drop((tx1, tx2));
+
+    let total: i32 = workers.into_iter().map(|t| t.join().unwrap()).sum();
+    assert_eq!(total, 2 * (1..=50).sum::<i32>());
+}
+
+#[test]
+fn select_timeout_without_operations() {
+    let mut sel = Select::new();
+    assert_eq!(sel.try_ready(), Err(TryReadyError));
+    assert_eq!(sel.ready_timeout(Duration::from_millis(10)), Err(ReadyTimeoutError));
+}
+
+#[test]
+#[should_panic(expected = "no operation with this index")]
+fn select_remove_twice() {
+    let (_tx, rx) = channel::<i32>();
+    let mut sel = Select::new();
+    let oper = sel.recv(&rx);
+    sel.remove(oper);
+    sel.remove(oper);
+}
@@ -279,6 +279,48 @@ pub(crate) fn recv(&self, deadline: Option<Instant>) -> Result<T, RecvTimeoutErr
         })
     }
 
+    /// Returns `true` if a send operation would not block.
+    pub(crate) fn is_ready_to_send(&self) -> bool {
+        let inner = self.inner.lock().unwrap();
+        inner.receivers.can_select() || inner.is_disconnected
+    }
+
+    /// Registers a send operation waiting to become ready.
+    ///
+    /// Returns `true` if the operation is already ready.
+    pub(crate) fn watch_send(&self, oper: Operation, cx: &Context) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+        inner.senders.watch(oper, cx);
+        inner.receivers.can_select() || inner.is_disconnected
+    }
+
+    /// Unregisters a send operation previously registered with `watch_send`.
+    pub(crate) fn unwatch_send(&self, oper: Operation) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.senders.unwatch(oper);
+    }
+
+    /// Returns `true` if a receive operation would not block.
+    pub(crate) fn is_ready_to_recv(&self) -> bool {
+        let inner = self.inner.lock().unwrap();
+        inner.senders.can_select() || inner.is_disconnected
+    }
+
+    /// Registers a receive operation waiting to become ready.
+    ///
+    /// Returns `true` if the operation is already ready.
+    pub(crate) fn watch_recv(&self, oper: Operation, cx: &Context) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+        inner.receivers.watch(oper, cx);
+        inner.senders.can_select() || inner.is_disconnected
+    }
+
+    /// Unregisters a receive operation previously registered with `watch_recv`.
+    pub(crate) fn unwatch_recv(&self, oper: Operation) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.receivers.unwatch(oper);
+    }
+
     /// Disconnects the channel and wakes up all blocked senders and receivers.
     ///
     /// Returns `true` if this call disconnected the channel.
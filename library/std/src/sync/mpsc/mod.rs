@@ -144,15 +144,17 @@
 mod sync_tests;
 
 // MPSC channels are built as a wrapper around MPMC channels, which
-// were ported from the `crossbeam-channel` crate. MPMC channels are
-// not exposed publicly, but if you are curious about the implementation,
-// that's where everything is.
+// were ported from the `crossbeam-channel` crate. If you are curious
+// about the implementation, that's where everything is.
 
 use crate::error;
 use crate::fmt;
 use crate::sync::mpmc;
 use crate::time::{Duration, Instant};
 
+#[unstable(feature = "mpmc_channel", issue = "none")]
+pub use crate::sync::mpmc::{ReadyTimeoutError, TryReadyError};
+
 /// The receiving half of Rust's [`channel`] (or [`sync_channel`]) type.
 /// This half can only be owned by one thread.
 ///
@@ -939,7 +941,6 @@ pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
     /// Successfully receiving value before reaching deadline:
     ///
     /// ```no_run
-    /// #![feature(deadline_api)]
     /// use std::thread;
     /// use std::time::{Duration, Instant};
     /// use std::sync::mpsc;
@@ -959,7 +960,6 @@ pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
     /// Receiving an error upon reaching deadline:
     ///
     /// ```no_run
-    /// #![feature(deadline_api)]
     /// use std::thread;
     /// use std::time::{Duration, Instant};
     /// use std::sync::mpsc;
@@ -976,7 +976,7 @@ pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
     ///     Err(mpsc::RecvTimeoutError::Timeout)
     /// );
     /// ```
-    #[unstable(feature = "deadline_api", issue = "46316")]
+    #[stable(feature = "deadline_api", since = "CURRENT_RUSTC_VERSION")]
     pub fn recv_deadline(&self, deadline: Instant) -> Result<T, RecvTimeoutError> {
         self.inner.recv_deadline(deadline)
     }
@@ -1109,6 +1109,163 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// Waits on the readiness of operations on multiple channels.
+///
+/// Operations are added with [`recv`], [`send`] and [`sync_send`], each
+/// returning an index that identifies the operation. Methods like [`ready`]
+/// then block until one of the operations becomes ready and return its index.
+///
+/// An operation is considered ready if it doesn't have to block, including
+/// when it would immediately fail because the channel is disconnected. If
+/// several operations are ready at the same time, a random one is chosen.
+///
+/// Readiness is only a hint: by the time the selected operation is attempted,
+/// another thread may have already made it block again (e.g. by filling up a
+/// bounded channel), so it should be performed with a non-blocking method such
+/// as [`Receiver::try_recv`] or [`SyncSender::try_send`].
+///
+/// [`recv`]: Select::recv
+/// [`send`]: Select::send
+/// [`sync_send`]: Select::sync_send
+/// [`ready`]: Select::ready
+///
+/// # Examples
+///
+/// ```
+/// #![feature(mpmc_channel)]
+///
+/// use std::sync::mpsc::{channel, Select};
+/// use std::thread;
+/// use std::time::Duration;
+///
+/// let (numbers_tx, numbers) = channel();
+/// let (words_tx, words) = channel();
+///
+/// thread::spawn(move || numbers_tx.send(1).unwrap());
+/// thread::spawn(move || words_tx.send("one").unwrap());
+///
+/// let mut sel = Select::new();
+/// let numbers_op = sel.recv(&numbers);
+/// let words_op = sel.recv(&words);
+///
+/// for _ in 0..2 {
+///     match sel.ready_timeout(Duration::from_secs(10)) {
+///         Ok(i) if i == numbers_op => {
+///             assert_eq!(numbers.recv(), Ok(1));
+///             sel.remove(numbers_op);
+///         }
+///         Ok(i) if i == words_op => {
+///             assert_eq!(words.recv(), Ok("one"));
+///             sel.remove(words_op);
+///         }
+///         Ok(_) => unreachable!(),
+///         Err(_) => panic!("no message arrived in time"),
+///     }
+/// }
+/// ```
+#[unstable(feature = "mpmc_channel", issue = "none")]
+pub struct Select<'a> {
+    inner: mpmc::Select<'a>,
+}
+
+impl<'a> Select<'a> {
+    /// Creates an empty list of channel operations.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    #[must_use]
+    pub fn new() -> Select<'a> {
+        Select { inner: mpmc::Select::new() }
+    }
+
+    /// Adds a receive operation on `rx` and returns its index.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn recv<T>(&mut self, rx: &'a Receiver<T>) -> usize {
+        self.inner.recv(&rx.inner)
+    }
+
+    /// Adds a send operation on `tx` and returns its index.
+    ///
+    /// Sends on an asynchronous channel never block, so this operation is
+    /// always ready.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn send<T>(&mut self, tx: &'a Sender<T>) -> usize {
+        self.inner.send(&tx.inner)
+    }
+
+    /// Adds a send operation on the synchronous channel `tx` and returns its
+    /// index.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn sync_send<T>(&mut self, tx: &'a SyncSender<T>) -> usize {
+        self.inner.send(&tx.inner)
+    }
+
+    /// Removes a previously added operation.
+    ///
+    /// This is useful once a channel has been drained or disconnected and
+    /// should no longer wake up the selection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is invalid or the operation has already been
+    /// removed.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn remove(&mut self, index: usize) {
+        self.inner.remove(index)
+    }
+
+    /// Attempts to find a ready operation without blocking.
+    ///
+    /// Returns the index of a ready operation, or an error if none of them is
+    /// ready.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn try_ready(&mut self) -> Result<usize, TryReadyError> {
+        self.inner.try_ready()
+    }
+
+    /// Blocks until one of the operations becomes ready and returns its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no operations have been added.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn ready(&mut self) -> usize {
+        self.inner.ready()
+    }
+
+    /// Blocks until one of the operations becomes ready, but only for a
+    /// limited time.
+    ///
+    /// Returns the index of the ready operation, or an error if the timeout
+    /// elapsed first.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn ready_timeout(&mut self, timeout: Duration) -> Result<usize, ReadyTimeoutError> {
+        self.inner.ready_timeout(timeout)
+    }
+
+    /// Blocks until one of the operations becomes ready, but only until a
+    /// given deadline.
+    ///
+    /// Returns the index of the ready operation, or an error if the deadline
+    /// was reached first.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn ready_deadline(&mut self, deadline: Instant) -> Result<usize, ReadyTimeoutError> {
+        self.inner.ready_deadline(deadline)
+    }
+}
+
+#[unstable(feature = "mpmc_channel", issue = "none")]
+impl<'a> Default for Select<'a> {
+    fn default() -> Select<'a> {
+        Select::new()
+    }
+}
+
+#[unstable(feature = "mpmc_channel", issue = "none")]
+impl fmt::Debug for Select<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Select").finish_non_exhaustive()
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T> fmt::Debug for SendError<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -669,3 +669,41 @@ fn drop_unreceived() {
     assert!(weak.upgrade().is_none());
     drop(tx);
 }
+
+#[test]
+fn select_sync_send() {
+    let (tx, rx) = sync_channel::<i32>(1);
+    tx.send(1).unwrap();
+
+    let mut sel = Select::new();
+    let oper = sel.sync_send(&tx);
+    assert_eq!(sel.try_ready(), Err(TryReadyError));
+
+    let t = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(rx.recv(), Ok(1));
+        rx
+    });
+
+    assert_eq!(sel.ready(), oper);
+    tx.try_send(2).unwrap();
+    let rx = t.join().unwrap();
+    assert_eq!(rx.recv(), Ok(2));
+}
+
+#[test]
+fn select_rendezvous() {
+    let (tx, rx) = sync_channel::<i32>(0);
+    let mut sel = Select::new();
+    let oper = sel.recv(&rx);
+    assert_eq!(sel.try_ready(), Err(TryReadyError));
+
+    // A zero-capacity channel is ready to receive once a sender is blocked on it.
+    let t = thread::spawn(move || tx.send(7).unwrap());
+    assert_eq!(sel.ready(), oper);
+    assert_eq!(rx.try_recv(), Ok(7));
+    t.join().unwrap();
+
+    assert_eq!(sel.ready(), oper);
+    assert_eq!(rx.try_recv(), Err(TryRecvError::Disconnected));
+}
@@ -721,3 +721,58 @@ fn issue_39364() {
     let _tx = t.join().unwrap(); // delay dropping until end of test
     let _ = rx.recv_timeout(Duration::from_millis(500));
 }
+
+#[test]
+fn select_ready_recv() {
+    let (tx1, rx1) = channel::<i32>();
+    let (tx2, rx2) = channel::<i32>();
+    let mut sel = Select::new();
+    let oper1 = sel.recv(&rx1);
+    let oper2 = sel.recv(&rx2);
+
+    assert_eq!(sel.try_ready(), Err(TryReadyError));
+
+    let t = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        tx2.send(2).unwrap();
+        tx1
+    });
+
+    assert_eq!(sel.ready(), oper2);
+    assert_eq!(rx2.try_recv(), Ok(2));
+
+    // `tx2` was dropped by the thread, so its receiver stays ready.
+    assert_eq!(sel.ready(), oper2);
+    assert_eq!(rx2.try_recv(), Err(TryRecvError::Disconnected));
+    sel.remove(oper2);
+
+    let tx1 = t.join().unwrap();
+    tx1.send(1).unwrap();
+    assert_eq!(sel.try_ready(), Ok(oper1));
+    assert_eq!(rx1.try_recv(), Ok(1));
+}
+
+#[test]
+fn select_ready_timeout() {
+    let (_tx, rx) = channel::<i32>();
+    let mut sel = Select::new();
+    sel.recv(&rx);
+
+    let start = Instant::now();
+    assert_eq!(sel.ready_timeout(Duration::from_millis(100)), Err(ReadyTimeoutError));
+    assert!(start.elapsed() >= Duration::from_millis(100));
+
+    let mut empty = Select::new();
+    assert_eq!(empty.try_ready(), Err(TryReadyError));
+    assert_eq!(empty.ready_deadline(Instant::now()), Err(ReadyTimeoutError));
+}
+
+#[test]
+fn select_send_always_ready() {
+    let (tx, _rx) = channel::<i32>();
+    let (_tx2, rx2) = channel::<i32>();
+    let mut sel = Select::new();
+    sel.recv(&rx2);
+    let oper = sel.send(&tx);
+    assert_eq!(sel.ready(), oper);
+}
@@ -1,7 +1,8 @@
 use crate::sync::atomic::{AtomicUsize, Ordering};
 use crate::sync::mpsc::channel;
-use crate::sync::{Arc, Condvar, Mutex};
+use crate::sync::{Arc, Condvar, MappedMutexGuard, Mutex, MutexGuard, TryLockError};
 use crate::thread;
+use crate::time::{Duration, Instant};
 
 struct Packet<T>(Arc<(Mutex<T>, Condvar)>);
 
@@ -236,3 +237,78 @@ fn test_mutex_unsized() {
     let comp: &[i32] = &[4, 2, 5];
     assert_eq!(&*mutex.lock().unwrap(), comp);
 }
+
+#[test]
+fn test_try_lock_for() {
+    let m = Arc::new(Mutex::new(1));
+    let guard = m.lock().unwrap();
+
+    let m2 = m.clone();
+    thread::spawn(move || {
+        let start = Instant::now();
+        let timeout = Duration::from_millis(20);
+        assert!(matches!(m2.try_lock_for(timeout), Err(TryLockError::WouldBlock)));
+        assert!(start.elapsed() >= timeout);
+    })
+    .join()
+    .unwrap();
+
+    let m2 = m.clone();
+    let (tx, rx) = channel();
+    let waiter = thread::spawn(move || {
+        tx.send(()).unwrap();
+        *m2.try_lock_for(Duration::from_secs(60)).unwrap() += 1;
+    });
+    rx.recv().unwrap();
+    drop(guard);
+    waiter.join().unwrap();
+    assert_eq!(*m.lock().unwrap(), 2);
+
+    let deadline = Instant::now() - Duration::from_millis(1);
+    assert_eq!(*m.try_lock_until(deadline).unwrap(), 2);
+}
+
+#[test]
+fn test_try_lock_for_poison() {
+    let m = Arc::new(Mutex::new(1));
+    let m2 = m.clone();
+    let _ = thread::spawn(move || {
+        let _lock = m2.lock().unwrap();
+        panic!("test panic in inner thread to poison mutex");
+    })
+    .join();
+
+    assert!(matches!(m.try_lock_for(Duration::from_millis(10)), Err(TryLockError::Poisoned(_))));
+}
+
+#[test]
+fn test_mapped_guard() {
+    let m = Mutex::new((1, NonCopy(2)));
+
+    let mut second = MutexGuard::map(m.lock().unwrap(), |data| &mut data.1);
+    assert!(m.try_lock().is_err());
+    second.0 = 3;
+    let mut inner = MappedMutexGuard::map(second, |data| &mut data.0);
+    *inner += 1;
+    drop(inner);
+    assert_eq!(m.lock().unwrap().1, NonCopy(4));
+
+    let guard = MutexGuard::filter_map(m.lock().unwrap(), |_| None::<&mut i32>).unwrap_err();
+    assert_eq!(guard.0, 1);
+    let first = MutexGuard::filter_map(guard, |data| Some(&mut data.0)).unwrap();
+    assert_eq!(*first, 1);
+}
+
+#[test]
+fn test_mapped_guard_poison() {
+    let m = Arc::new(Mutex::new((1, 2)));
+    let m2 = m.clone();
+    let _ = thread::spawn(move || {
+        let _second = MutexGuard::map(m2.lock().unwrap(), |data| &mut data.1);
+        panic!("test panic in inner thread to poison mutex");
+    })
+    .join();
+
+    assert!(m.is_poisoned());
+    assert!(m.try_lock().is_err());
+}
@@ -55,6 +55,7 @@ pub fn clear(&self) {
     }
 }
 
+#[derive(Clone)]
 pub struct Guard {
     panicking: bool,
 }
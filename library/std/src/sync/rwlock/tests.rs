@@ -1,7 +1,11 @@
 use crate::sync::atomic::{AtomicUsize, Ordering};
 use crate::sync::mpsc::channel;
-use crate::sync::{Arc, RwLock, RwLockReadGuard, TryLockError};
+use crate::sync::{
+    Arc, MappedRwLockReadGuard, MappedRwLockWriteGuard, RwLock, RwLockPolicy, RwLockReadGuard,
+    RwLockUpgradableReadGuard, RwLockWriteGuard, TryLockError,
+};
 use crate::thread;
+use crate::time::{Duration, Instant};
 use rand::Rng;
 
 #[derive(Eq, PartialEq, Debug)]
@@ -257,3 +261,214 @@ fn do_stuff<'a>(_: RwLockReadGuard<'_, &'a i32>, _: &'a i32) {}
     }
     drop(lock);
 }
+
+#[test]
+fn test_try_read_write_for() {
+    let lock = Arc::new(RwLock::new(1));
+    let timeout = Duration::from_millis(20);
+
+    let w = lock.write().unwrap();
+    let lock2 = lock.clone();
+    thread::spawn(move || {
+        let start = Instant::now();
+        assert!(matches!(lock2.try_read_for(timeout), Err(TryLockError::WouldBlock)));
+        assert!(matches!(lock2.try_write_for(timeout), Err(TryLockError::WouldBlock)));
+        assert!(start.elapsed() >= timeout * 2);
+    })
+    .join()
+    .unwrap();
+    drop(w);
+
+    let r = lock.read().unwrap();
+    let lock2 = lock.clone();
+    thread::spawn(move || {
+        assert_eq!(*lock2.try_read_for(timeout).unwrap(), 1);
+        assert!(matches!(lock2.try_write_for(timeout), Err(TryLockError::WouldBlock)));
+    })
+    .join()
+    .unwrap();
+
+    let lock2 = lock.clone();
+    let (tx, rx) = channel();
+    let writer = thread::spawn(move || {
+        tx.send(()).unwrap();
+        *lock2.try_write_for(Duration::from_secs(60)).unwrap() = 2;
+    });
+    rx.recv().unwrap();
+    drop(r);
+    writer.join().unwrap();
+
+    let deadline = Instant::now() - Duration::from_millis(1);
+    assert_eq!(*lock.try_read_until(deadline).unwrap(), 2);
+    drop(lock.try_write_until(deadline).unwrap());
+}
+
+#[test]
+fn test_upgradable_read() {
+    let lock = RwLock::new(1);
+
+    let u = lock.upgradable_read().unwrap();
+    assert_eq!(*u, 1);
+    assert!(matches!(lock.try_write(), Err(TryLockError::WouldBlock)));
+    assert!(matches!(lock.try_upgradable_read(), Err(TryLockError::WouldBlock)));
+
+    let mut w = RwLockUpgradableReadGuard::upgrade(u);
+    *w = 2;
+    assert!(matches!(lock.try_read(), Err(TryLockError::WouldBlock)));
+    drop(w);
+
+    assert_eq!(*lock.try_upgradable_read().unwrap(), 2);
+    drop(lock.write().unwrap());
+}
+
+#[test]
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "dragonfly",
+))]
+fn test_upgradable_read_with_readers() {
+    let lock = Arc::new(RwLock::new(0));
+    let r = lock.read().unwrap();
+
+    // An upgradable reader shares the lock with plain readers...
+    let u = lock.try_upgradable_read().unwrap();
+    drop(lock.try_read().unwrap());
+
+    // ...and waits for them to leave when upgrading.
+    let lock2 = lock.clone();
+    let (tx, rx) = channel();
+    let reader = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        tx.send(()).unwrap();
+        drop(r);
+        // New readers have to wait for the upgraded writer.
+        assert_eq!(*lock2.read().unwrap(), 1);
+    });
+
+    let mut w = RwLockUpgradableReadGuard::upgrade(u);
+    rx.try_recv().unwrap();
+    *w += 1;
+    drop(w);
+    reader.join().unwrap();
+}
+
+#[test]
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "dragonfly",
+))]
+fn test_prefer_readers() {
+    let lock = Arc::new(RwLock::with_policy(0, RwLockPolicy::PreferReaders));
+    let r = lock.read().unwrap();
+
+    let lock2 = lock.clone();
+    let writer = thread::spawn(move || *lock2.write().unwrap() += 1);
+    thread::sleep(Duration::from_millis(20));
+
+    // The waiting writer doesn't keep new readers out.
+    assert_eq!(*lock.try_read().unwrap(), 0);
+    assert_eq!(*lock.try_read_for(Duration::from_millis(10)).unwrap(), 0);
+    drop(r);
+    writer.join().unwrap();
+    assert_eq!(*lock.read().unwrap(), 1);
+}
+
+#[test]
+fn test_policy_contention() {
+    const N: usize = 10;
+    const M: usize = 100;
+
+    for policy in [RwLockPolicy::PreferWriters, RwLockPolicy::PreferReaders] {
+        let lock = Arc::new(RwLock::with_policy(0, policy));
+        let mut threads = Vec::new();
+        for i in 0..N {
+            let lock = lock.clone();
+            threads.push(thread::spawn(move || {
+                for _ in 0..M {
+                    if i % 2 == 0 {
+                        *lock.write().unwrap() += 1;
+                    } else {
+                        drop(lock.read().unwrap());
+                    }
+                }
+            }));
+        }
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert_eq!(*lock.read().unwrap(), N / 2 * M);
+    }
+}
+
+#[test]
+fn test_upgradable_read_contention() {
+    const N: usize = 10;
+    const M: usize = 100;
+
+    let lock = Arc::new(RwLock::new(0));
+    let mut threads = Vec::new();
+    for i in 0..N {
+        let lock = lock.clone();
+        threads.push(thread::spawn(move || {
+            for _ in 0..M {
+                if i % 2 == 0 {
+                    let u = lock.upgradable_read().unwrap();
+                    let value = *u;
+                    let mut w = RwLockUpgradableReadGuard::upgrade(u);
+                    // No other writer can get in between reading and upgrading.
+                    assert_eq!(*w, value);
+                    *w += 1;
+                } else {
+                    drop(lock.read().unwrap());
+                }
+            }
+        }));
+    }
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert_eq!(*lock.read().unwrap(), N / 2 * M);
+}
+
+#[test]
+fn test_mapped_read_guard() {
+    let lock = RwLock::new((1, NonCopy(2)));
+
+    let second = RwLockReadGuard::map(lock.read().unwrap(), |data| &data.1);
+    assert!(lock.try_write().is_err());
+    let inner = MappedRwLockReadGuard::map(second, |data| &data.0);
+    assert_eq!(*inner, 2);
+    drop(inner);
+
+    let guard = RwLockReadGuard::filter_map(lock.read().unwrap(), |_| None::<&i32>).unwrap_err();
+    assert_eq!(guard.0, 1);
+    drop(guard);
+    drop(lock.try_write().unwrap());
+}
+
+#[test]
+fn test_mapped_write_guard() {
+    let lock = Arc::new(RwLock::new((1, NonCopy(2))));
+
+    let mut second = RwLockWriteGuard::map(lock.write().unwrap(), |data| &mut data.1);
+    assert!(lock.try_read().is_err());
+    second.0 = 3;
+    let mut inner = MappedRwLockWriteGuard::map(second, |data| &mut data.0);
+    *inner += 1;
+    drop(inner);
+    assert_eq!(lock.read().unwrap().1, NonCopy(4));
+
+    let lock2 = lock.clone();
+    let _ = thread::spawn(move || {
+        let _first = RwLockWriteGuard::map(lock2.write().unwrap(), |data| &mut data.0);
+        panic!("test panic in inner thread to poison RwLock");
+    })
+    .join();
+    assert!(lock.is_poisoned());
+}
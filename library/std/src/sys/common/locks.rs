@@ -0,0 +1,50 @@
+//! Fallbacks for lock operations that not every platform supports natively.
+
+use crate::thread;
+use crate::time::{Duration, Instant};
+
+/// Repeatedly calls `try_lock` until it succeeds or `timeout` has elapsed.
+///
+/// This is used to implement timed locking on top of lock implementations
+/// that can only block indefinitely. It spins briefly before falling back to
+/// yielding and finally sleeping, so a long timeout on a contended lock does
+/// not burn a whole core.
+///
+/// Unlike a blocked thread, a polling thread is not queued by the lock, so it
+/// can be starved by threads that take the lock in between its attempts. The
+/// public timed locking functions document this.
+pub fn lock_timeout(timeout: Duration, mut try_lock: impl FnMut() -> bool) -> bool {
+    if try_lock() {
+        return true;
+    }
+
+    // Overflows are rounded up to an infinite timeout (None).
+    let deadline = Instant::now().checked_add(timeout);
+    let mut attempt = 0u32;
+    loop {
+        if attempt < 6 {
+            for _ in 0..(1 << attempt) {
+                crate::hint::spin_loop();
+            }
+        } else if attempt < 16 {
+            thread::yield_now();
+        } else {
+            let nap = Duration::from_micros(100);
+            match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(remaining) => thread::sleep(remaining.min(nap)),
+                    None => return try_lock(),
+                },
+                None => thread::sleep(nap),
+            }
+        }
+        attempt = attempt.saturating_add(1);
+
+        if try_lock() {
+            return true;
+        }
+        if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            return false;
+        }
+    }
+}
@@ -11,6 +11,7 @@
 #![allow(dead_code)]
 
 pub mod alloc;
+pub mod locks;
 pub mod small_c_string;
 pub mod thread_local;
 
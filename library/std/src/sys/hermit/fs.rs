@@ -322,6 +322,10 @@ pub fn read_buf(&self, cursor: BorrowedCursor<'_>) -> io::Result<()> {
         crate::io::default_read_buf(|buf| self.read(buf), cursor)
     }
 
+    pub fn read_buf_vectored(&self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        crate::io::default_read_buf_vectored(|cursor| self.read_buf(cursor), cursors)
+    }
+
     pub fn write(&self, buf: &[u8]) -> io::Result<usize> {
         self.0.write(buf)
     }
@@ -351,6 +355,26 @@ pub fn set_permissions(&self, _perm: FilePermissions) -> io::Result<()> {
         Err(Error::from_raw_os_error(22))
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        Err(Error::from_raw_os_error(22))
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        Err(Error::from_raw_os_error(22))
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        Err(Error::from_raw_os_error(22))
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        Err(Error::from_raw_os_error(22))
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        Err(Error::from_raw_os_error(22))
+    }
+
     pub fn set_times(&self, _times: FileTimes) -> io::Result<()> {
         Err(Error::from_raw_os_error(22))
     }
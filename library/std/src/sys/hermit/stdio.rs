@@ -17,6 +17,10 @@ fn read(&mut self, data: &mut [u8]) -> io::Result<usize> {
         self.read_vectored(&mut [IoSliceMut::new(data)])
     }
 
+    fn read_buf(&mut self, _buf: io::BorrowedCursor<'_>) -> io::Result<()> {
+        Ok(())
+    }
+
     fn read_vectored(&mut self, _data: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         Ok(0)
     }
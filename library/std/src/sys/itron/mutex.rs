@@ -5,6 +5,8 @@
     error::{expect_success, expect_success_aborting, fail, ItronError},
     spin::SpinIdOnceCell,
 };
+use crate::sys::common::locks;
+use crate::time::Duration;
 
 pub struct Mutex {
     /// The ID of the underlying mutex object
@@ -57,6 +59,10 @@ pub fn try_lock(&self) -> bool {
             }
         }
     }
+
+    pub fn lock_timeout(&self, timeout: Duration) -> bool {
+        locks::lock_timeout(timeout, || self.try_lock())
+    }
 }
 
 impl Drop for Mutex {
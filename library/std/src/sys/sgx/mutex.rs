@@ -1,5 +1,7 @@
 use super::waitqueue::{try_lock_or_false, SpinMutex, WaitQueue, WaitVariable};
+use crate::sys::common::locks;
 use crate::sys_common::lazy_box::{LazyBox, LazyInit};
+use crate::time::Duration;
 
 /// FIXME: `UnsafeList` is not movable.
 struct AllocatedMutex(SpinMutex<WaitVariable<bool>>);
@@ -56,4 +58,8 @@ pub fn try_lock(&self) -> bool {
             true
         }
     }
+
+    pub fn lock_timeout(&self, timeout: Duration) -> bool {
+        locks::lock_timeout(timeout, || self.try_lock())
+    }
 }
@@ -149,6 +149,10 @@ pub fn read_buf(&self, buf: BorrowedCursor<'_>) -> io::Result<()> {
         self.inner.inner.read_buf(buf)
     }
 
+    pub fn read_buf_vectored(&self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        self.inner.inner.read_buf_vectored(cursors)
+    }
+
     pub fn read_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.inner.inner.read_vectored(bufs)
     }
@@ -203,6 +207,46 @@ pub fn nodelay(&self) -> io::Result<bool> {
         sgx_ineffective(false)
     }
 
+    pub fn set_keepalive(&self, _: Option<Duration>) -> io::Result<()> {
+        sgx_ineffective(())
+    }
+
+    pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+        sgx_ineffective(None)
+    }
+
+    pub fn set_keepalive_interval(&self, _: Duration) -> io::Result<()> {
+        sgx_ineffective(())
+    }
+
+    pub fn keepalive_interval(&self) -> io::Result<Duration> {
+        unsupported()
+    }
+
+    pub fn set_keepalive_retries(&self, _: u32) -> io::Result<()> {
+        sgx_ineffective(())
+    }
+
+    pub fn keepalive_retries(&self) -> io::Result<u32> {
+        unsupported()
+    }
+
+    pub fn set_send_buffer_size(&self, _: usize) -> io::Result<()> {
+        sgx_ineffective(())
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        unsupported()
+    }
+
+    pub fn set_recv_buffer_size(&self, _: usize) -> io::Result<()> {
+        sgx_ineffective(())
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        unsupported()
+    }
+
     pub fn set_ttl(&self, _: u32) -> io::Result<()> {
         sgx_ineffective(())
     }
@@ -324,6 +368,60 @@ fn from_inner(inner: Socket) -> TcpListener {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct TcpBuilder(());
+
+impl TcpBuilder {
+    pub fn new() -> TcpBuilder {
+        TcpBuilder(())
+    }
+
+    pub fn reuse_address(&mut self, _: bool) {}
+
+    pub fn reuse_port(&mut self, _: bool) {}
+
+    pub fn only_v6(&mut self, _: bool) {}
+
+    pub fn send_buffer_size(&mut self, _: usize) {}
+
+    pub fn recv_buffer_size(&mut self, _: usize) {}
+
+    pub fn backlog(&mut self, _: u32) {}
+
+    pub fn bind(&mut self, _: SocketAddr) {}
+
+    pub fn listen(&self, addr: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
+        TcpListener::bind(addr)
+    }
+
+    pub fn connect(&self, addr: io::Result<&SocketAddr>) -> io::Result<TcpStream> {
+        TcpStream::connect(addr)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct UdpBuilder(());
+
+impl UdpBuilder {
+    pub fn new() -> UdpBuilder {
+        UdpBuilder(())
+    }
+
+    pub fn reuse_address(&mut self, _: bool) {}
+
+    pub fn reuse_port(&mut self, _: bool) {}
+
+    pub fn only_v6(&mut self, _: bool) {}
+
+    pub fn send_buffer_size(&mut self, _: usize) {}
+
+    pub fn recv_buffer_size(&mut self, _: usize) {}
+
+    pub fn bind(&self, addr: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
+        UdpSocket::bind(addr)
+    }
+}
+
 pub struct UdpSocket(!);
 
 impl UdpSocket {
@@ -379,6 +477,22 @@ pub fn broadcast(&self) -> io::Result<bool> {
         self.0
     }
 
+    pub fn set_send_buffer_size(&self, _: usize) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.0
+    }
+
+    pub fn set_recv_buffer_size(&self, _: usize) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.0
+    }
+
     pub fn set_multicast_loop_v4(&self, _: bool) -> io::Result<()> {
         self.0
     }
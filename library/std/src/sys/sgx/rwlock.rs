@@ -2,7 +2,10 @@
 mod tests;
 
 use crate::num::NonZeroUsize;
+use crate::sync::RwLockPolicy;
+use crate::sys::common::locks;
 use crate::sys_common::lazy_box::{LazyBox, LazyInit};
+use crate::time::Duration;
 
 use super::waitqueue::{
     try_lock_or_false, NotifiedTcs, SpinMutex, SpinMutexGuard, WaitQueue, WaitVariable,
@@ -41,6 +44,12 @@ pub const fn new() -> RwLock {
         RwLock { inner: LazyBox::new() }
     }
 
+    // The size of `RwLock` is fixed by libunwind (see above), so there is no
+    // room to store a policy and the lock keeps its own.
+    pub const fn with_policy(_policy: RwLockPolicy) -> RwLock {
+        RwLock::new()
+    }
+
     #[inline]
     pub fn read(&self) {
         let lock = &*self.inner;
@@ -171,6 +180,34 @@ pub unsafe fn write_unlock(&self) {
         unsafe { self.__write_unlock(rguard, wguard) };
     }
 
+    pub fn read_timeout(&self, timeout: Duration) -> bool {
+        locks::lock_timeout(timeout, || unsafe { self.try_read() })
+    }
+
+    pub fn write_timeout(&self, timeout: Duration) -> bool {
+        locks::lock_timeout(timeout, || self.try_write())
+    }
+
+    // The wait queues cannot atomically turn a read lock into a write lock,
+    // so an upgradable read lock is an exclusive lock that is already upgraded.
+    #[inline]
+    pub fn upgradable_read(&self) {
+        self.write()
+    }
+
+    #[inline]
+    pub fn try_upgradable_read(&self) -> bool {
+        self.try_write()
+    }
+
+    #[inline]
+    pub unsafe fn upgradable_read_unlock(&self) {
+        unsafe { self.write_unlock() }
+    }
+
+    #[inline]
+    pub unsafe fn upgrade(&self) {}
+
     // only used by __rust_rwlock_unlock below
     #[inline]
     #[cfg_attr(test, allow(dead_code))]
@@ -6,6 +6,9 @@
         spin::SpinIdOnceCell,
     },
 };
+use crate::sync::RwLockPolicy;
+use crate::sys::common::locks;
+use crate::time::Duration;
 
 pub struct RwLock {
     /// The ID of the underlying mutex object
@@ -26,6 +29,13 @@ pub const fn new() -> RwLock {
         RwLock { rwl: SpinIdOnceCell::new() }
     }
 
+    /// The kernel's readers-writer lock has no way to choose between readers
+    /// and writers, so its own policy is kept.
+    #[inline]
+    pub const fn with_policy(_policy: RwLockPolicy) -> RwLock {
+        RwLock::new()
+    }
+
     /// Get the inner mutex's ID, which is lazily created.
     fn raw(&self) -> abi::ID {
         match self.rwl.get_or_try_init(|| new_rwl().map(|id| (id, ()))) {
@@ -81,6 +91,34 @@ pub unsafe fn write_unlock(&self) {
         let rwl = self.raw();
         expect_success_aborting(unsafe { abi::rwl_unl_rwl(rwl) }, &"rwl_unl_rwl");
     }
+
+    pub fn read_timeout(&self, timeout: Duration) -> bool {
+        locks::lock_timeout(timeout, || self.try_read())
+    }
+
+    pub fn write_timeout(&self, timeout: Duration) -> bool {
+        locks::lock_timeout(timeout, || self.try_write())
+    }
+
+    // The kernel's rwlocks cannot atomically turn a read lock into a write lock,
+    // so an upgradable read lock is an exclusive lock that is already upgraded.
+    #[inline]
+    pub fn upgradable_read(&self) {
+        self.write()
+    }
+
+    #[inline]
+    pub fn try_upgradable_read(&self) -> bool {
+        self.try_write()
+    }
+
+    #[inline]
+    pub unsafe fn upgradable_read_unlock(&self) {
+        unsafe { self.write_unlock() }
+    }
+
+    #[inline]
+    pub unsafe fn upgrade(&self) {}
 }
 
 impl Drop for RwLock {
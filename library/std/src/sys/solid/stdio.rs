@@ -16,6 +16,10 @@ impl io::Read for Stdin {
     fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
         Ok(0)
     }
+
+    fn read_buf(&mut self, _buf: io::BorrowedCursor<'_>) -> io::Result<()> {
+        Ok(())
+    }
 }
 
 impl Stdout {
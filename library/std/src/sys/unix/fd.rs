@@ -156,6 +156,43 @@ pub fn read_buf(&self, mut cursor: BorrowedCursor<'_>) -> io::Result<()> {
         Ok(())
     }
 
+    #[cfg(not(any(target_os = "espidf", target_os = "horizon", target_os = "vita")))]
+    pub fn read_buf_vectored(&self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        // Keep the `iovec`s on the stack, only reading into the first cursors if there are more.
+        const MAX_CURSORS: usize = 64;
+        let mut iovecs =
+            [libc::iovec { iov_base: crate::ptr::null_mut(), iov_len: 0 }; MAX_CURSORS];
+        let count = cmp::min(cursors.len(), cmp::min(MAX_CURSORS, max_iov()));
+        // Like `read_buf`, never ask for more than `READ_LIMIT` bytes in total.
+        let mut limit = READ_LIMIT;
+        for (iovec, cursor) in iovecs.iter_mut().zip(&mut cursors[..count]) {
+            iovec.iov_len = cmp::min(cursor.capacity(), limit);
+            limit -= iovec.iov_len;
+            // SAFETY: the pointer is only handed to `readv`, which never de-initializes memory.
+            iovec.iov_base = unsafe { cursor.as_mut() }.as_mut_ptr() as *mut libc::c_void;
+        }
+
+        let ret =
+            cvt(unsafe { libc::readv(self.as_raw_fd(), iovecs.as_ptr(), count as libc::c_int) })?;
+
+        let mut remaining = ret as usize;
+        for cursor in &mut cursors[..count] {
+            let n = cmp::min(remaining, cursor.capacity());
+            // Safety: `readv` filled the cursors in order, so the first `n` bytes of this one
+            // were written.
+            unsafe {
+                cursor.advance(n);
+            }
+            remaining -= n;
+        }
+        Ok(())
+    }
+
+    #[cfg(any(target_os = "espidf", target_os = "horizon", target_os = "vita"))]
+    pub fn read_buf_vectored(&self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        io::default_read_buf_vectored(|cursor| self.read_buf(cursor), cursors)
+    }
+
     #[cfg(any(
         target_os = "emscripten",
         target_os = "freebsd",
@@ -480,6 +517,10 @@ fn read_buf(&mut self, cursor: BorrowedCursor<'_>) -> io::Result<()> {
         (**self).read_buf(cursor)
     }
 
+    fn read_buf_vectored(&mut self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        (**self).read_buf_vectored(cursors)
+    }
+
     fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         (**self).read_vectored(bufs)
     }
@@ -1073,6 +1073,13 @@ fn get_creation_mode(&self) -> io::Result<c_int> {
     }
 }
 
+#[derive(Clone, Copy)]
+enum LockOperation {
+    Exclusive,
+    Shared,
+    Unlock,
+}
+
 impl File {
     pub fn open(path: &Path, opts: &OpenOptions) -> io::Result<File> {
         run_path_with_cstr(path, |path| File::open_c(path, opts))
@@ -1202,6 +1209,10 @@ pub fn read_buf(&self, cursor: BorrowedCursor<'_>) -> io::Result<()> {
         self.0.read_buf(cursor)
     }
 
+    pub fn read_buf_vectored(&self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        self.0.read_buf_vectored(cursors)
+    }
+
     pub fn read_vectored_at(&self, bufs: &mut [IoSliceMut<'_>], offset: u64) -> io::Result<usize> {
         self.0.read_vectored_at(bufs, offset)
     }
@@ -1252,6 +1263,111 @@ pub fn set_permissions(&self, perm: FilePermissions) -> io::Result<()> {
         Ok(())
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        self.flock(LockOperation::Exclusive, true).map(drop)
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.flock(LockOperation::Shared, true).map(drop)
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.flock(LockOperation::Exclusive, false)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.flock(LockOperation::Shared, false)
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        self.flock(LockOperation::Unlock, true).map(drop)
+    }
+
+    /// Returns whether the lock was acquired, which is always the case when `blocking`.
+    ///
+    /// `flock` locks belong to the open file description, so they are shared with the
+    /// descriptors duplicated from this one, including across `fork`.
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "tvos",
+        target_os = "watchos",
+    ))]
+    fn flock(&self, operation: LockOperation, blocking: bool) -> io::Result<bool> {
+        let mut operation = match operation {
+            LockOperation::Exclusive => libc::LOCK_EX,
+            LockOperation::Shared => libc::LOCK_SH,
+            LockOperation::Unlock => libc::LOCK_UN,
+        };
+        if !blocking {
+            operation |= libc::LOCK_NB;
+        }
+        match cvt_r(|| unsafe { libc::flock(self.as_raw_fd(), operation) }) {
+            Ok(_) => Ok(true),
+            // Another open file description holds a conflicting lock.
+            Err(err) if !blocking && err.raw_os_error() == Some(libc::EWOULDBLOCK) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Falls back to `fcntl` record locks covering the whole file where `flock` is missing.
+    ///
+    /// Unlike `flock` locks, these belong to the process: they never conflict with the other
+    /// locks of this process, and closing any descriptor of the file releases them. `F_WRLCK`
+    /// also requires the file to be open for writing and `F_RDLCK` for reading.
+    #[cfg(any(target_os = "aix", target_os = "illumos", target_os = "solaris"))]
+    fn flock(&self, operation: LockOperation, blocking: bool) -> io::Result<bool> {
+        let mut lock: libc::flock = unsafe { mem::zeroed() };
+        lock.l_type = match operation {
+            LockOperation::Exclusive => libc::F_WRLCK,
+            LockOperation::Shared => libc::F_RDLCK,
+            LockOperation::Unlock => libc::F_UNLCK,
+        } as _;
+        // A zero `l_len` starting at offset zero covers the whole file, however large it grows.
+        lock.l_whence = libc::SEEK_SET as _;
+        lock.l_start = 0;
+        lock.l_len = 0;
+        let cmd = if blocking { libc::F_SETLKW } else { libc::F_SETLK };
+        match cvt_r(|| unsafe { libc::fcntl(self.as_raw_fd(), cmd, &lock) }) {
+            Ok(_) => Ok(true),
+            // Another process holds a conflicting lock, POSIX allows either error.
+            Err(err)
+                if !blocking && matches!(err.raw_os_error(), Some(libc::EACCES | libc::EAGAIN)) =>
+            {
+                Ok(false)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    #[cfg(not(any(
+        target_os = "aix",
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "illumos",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "solaris",
+        target_os = "tvos",
+        target_os = "watchos",
+    )))]
+    fn flock(&self, _operation: LockOperation, _blocking: bool) -> io::Result<bool> {
+        Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "file locking is not supported on this platform",
+        ))
+    }
+
     pub fn set_times(&self, times: FileTimes) -> io::Result<()> {
         #[cfg(not(any(target_os = "redox", target_os = "espidf", target_os = "horizon")))]
         let to_timespec = |time: Option<SystemTime>| {
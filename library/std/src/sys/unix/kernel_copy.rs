@@ -44,10 +44,10 @@
 
 use crate::cmp::min;
 use crate::fs::{File, Metadata};
-use crate::io::copy::generic_copy;
+use crate::io::copy::{generic_copy, CopyStrategy};
 use crate::io::{
-    BufRead, BufReader, BufWriter, Error, Read, Result, StderrLock, StdinLock, StdoutLock, Take,
-    Write,
+    BufRead, BufReader, BufWriter, Error, LineWriter, Read, Result, Stderr, StderrLock, Stdin,
+    StdinLock, Stdout, StdoutLock, Take, Write,
 };
 use crate::mem::ManuallyDrop;
 use crate::net::TcpStream;
@@ -71,7 +71,7 @@
 pub(crate) fn copy_spec<R: Read + ?Sized, W: Write + ?Sized>(
     read: &mut R,
     write: &mut W,
-) -> Result<u64> {
+) -> Result<(u64, CopyStrategy)> {
     let copier = Copier { read, write };
     SpecCopy::copy(copier)
 }
@@ -166,17 +166,17 @@ struct Copier<'a, 'b, R: Read + ?Sized, W: Write + ?Sized> {
 }
 
 trait SpecCopy {
-    fn copy(self) -> Result<u64>;
+    fn copy(self) -> Result<(u64, CopyStrategy)>;
 }
 
 impl<R: Read + ?Sized, W: Write + ?Sized> SpecCopy for Copier<'_, '_, R, W> {
-    default fn copy(self) -> Result<u64> {
-        generic_copy(self.read, self.write)
+    default fn copy(self) -> Result<(u64, CopyStrategy)> {
+        generic_copy(self.read, self.write).map(|copied| (copied, CopyStrategy::ReadWrite))
     }
 }
 
 impl<R: CopyRead, W: CopyWrite> SpecCopy for Copier<'_, '_, R, W> {
-    fn copy(self) -> Result<u64> {
+    fn copy(self) -> Result<(u64, CopyStrategy)> {
         let (reader, writer) = (self.read, self.write);
         let r_cfg = reader.properties();
         let w_cfg = writer.properties();
@@ -190,6 +190,8 @@ fn copy(self) -> Result<u64> {
         };
 
         let mut written = 0u64;
+        // The last strategy which copied any data.
+        let mut strategy = CopyStrategy::ReadWrite;
 
         if let (CopyParams(input_meta, Some(readfd)), CopyParams(output_meta, Some(writefd))) =
             (r_cfg, w_cfg)
@@ -202,9 +204,15 @@ fn copy(self) -> Result<u64> {
                 result.update_take(reader);
 
                 match result {
-                    CopyResult::Ended(bytes_copied) => return Ok(bytes_copied + written),
+                    CopyResult::Ended(bytes_copied) => {
+                        return Ok((bytes_copied + written, CopyStrategy::CopyFileRange));
+                    }
                     CopyResult::Error(e, _) => return Err(e),
-                    CopyResult::Fallback(bytes) => written += bytes,
+                    CopyResult::Fallback(0) => {}
+                    CopyResult::Fallback(bytes) => {
+                        written += bytes;
+                        strategy = CopyStrategy::CopyFileRange;
+                    }
                 }
             }
 
@@ -219,9 +227,15 @@ fn copy(self) -> Result<u64> {
                 result.update_take(reader);
 
                 match result {
-                    CopyResult::Ended(bytes_copied) => return Ok(bytes_copied + written),
+                    CopyResult::Ended(bytes_copied) => {
+                        return Ok((bytes_copied + written, CopyStrategy::Sendfile));
+                    }
                     CopyResult::Error(e, _) => return Err(e),
-                    CopyResult::Fallback(bytes) => written += bytes,
+                    CopyResult::Fallback(0) => {}
+                    CopyResult::Fallback(bytes) => {
+                        written += bytes;
+                        strategy = CopyStrategy::Sendfile;
+                    }
                 }
             }
 
@@ -232,7 +246,9 @@ fn copy(self) -> Result<u64> {
                 result.update_take(reader);
 
                 match result {
-                    CopyResult::Ended(bytes_copied) => return Ok(bytes_copied + written),
+                    CopyResult::Ended(bytes_copied) => {
+                        return Ok((bytes_copied + written, CopyStrategy::Splice));
+                    }
                     CopyResult::Error(e, _) => return Err(e),
                     CopyResult::Fallback(0) => { /* use the fallback below */ }
                     CopyResult::Fallback(_) => {
@@ -243,10 +259,11 @@ fn copy(self) -> Result<u64> {
         }
 
         // fallback if none of the more specialized syscalls wants to work with these file descriptors
-        match generic_copy(reader, writer) {
-            Ok(bytes) => Ok(bytes + written),
-            err => err,
+        let bytes = generic_copy(reader, writer)?;
+        if bytes > 0 {
+            strategy = CopyStrategy::ReadWrite;
         }
+        Ok((bytes + written, strategy))
     }
 }
 
@@ -429,6 +446,28 @@ fn properties(&self) -> CopyParams {
     }
 }
 
+impl CopyRead for Stdin {
+    fn drain_to<W: Write>(&mut self, writer: &mut W, outer_limit: u64) -> Result<u64> {
+        self.lock().drain_to(writer, outer_limit)
+    }
+
+    fn properties(&self) -> CopyParams {
+        CopyParams(fd_to_meta(self), Some(self.as_raw_fd()))
+    }
+}
+
+impl CopyWrite for Stdout {
+    fn properties(&self) -> CopyParams {
+        CopyParams(fd_to_meta(self), Some(self.as_raw_fd()))
+    }
+}
+
+impl CopyWrite for Stderr {
+    fn properties(&self) -> CopyParams {
+        CopyParams(fd_to_meta(self), Some(self.as_raw_fd()))
+    }
+}
+
 impl CopyWrite for StdoutLock<'_> {
     fn properties(&self) -> CopyParams {
         CopyParams(fd_to_meta(self), Some(self.as_raw_fd()))
@@ -501,6 +540,12 @@ fn properties(&self) -> CopyParams {
     }
 }
 
+impl<T: ?Sized + CopyWrite> CopyWrite for LineWriter<T> {
+    fn properties(&self) -> CopyParams {
+        self.get_ref().properties()
+    }
+}
+
 fn fd_to_meta<T: AsRawFd>(fd: &T) -> FdMeta {
     let fd = fd.as_raw_fd();
     let file: ManuallyDrop<File> = ManuallyDrop::new(unsafe { File::from_raw_fd(fd) });
@@ -65,6 +65,70 @@ fn copy_specialization() -> Result<()> {
     result.and(rm1).and(rm2)
 }
 
+#[test]
+fn copy_n_through_buffers() -> Result<()> {
+    use crate::io::{BufReader, BufWriter};
+
+    let tmp_path = tmpdir();
+    let source_path = tmp_path.join("copy-n.source");
+    let sink_path = tmp_path.join("copy-n.sink");
+
+    let result: Result<()> = try {
+        let mut source = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&source_path)?;
+        source.write_all(b"abcdefghiklmnopqr")?;
+        source.seek(SeekFrom::Start(0))?;
+        let mut source = BufReader::with_capacity(4, source);
+        source.fill_buf()?;
+        assert_eq!(source.buffer(), b"abcd");
+
+        let sink = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&sink_path)?;
+        let mut sink = BufWriter::with_capacity(8, sink);
+        sink.write_all(b"xyz")?;
+
+        // The pending buffers are drained before the file descriptors are used directly, and the
+        // limit applies to the buffered data too.
+        let copied = crate::io::copy_n(&mut source, &mut sink, 10)?;
+        assert_eq!(copied, 10);
+        assert_eq!(sink.buffer().len(), 0, "sink buffer was flushed");
+        assert_eq!(source.buffer().len(), 0, "source buffer was drained");
+        assert_eq!(source.get_mut().stream_position()?, 10);
+
+        let (copied, strategy) = crate::io::copy_with_strategy(&mut source, &mut sink)?;
+        assert_eq!(copied, 7);
+        // Depending on the kernel and the filesystem, the syscalls may not be usable.
+        assert!(
+            matches!(
+                strategy,
+                io::CopyStrategy::CopyFileRange
+                    | io::CopyStrategy::Sendfile
+                    | io::CopyStrategy::ReadWrite
+            ),
+            "unexpected strategy {strategy:?}"
+        );
+
+        let mut sink = sink.into_inner()?;
+        sink.seek(SeekFrom::Start(0))?;
+        let mut copied = Vec::new();
+        sink.read_to_end(&mut copied)?;
+        assert_eq!(&copied, b"xyzabcdefghiklmnopqr");
+    };
+
+    let rm1 = crate::fs::remove_file(source_path);
+    let rm2 = crate::fs::remove_file(sink_path);
+
+    result.and(rm1).and(rm2)
+}
+
 #[test]
 fn copies_append_mode_sink() -> Result<()> {
     let tmp_path = tmpdir();
@@ -274,6 +274,46 @@ pub fn nodelay(&self) -> io::Result<bool> {
             unimpl!();
         }
 
+        pub fn set_keepalive(&self, _: Option<Duration>) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+            unimpl!();
+        }
+
+        pub fn set_keepalive_interval(&self, _: Duration) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive_interval(&self) -> io::Result<Duration> {
+            unimpl!();
+        }
+
+        pub fn set_keepalive_retries(&self, _: u32) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive_retries(&self) -> io::Result<u32> {
+            unimpl!();
+        }
+
+        pub fn set_send_buffer_size(&self, _: usize) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn send_buffer_size(&self) -> io::Result<usize> {
+            unimpl!();
+        }
+
+        pub fn set_recv_buffer_size(&self, _: usize) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn recv_buffer_size(&self) -> io::Result<usize> {
+            unimpl!();
+        }
+
         pub fn set_ttl(&self, _: u32) -> io::Result<()> {
             unimpl!();
         }
@@ -370,6 +410,60 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct TcpBuilder(());
+
+    impl TcpBuilder {
+        pub fn new() -> TcpBuilder {
+            TcpBuilder(())
+        }
+
+        pub fn reuse_address(&mut self, _: bool) {}
+
+        pub fn reuse_port(&mut self, _: bool) {}
+
+        pub fn only_v6(&mut self, _: bool) {}
+
+        pub fn send_buffer_size(&mut self, _: usize) {}
+
+        pub fn recv_buffer_size(&mut self, _: usize) {}
+
+        pub fn backlog(&mut self, _: u32) {}
+
+        pub fn bind(&mut self, _: SocketAddr) {}
+
+        pub fn listen(&self, _: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
+            unimpl!();
+        }
+
+        pub fn connect(&self, _: io::Result<&SocketAddr>) -> io::Result<TcpStream> {
+            unimpl!();
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct UdpBuilder(());
+
+    impl UdpBuilder {
+        pub fn new() -> UdpBuilder {
+            UdpBuilder(())
+        }
+
+        pub fn reuse_address(&mut self, _: bool) {}
+
+        pub fn reuse_port(&mut self, _: bool) {}
+
+        pub fn only_v6(&mut self, _: bool) {}
+
+        pub fn send_buffer_size(&mut self, _: usize) {}
+
+        pub fn recv_buffer_size(&mut self, _: usize) {}
+
+        pub fn bind(&self, _: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
+            unimpl!();
+        }
+    }
+
     pub struct UdpSocket {
         inner: Socket,
     }
@@ -436,6 +530,22 @@ pub fn broadcast(&self) -> io::Result<bool> {
             unimpl!();
         }
 
+        pub fn set_send_buffer_size(&self, _: usize) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn send_buffer_size(&self) -> io::Result<usize> {
+            unimpl!();
+        }
+
+        pub fn set_recv_buffer_size(&self, _: usize) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn recv_buffer_size(&self) -> io::Result<usize> {
+            unimpl!();
+        }
+
         pub fn set_multicast_loop_v4(&self, _: bool) -> io::Result<()> {
             unimpl!();
         }
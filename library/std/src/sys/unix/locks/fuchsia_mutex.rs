@@ -41,11 +41,13 @@
     AtomicU32,
     Ordering::{Acquire, Relaxed, Release},
 };
+use crate::sys::common::locks;
 use crate::sys::futex::zircon::{
     zx_futex_wait, zx_futex_wake_single_owner, zx_handle_t, zx_thread_self, ZX_ERR_BAD_HANDLE,
     ZX_ERR_BAD_STATE, ZX_ERR_INVALID_ARGS, ZX_ERR_TIMED_OUT, ZX_ERR_WRONG_TYPE, ZX_OK,
     ZX_TIME_INFINITE,
 };
+use crate::time::Duration;
 
 // The lowest two bits of a `zx_handle_t` are always set, so the lowest bit is used to mark the
 // mutex as contested by clearing it.
@@ -145,6 +147,12 @@ unsafe fn lock_contested(&self, mut state: u32, thread_self: zx_handle_t) {
         }
     }
 
+    // Polling keeps the contested state bookkeeping in `lock_contested` simple,
+    // as it does not have to undo marking the mutex as contested on timeout.
+    pub fn lock_timeout(&self, timeout: Duration) -> bool {
+        locks::lock_timeout(timeout, || self.try_lock())
+    }
+
     #[inline]
     pub unsafe fn unlock(&self) {
         if is_contested(self.futex.swap(UNLOCKED, Release)) {
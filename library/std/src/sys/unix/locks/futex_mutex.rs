@@ -3,6 +3,7 @@
     Ordering::{Acquire, Relaxed, Release},
 };
 use crate::sys::futex::{futex_wait, futex_wake};
+use crate::time::{Duration, Instant};
 
 pub struct Mutex {
     /// 0: unlocked
@@ -25,12 +26,20 @@ pub fn try_lock(&self) -> bool {
     #[inline]
     pub fn lock(&self) {
         if self.futex.compare_exchange(0, 1, Acquire, Relaxed).is_err() {
-            self.lock_contended();
+            self.lock_contended(None);
         }
     }
 
+    /// Locks the mutex, giving up and returning `false` once `timeout` has elapsed.
+    #[inline]
+    pub fn lock_timeout(&self, timeout: Duration) -> bool {
+        self.futex.compare_exchange(0, 1, Acquire, Relaxed).is_ok()
+            // Overflows are rounded up to an infinite timeout.
+            || self.lock_contended(Instant::now().checked_add(timeout))
+    }
+
     #[cold]
-    fn lock_contended(&self) {
+    fn lock_contended(&self, deadline: Option<Instant>) -> bool {
         // Spin first to speed things up if the lock is released quickly.
         let mut state = self.spin();
 
@@ -38,7 +47,7 @@ fn lock_contended(&self) {
         // without marking it as contended.
         if state == 0 {
             match self.futex.compare_exchange(0, 1, Acquire, Relaxed) {
-                Ok(_) => return, // Locked!
+                Ok(_) => return true, // Locked!
                 Err(s) => state = s,
             }
         }
@@ -49,11 +58,20 @@ fn lock_contended(&self) {
             // to be friendlier for the caches.
             if state != 2 && self.futex.swap(2, Acquire) == 0 {
                 // We changed it from 0 to 2, so we just successfully locked it.
-                return;
+                return true;
             }
 
             // Wait for the futex to change state, assuming it is still 2.
-            futex_wait(&self.futex, 2, None);
+            // The contended state is left as is on timeout, which at worst
+            // causes a spurious wake-up when the mutex is unlocked.
+            let timeout = match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(timeout) => Some(timeout),
+                    None => return false,
+                },
+                None => None,
+            };
+            futex_wait(&self.futex, 2, timeout);
 
             // Spin again after waking up.
             state = self.spin();
@@ -1,8 +1,11 @@
+use super::Mutex;
 use crate::sync::atomic::{
-    AtomicU32,
-    Ordering::{Acquire, Relaxed, Release},
+    fence, AtomicU32,
+    Ordering::{AcqRel, Acquire, Relaxed, Release},
 };
+use crate::sync::RwLockPolicy;
 use crate::sys::futex::{futex_wait, futex_wake, futex_wake_all};
+use crate::time::{Duration, Instant};
 
 pub struct RwLock {
     // The state consists of a 30-bit reader counter, a 'readers waiting' flag, and a 'writers waiting' flag.
@@ -16,6 +19,13 @@ pub struct RwLock {
     // The 'condition variable' to notify writers through.
     // Incremented on every signal.
     writer_notify: AtomicU32,
+    // Held by the upgradable reader, if any, in addition to its read lock.
+    // This makes sure there is only ever one thread that may upgrade.
+    upgradable: Mutex,
+    // Set to 1 by the upgradable reader while it waits for the other readers to leave.
+    upgrading: AtomicU32,
+    // Whether new readers may lock the lock while writers are waiting.
+    prefer_readers: bool,
 }
 
 const READ_LOCKED: u32 = 1;
@@ -46,14 +56,18 @@ fn has_writers_waiting(state: u32) -> bool {
 }
 
 #[inline]
-fn is_read_lockable(state: u32) -> bool {
+fn is_read_lockable(state: u32, prefer_readers: bool) -> bool {
     // This also returns false if the counter could overflow if we tried to read lock it.
     //
     // We don't allow read-locking if there's readers waiting, even if the lock is unlocked
     // and there's no writers waiting. The only situation when this happens is after unlocking,
     // at which point the unlocking thread might be waking up writers, which have priority over readers.
     // The unlocking thread will clear the readers waiting bit and wake up readers, if necessary.
-    state & MASK < MAX_READERS && !has_readers_waiting(state) && !has_writers_waiting(state)
+    //
+    // Waiting writers only keep new readers out if readers aren't preferred.
+    state & MASK < MAX_READERS
+        && !has_readers_waiting(state)
+        && (prefer_readers || !has_writers_waiting(state))
 }
 
 #[inline]
@@ -64,29 +78,54 @@ fn has_reached_max_readers(state: u32) -> bool {
 impl RwLock {
     #[inline]
     pub const fn new() -> Self {
-        Self { state: AtomicU32::new(0), writer_notify: AtomicU32::new(0) }
+        Self::with_policy(RwLockPolicy::PreferWriters)
+    }
+
+    #[inline]
+    pub const fn with_policy(policy: RwLockPolicy) -> Self {
+        Self {
+            state: AtomicU32::new(0),
+            writer_notify: AtomicU32::new(0),
+            upgradable: Mutex::new(),
+            upgrading: AtomicU32::new(0),
+            prefer_readers: matches!(policy, RwLockPolicy::PreferReaders),
+        }
     }
 
     #[inline]
     pub fn try_read(&self) -> bool {
         self.state
-            .fetch_update(Acquire, Relaxed, |s| is_read_lockable(s).then(|| s + READ_LOCKED))
+            .fetch_update(Acquire, Relaxed, |s| {
+                is_read_lockable(s, self.prefer_readers).then(|| s + READ_LOCKED)
+            })
             .is_ok()
     }
 
     #[inline]
     pub fn read(&self) {
         let state = self.state.load(Relaxed);
-        if !is_read_lockable(state)
+        if !is_read_lockable(state, self.prefer_readers)
             || self
                 .state
                 .compare_exchange_weak(state, state + READ_LOCKED, Acquire, Relaxed)
                 .is_err()
         {
-            self.read_contended();
+            self.read_contended(None);
         }
     }
 
+    #[inline]
+    pub fn read_timeout(&self, timeout: Duration) -> bool {
+        let state = self.state.load(Relaxed);
+        (is_read_lockable(state, self.prefer_readers)
+            && self
+                .state
+                .compare_exchange_weak(state, state + READ_LOCKED, Acquire, Relaxed)
+                .is_ok())
+            // Overflows are rounded up to an infinite timeout.
+            || self.read_contended(Instant::now().checked_add(timeout))
+    }
+
     #[inline]
     pub unsafe fn read_unlock(&self) {
         let state = self.state.fetch_sub(READ_LOCKED, Release) - READ_LOCKED;
@@ -98,19 +137,22 @@ pub unsafe fn read_unlock(&self) {
         // Wake up a writer if we were the last reader and there's a writer waiting.
         if is_unlocked(state) && has_writers_waiting(state) {
             self.wake_writer_or_readers(state);
+        } else if state & MASK == READ_LOCKED && has_writers_waiting(state) {
+            // The remaining reader might be waiting for us to leave so it can upgrade.
+            self.wake_upgrader();
         }
     }
 
     #[cold]
-    fn read_contended(&self) {
+    fn read_contended(&self, deadline: Option<Instant>) -> bool {
         let mut state = self.spin_read();
 
         loop {
             // If we can lock it, lock it.
-            if is_read_lockable(state) {
+            if is_read_lockable(state, self.prefer_readers) {
                 match self.state.compare_exchange_weak(state, state + READ_LOCKED, Acquire, Relaxed)
                 {
-                    Ok(_) => return, // Locked!
+                    Ok(_) => return true, // Locked!
                     Err(s) => {
                         state = s;
                         continue;
@@ -134,7 +176,14 @@ fn read_contended(&self) {
             }
 
             // Wait for the state to change.
-            futex_wait(&self.state, state | READERS_WAITING, None);
+            let timeout = match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(timeout) => Some(timeout),
+                    None => return false,
+                },
+                None => None,
+            };
+            futex_wait(&self.state, state | READERS_WAITING, timeout);
 
             // Spin again after waking up.
             state = self.spin_read();
@@ -151,10 +200,17 @@ pub fn try_write(&self) -> bool {
     #[inline]
     pub fn write(&self) {
         if self.state.compare_exchange_weak(0, WRITE_LOCKED, Acquire, Relaxed).is_err() {
-            self.write_contended();
+            self.write_contended(None);
         }
     }
 
+    #[inline]
+    pub fn write_timeout(&self, timeout: Duration) -> bool {
+        self.state.compare_exchange_weak(0, WRITE_LOCKED, Acquire, Relaxed).is_ok()
+            // Overflows are rounded up to an infinite timeout.
+            || self.write_contended(Instant::now().checked_add(timeout))
+    }
+
     #[inline]
     pub unsafe fn write_unlock(&self) {
         let state = self.state.fetch_sub(WRITE_LOCKED, Release) - WRITE_LOCKED;
@@ -167,7 +223,7 @@ pub unsafe fn write_unlock(&self) {
     }
 
     #[cold]
-    fn write_contended(&self) {
+    fn write_contended(&self, deadline: Option<Instant>) -> bool {
         let mut state = self.spin_write();
 
         let mut other_writers_waiting = 0;
@@ -181,7 +237,7 @@ fn write_contended(&self) {
                     Acquire,
                     Relaxed,
                 ) {
-                    Ok(_) => return, // Locked!
+                    Ok(_) => return true, // Locked!
                     Err(s) => {
                         state = s;
                         continue;
@@ -214,22 +270,130 @@ fn write_contended(&self) {
                 continue;
             }
 
-            // Wait for the state to change.
-            futex_wait(&self.writer_notify, seq, None);
+            // Wait for the state to change. If we time out, the writers waiting
+            // bit is left for the next unlock to clear, as if we were still waiting.
+            let timeout = match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(timeout) => Some(timeout),
+                    None => return false,
+                },
+                None => None,
+            };
+            futex_wait(&self.writer_notify, seq, timeout);
 
             // Spin again after waking up.
             state = self.spin_write();
         }
     }
 
+    #[inline]
+    pub fn upgradable_read(&self) {
+        self.upgradable.lock();
+        self.read();
+    }
+
+    #[inline]
+    pub fn try_upgradable_read(&self) -> bool {
+        if !self.upgradable.try_lock() {
+            return false;
+        }
+        if !self.try_read() {
+            unsafe { self.upgradable.unlock() };
+            return false;
+        }
+        true
+    }
+
+    #[inline]
+    pub unsafe fn upgradable_read_unlock(&self) {
+        self.read_unlock();
+        self.upgradable.unlock();
+    }
+
+    /// Turns the upgradable read lock held by the current thread into a write lock.
+    #[inline]
+    pub unsafe fn upgrade(&self) {
+        // If we are the only reader, we can take the write lock right away.
+        if self
+            .state
+            .fetch_update(Acquire, Relaxed, |s| {
+                (s & MASK == READ_LOCKED).then(|| s - READ_LOCKED + WRITE_LOCKED)
+            })
+            .is_err()
+        {
+            self.upgrade_contended();
+        }
+        self.upgradable.unlock();
+    }
+
+    #[cold]
+    fn upgrade_contended(&self) {
+        loop {
+            // Announce that we're waiting before touching the state, so that
+            // a reader that sees the writers waiting bit we set below also sees this.
+            self.upgrading.store(1, Relaxed);
+
+            // Take the write lock if we're the last reader. Otherwise, set the
+            // writers waiting bit to keep new readers out while we wait.
+            let state = self
+                .state
+                .fetch_update(AcqRel, Acquire, |s| {
+                    Some(if s & MASK == READ_LOCKED {
+                        s - READ_LOCKED + WRITE_LOCKED
+                    } else {
+                        s | WRITERS_WAITING
+                    })
+                })
+                .unwrap();
+            if state & MASK == READ_LOCKED {
+                self.upgrading.store(0, Relaxed);
+                return;
+            }
+
+            // Wait for the last other reader to wake us up.
+            futex_wait(&self.upgrading, 1, None);
+        }
+    }
+
+    /// Wakes up the upgradable reader if it's waiting for the other readers to leave.
+    #[cold]
+    fn wake_upgrader(&self) {
+        // Synchronize with the upgrading thread's update of `state`, so that
+        // we see its store to `upgrading` if it set the writers waiting bit.
+        fence(Acquire);
+        if self.upgrading.load(Relaxed) == 1 && self.upgrading.swap(0, Relaxed) == 1 {
+            futex_wake(&self.upgrading);
+        }
+    }
+
     /// Wake up waiting threads after unlocking.
     ///
     /// If both are waiting, this will wake up only one writer, but will fall
-    /// back to waking up readers if there was no writer to wake up.
+    /// back to waking up readers if there was no writer to wake up. If readers
+    /// are preferred, this wakes up all readers instead.
     #[cold]
     fn wake_writer_or_readers(&self, mut state: u32) {
         assert!(is_unlocked(state));
 
+        if self.prefer_readers && has_readers_waiting(state) {
+            // Wake up all readers, but also a writer if one is waiting, since
+            // none of the readers might end up taking the lock. A writer that
+            // loses the race sets the writers waiting bit again, and the last
+            // reader wakes it up once more when it unlocks.
+            if let Ok(state) =
+                self.state.fetch_update(Relaxed, Relaxed, |s| is_unlocked(s).then_some(0))
+            {
+                if has_readers_waiting(state) {
+                    futex_wake_all(&self.state);
+                }
+                if has_writers_waiting(state) {
+                    self.wake_writer();
+                }
+            }
+            // Otherwise the lock got locked. Not our problem anymore.
+            return;
+        }
+
         // The readers waiting bit might be turned on at any point now,
         // since readers will block when there's anything waiting.
         // Writers will just lock the lock though, regardless of the waiting bits,
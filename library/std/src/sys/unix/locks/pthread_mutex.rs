@@ -1,7 +1,10 @@
 use crate::cell::UnsafeCell;
 use crate::mem::{forget, MaybeUninit};
+#[cfg(not(any(target_os = "netbsd", target_os = "solaris", target_os = "illumos")))]
+use crate::sys::common::locks;
 use crate::sys::cvt_nz;
 use crate::sys_common::lazy_box::{LazyBox, LazyInit};
+use crate::time::Duration;
 
 struct AllocatedMutex(UnsafeCell<libc::pthread_mutex_t>);
 
@@ -117,6 +120,65 @@ pub unsafe fn unlock(&self) {
     pub unsafe fn try_lock(&self) -> bool {
         libc::pthread_mutex_trylock(raw(self)) == 0
     }
+
+    #[cfg(any(target_os = "netbsd", target_os = "solaris", target_os = "illumos"))]
+    pub unsafe fn lock_timeout(&self, timeout: Duration) -> bool {
+        let r = timed_lock(timeout, |abstime| pthread_mutex_timedlock(raw(self), abstime));
+        debug_assert!(r == 0 || r == libc::ETIMEDOUT);
+        r == 0
+    }
+
+    // `pthread_mutex_timedlock` is not available on the other platforms that
+    // use this implementation, such as macOS, so we poll instead.
+    #[cfg(not(any(target_os = "netbsd", target_os = "solaris", target_os = "illumos")))]
+    pub unsafe fn lock_timeout(&self, timeout: Duration) -> bool {
+        locks::lock_timeout(timeout, || self.try_lock())
+    }
+}
+
+#[cfg(any(target_os = "netbsd", target_os = "solaris", target_os = "illumos"))]
+extern "C" {
+    fn pthread_mutex_timedlock(
+        mutex: *mut libc::pthread_mutex_t,
+        abstime: *const libc::timespec,
+    ) -> libc::c_int;
+}
+
+/// Calls `lock` with the `CLOCK_REALTIME` deadline `timeout` from now, as the
+/// `pthread_*_timed*lock` functions expect, and returns its result. Returns
+/// `ETIMEDOUT` once `timeout` has elapsed.
+///
+/// Those functions give up once the system clock reaches the deadline, which
+/// can be early if the clock is moved forward. We measure the timeout
+/// against the monotonic clock instead and try again if that happens.
+#[cfg(any(target_os = "netbsd", target_os = "solaris", target_os = "illumos"))]
+pub(super) fn timed_lock(
+    timeout: Duration,
+    mut lock: impl FnMut(&libc::timespec) -> libc::c_int,
+) -> libc::c_int {
+    use crate::sys::time::{Timespec, TIMESPEC_MAX};
+    use crate::time::Instant;
+
+    // Overflows are rounded up to an infinite timeout (None).
+    let deadline = Instant::now().checked_add(timeout);
+    let mut remaining = timeout;
+    loop {
+        let abstime = Timespec::now(libc::CLOCK_REALTIME)
+            .checked_add_duration(&remaining)
+            .and_then(|t| t.to_timespec())
+            .unwrap_or(TIMESPEC_MAX);
+        let r = lock(&abstime);
+        if r != libc::ETIMEDOUT {
+            return r;
+        }
+        remaining = match deadline {
+            Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                Some(remaining) if !remaining.is_zero() => remaining,
+                _ => return r,
+            },
+            None => remaining,
+        };
+    }
 }
 
 pub(super) struct PthreadMutexAttr<'a>(pub &'a mut MaybeUninit<libc::pthread_mutexattr_t>);
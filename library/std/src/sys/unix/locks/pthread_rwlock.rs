@@ -1,7 +1,13 @@
+#[cfg(any(target_os = "netbsd", target_os = "solaris", target_os = "illumos"))]
+use super::pthread_mutex::timed_lock;
 use crate::cell::UnsafeCell;
 use crate::mem::forget;
 use crate::sync::atomic::{AtomicUsize, Ordering};
+use crate::sync::RwLockPolicy;
+#[cfg(not(any(target_os = "netbsd", target_os = "solaris", target_os = "illumos")))]
+use crate::sys::common::locks;
 use crate::sys_common::lazy_box::{LazyBox, LazyInit};
+use crate::time::Duration;
 
 struct AllocatedRwLock {
     inner: UnsafeCell<libc::pthread_rwlock_t>,
@@ -47,34 +53,10 @@ unsafe fn raw_unlock(&self) {
         let r = libc::pthread_rwlock_unlock(self.inner.get());
         debug_assert_eq!(r, 0);
     }
-}
-
-impl Drop for AllocatedRwLock {
-    fn drop(&mut self) {
-        let r = unsafe { libc::pthread_rwlock_destroy(self.inner.get()) };
-        // On DragonFly pthread_rwlock_destroy() returns EINVAL if called on a
-        // rwlock that was just initialized with
-        // libc::PTHREAD_RWLOCK_INITIALIZER. Once it is used (locked/unlocked)
-        // or pthread_rwlock_init() is called, this behaviour no longer occurs.
-        if cfg!(target_os = "dragonfly") {
-            debug_assert!(r == 0 || r == libc::EINVAL);
-        } else {
-            debug_assert_eq!(r, 0);
-        }
-    }
-}
-
-impl RwLock {
-    #[inline]
-    pub const fn new() -> RwLock {
-        RwLock { inner: LazyBox::new() }
-    }
-
-    #[inline]
-    pub fn read(&self) {
-        let lock = &*self.inner;
-        let r = unsafe { libc::pthread_rwlock_rdlock(lock.inner.get()) };
 
+    /// Checks the result of read locking with `pthread_rwlock_rdlock` or
+    /// `pthread_rwlock_timedrdlock`.
+    fn check_read(&self, r: libc::c_int) {
         // According to POSIX, when a thread tries to acquire this read lock
         // while it already holds the write lock
         // (or vice versa, or tries to acquire the write lock twice),
@@ -90,13 +72,13 @@ pub fn read(&self) {
         // got the write lock more than once, or got a read and a write lock.
         if r == libc::EAGAIN {
             panic!("rwlock maximum reader count exceeded");
-        } else if r == libc::EDEADLK || (r == 0 && unsafe { *lock.write_locked.get() }) {
+        } else if r == libc::EDEADLK || (r == 0 && unsafe { *self.write_locked.get() }) {
             // Above, we make sure to only access `write_locked` when `r == 0` to avoid
             // data races.
             if r == 0 {
-                // `pthread_rwlock_rdlock` succeeded when it should not have.
+                // The lock call succeeded when it should not have.
                 unsafe {
-                    lock.raw_unlock();
+                    self.raw_unlock();
                 }
             }
             panic!("rwlock read lock would result in deadlock");
@@ -104,10 +86,75 @@ pub fn read(&self) {
             // POSIX does not make guarantees about all the errors that may be returned.
             // See issue #94705 for more details.
             assert_eq!(r, 0, "unexpected error during rwlock read lock: {:?}", r);
-            lock.num_readers.fetch_add(1, Ordering::Relaxed);
+            self.num_readers.fetch_add(1, Ordering::Relaxed);
         }
     }
 
+    /// Checks the result of write locking with `pthread_rwlock_wrlock` or
+    /// `pthread_rwlock_timedwrlock`.
+    fn check_write(&self, r: libc::c_int) {
+        // See comments above for why we check for EDEADLK and write_locked. For the same reason,
+        // we also need to check that there are no readers (tracked in `num_readers`).
+        if r == libc::EDEADLK
+            || (r == 0 && unsafe { *self.write_locked.get() })
+            || self.num_readers.load(Ordering::Relaxed) != 0
+        {
+            // Above, we make sure to only access `write_locked` when `r == 0` to avoid
+            // data races.
+            if r == 0 {
+                // The lock call succeeded when it should not have.
+                unsafe {
+                    self.raw_unlock();
+                }
+            }
+            panic!("rwlock write lock would result in deadlock");
+        } else {
+            // According to POSIX, for a properly initialized rwlock this can only
+            // return EDEADLK or 0. We rely on that.
+            debug_assert_eq!(r, 0);
+        }
+
+        unsafe {
+            *self.write_locked.get() = true;
+        }
+    }
+}
+
+impl Drop for AllocatedRwLock {
+    fn drop(&mut self) {
+        let r = unsafe { libc::pthread_rwlock_destroy(self.inner.get()) };
+        // On DragonFly pthread_rwlock_destroy() returns EINVAL if called on a
+        // rwlock that was just initialized with
+        // libc::PTHREAD_RWLOCK_INITIALIZER. Once it is used (locked/unlocked)
+        // or pthread_rwlock_init() is called, this behaviour no longer occurs.
+        if cfg!(target_os = "dragonfly") {
+            debug_assert!(r == 0 || r == libc::EINVAL);
+        } else {
+            debug_assert_eq!(r, 0);
+        }
+    }
+}
+
+impl RwLock {
+    #[inline]
+    pub const fn new() -> RwLock {
+        RwLock { inner: LazyBox::new() }
+    }
+
+    // POSIX has no way to choose between readers and writers, so the
+    // policy of the platform's rwlock is kept.
+    #[inline]
+    pub const fn with_policy(_policy: RwLockPolicy) -> RwLock {
+        RwLock::new()
+    }
+
+    #[inline]
+    pub fn read(&self) {
+        let lock = &*self.inner;
+        let r = unsafe { libc::pthread_rwlock_rdlock(lock.inner.get()) };
+        lock.check_read(r);
+    }
+
     #[inline]
     pub fn try_read(&self) -> bool {
         let lock = &*self.inner;
@@ -132,30 +179,7 @@ pub fn try_read(&self) -> bool {
     pub fn write(&self) {
         let lock = &*self.inner;
         let r = unsafe { libc::pthread_rwlock_wrlock(lock.inner.get()) };
-        // See comments above for why we check for EDEADLK and write_locked. For the same reason,
-        // we also need to check that there are no readers (tracked in `num_readers`).
-        if r == libc::EDEADLK
-            || (r == 0 && unsafe { *lock.write_locked.get() })
-            || lock.num_readers.load(Ordering::Relaxed) != 0
-        {
-            // Above, we make sure to only access `write_locked` when `r == 0` to avoid
-            // data races.
-            if r == 0 {
-                // `pthread_rwlock_wrlock` succeeded when it should not have.
-                unsafe {
-                    lock.raw_unlock();
-                }
-            }
-            panic!("rwlock write lock would result in deadlock");
-        } else {
-            // According to POSIX, for a properly initialized rwlock this can only
-            // return EDEADLK or 0. We rely on that.
-            debug_assert_eq!(r, 0);
-        }
-
-        unsafe {
-            *lock.write_locked.get() = true;
-        }
+        lock.check_write(r);
     }
 
     #[inline]
@@ -192,4 +216,74 @@ pub unsafe fn write_unlock(&self) {
         *lock.write_locked.get() = false;
         lock.raw_unlock();
     }
+
+    #[cfg(any(target_os = "netbsd", target_os = "solaris", target_os = "illumos"))]
+    pub fn read_timeout(&self, timeout: Duration) -> bool {
+        let lock = &*self.inner;
+        let r = timed_lock(timeout, |abstime| unsafe {
+            pthread_rwlock_timedrdlock(lock.inner.get(), abstime)
+        });
+        if r == libc::ETIMEDOUT {
+            return false;
+        }
+        lock.check_read(r);
+        true
+    }
+
+    #[cfg(any(target_os = "netbsd", target_os = "solaris", target_os = "illumos"))]
+    pub unsafe fn write_timeout(&self, timeout: Duration) -> bool {
+        let lock = &*self.inner;
+        let r =
+            timed_lock(timeout, |abstime| pthread_rwlock_timedwrlock(lock.inner.get(), abstime));
+        if r == libc::ETIMEDOUT {
+            return false;
+        }
+        lock.check_write(r);
+        true
+    }
+
+    // `pthread_rwlock_timedrdlock` and `pthread_rwlock_timedwrlock` are not
+    // available on the other platforms that use this implementation, such as
+    // macOS, so we poll instead.
+    #[cfg(not(any(target_os = "netbsd", target_os = "solaris", target_os = "illumos")))]
+    pub fn read_timeout(&self, timeout: Duration) -> bool {
+        locks::lock_timeout(timeout, || self.try_read())
+    }
+
+    #[cfg(not(any(target_os = "netbsd", target_os = "solaris", target_os = "illumos")))]
+    pub unsafe fn write_timeout(&self, timeout: Duration) -> bool {
+        locks::lock_timeout(timeout, || self.try_write())
+    }
+
+    // pthread rwlocks cannot atomically turn a read lock into a write lock,
+    // so an upgradable read lock is an exclusive lock that is already upgraded.
+    #[inline]
+    pub fn upgradable_read(&self) {
+        self.write();
+    }
+
+    #[inline]
+    pub unsafe fn try_upgradable_read(&self) -> bool {
+        self.try_write()
+    }
+
+    #[inline]
+    pub unsafe fn upgradable_read_unlock(&self) {
+        self.write_unlock();
+    }
+
+    #[inline]
+    pub unsafe fn upgrade(&self) {}
+}
+
+#[cfg(any(target_os = "netbsd", target_os = "solaris", target_os = "illumos"))]
+extern "C" {
+    fn pthread_rwlock_timedrdlock(
+        rwlock: *mut libc::pthread_rwlock_t,
+        abstime: *const libc::timespec,
+    ) -> libc::c_int;
+    fn pthread_rwlock_timedwrlock(
+        rwlock: *mut libc::pthread_rwlock_t,
+        abstime: *const libc::timespec,
+    ) -> libc::c_int;
 }
@@ -22,6 +22,37 @@
 
 pub use crate::sys::{cvt, cvt_r};
 
+/// Returns the `IPPROTO_TCP` options controlling the keepalive idle time,
+/// probe interval and probe count, on platforms where they can be set per
+/// socket.
+fn keepalive_options() -> io::Result<(c_int, c_int, c_int)> {
+    cfg_if::cfg_if! {
+        if #[cfg(target_vendor = "apple")] {
+            Ok((libc::TCP_KEEPALIVE, libc::TCP_KEEPINTVL, libc::TCP_KEEPCNT))
+        } else if #[cfg(any(
+            target_os = "android",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "linux",
+            target_os = "netbsd",
+        ))] {
+            Ok((libc::TCP_KEEPIDLE, libc::TCP_KEEPINTVL, libc::TCP_KEEPCNT))
+        } else {
+            Err(io::const_io_error!(
+                io::ErrorKind::Unsupported,
+                "TCP keepalive parameters are not supported on this platform",
+            ))
+        }
+    }
+}
+
+/// Keepalive times are configured in whole seconds and must be positive, so
+/// round up to at least one second.
+fn keepalive_secs(dur: Duration) -> c_int {
+    let secs = dur.as_secs().saturating_add((dur.subsec_nanos() > 0) as u64);
+    secs.clamp(1, c_int::MAX as u64) as c_int
+}
+
 #[allow(unused_extern_crates)]
 pub extern crate libc as netc;
 
@@ -277,6 +308,10 @@ pub fn read_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.0.read_vectored(bufs)
     }
 
+    pub fn read_buf_vectored(&self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        self.0.read_buf_vectored(cursors)
+    }
+
     #[inline]
     pub fn is_read_vectored(&self) -> bool {
         self.0.is_read_vectored()
@@ -410,6 +445,104 @@ pub fn nodelay(&self) -> io::Result<bool> {
         Ok(raw != 0)
     }
 
+    pub fn set_keepalive(&self, keepalive: Option<Duration>) -> io::Result<()> {
+        // Where the idle time can't be set per socket, the system-wide one is
+        // used.
+        if let (Some(idle), Ok((idle_opt, _, _))) = (keepalive, keepalive_options()) {
+            setsockopt(self, libc::IPPROTO_TCP, idle_opt, keepalive_secs(idle))?;
+        }
+        setsockopt(self, libc::SOL_SOCKET, libc::SO_KEEPALIVE, keepalive.is_some() as c_int)
+    }
+
+    pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+        let raw: c_int = getsockopt(self, libc::SOL_SOCKET, libc::SO_KEEPALIVE)?;
+        if raw == 0 {
+            return Ok(None);
+        }
+        let (idle_opt, _, _) = keepalive_options()?;
+        let secs: c_int = getsockopt(self, libc::IPPROTO_TCP, idle_opt)?;
+        Ok(Some(Duration::from_secs(secs as u64)))
+    }
+
+    pub fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()> {
+        let (_, interval_opt, _) = keepalive_options()?;
+        setsockopt(self, libc::IPPROTO_TCP, interval_opt, keepalive_secs(interval))
+    }
+
+    pub fn keepalive_interval(&self) -> io::Result<Duration> {
+        let (_, interval_opt, _) = keepalive_options()?;
+        let secs: c_int = getsockopt(self, libc::IPPROTO_TCP, interval_opt)?;
+        Ok(Duration::from_secs(secs as u64))
+    }
+
+    pub fn set_keepalive_retries(&self, retries: u32) -> io::Result<()> {
+        let (_, _, retries_opt) = keepalive_options()?;
+        let retries = cmp::min(retries, c_int::MAX as u32) as c_int;
+        setsockopt(self, libc::IPPROTO_TCP, retries_opt, retries)
+    }
+
+    pub fn keepalive_retries(&self) -> io::Result<u32> {
+        let (_, _, retries_opt) = keepalive_options()?;
+        let raw: c_int = getsockopt(self, libc::IPPROTO_TCP, retries_opt)?;
+        Ok(raw as u32)
+    }
+
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        let size = cmp::min(size, c_int::MAX as usize) as c_int;
+        setsockopt(self, libc::SOL_SOCKET, libc::SO_SNDBUF, size)
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        let raw: c_int = getsockopt(self, libc::SOL_SOCKET, libc::SO_SNDBUF)?;
+        Ok(raw as usize)
+    }
+
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        let size = cmp::min(size, c_int::MAX as usize) as c_int;
+        setsockopt(self, libc::SOL_SOCKET, libc::SO_RCVBUF, size)
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        let raw: c_int = getsockopt(self, libc::SOL_SOCKET, libc::SO_RCVBUF)?;
+        Ok(raw as usize)
+    }
+
+    pub fn set_reuse_port(&self, reuse_port: bool) -> io::Result<()> {
+        cfg_if::cfg_if! {
+            if #[cfg(any(
+                target_os = "android",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "linux",
+                target_os = "netbsd",
+                target_os = "openbsd",
+                target_vendor = "apple",
+            ))] {
+                setsockopt(self, libc::SOL_SOCKET, libc::SO_REUSEPORT, reuse_port as c_int)
+            } else {
+                let _ = reuse_port;
+                Err(io::const_io_error!(
+                    io::ErrorKind::Unsupported,
+                    "SO_REUSEPORT is not supported on this platform",
+                ))
+            }
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux",))]
+    pub fn set_bind_device(&self, interface: &[u8]) -> io::Result<()> {
+        cvt(unsafe {
+            libc::setsockopt(
+                self.as_raw_fd(),
+                libc::SOL_SOCKET,
+                libc::SO_BINDTODEVICE,
+                interface.as_ptr() as *const c_void,
+                interface.len() as socklen_t,
+            )
+        })?;
+        Ok(())
+    }
+
     #[cfg(any(target_os = "android", target_os = "linux",))]
     pub fn set_quickack(&self, quickack: bool) -> io::Result<()> {
         setsockopt(self, libc::IPPROTO_TCP, libc::TCP_QUICKACK, quickack as c_int)
@@ -432,6 +565,61 @@ pub fn passcred(&self) -> io::Result<bool> {
         Ok(passcred != 0)
     }
 
+    #[cfg(any(target_os = "android", target_os = "linux",))]
+    pub fn set_passsec(&self, passsec: bool) -> io::Result<()> {
+        setsockopt(self, libc::SOL_SOCKET, libc::SO_PASSSEC, passsec as libc::c_int)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux",))]
+    pub fn passsec(&self) -> io::Result<bool> {
+        let passsec: libc::c_int = getsockopt(self, libc::SOL_SOCKET, libc::SO_PASSSEC)?;
+        Ok(passsec != 0)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux",))]
+    pub fn set_timestamp(&self, timestamp: bool) -> io::Result<()> {
+        setsockopt(self, libc::SOL_SOCKET, libc::SO_TIMESTAMP, timestamp as libc::c_int)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux",))]
+    pub fn timestamp(&self) -> io::Result<bool> {
+        let timestamp: libc::c_int = getsockopt(self, libc::SOL_SOCKET, libc::SO_TIMESTAMP)?;
+        Ok(timestamp != 0)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux",))]
+    pub fn peer_sec(&self) -> io::Result<Vec<u8>> {
+        let mut label = Vec::with_capacity(256);
+        loop {
+            let mut len = label.capacity() as libc::socklen_t;
+            let ret = unsafe {
+                libc::getsockopt(
+                    self.as_raw_fd(),
+                    libc::SOL_SOCKET,
+                    libc::SO_PEERSEC,
+                    label.as_mut_ptr() as *mut c_void,
+                    &mut len,
+                )
+            };
+            if ret == 0 {
+                unsafe { label.set_len(len as usize) };
+                // Depending on the security module, the label may or may not
+                // include a trailing NUL byte.
+                if label.last() == Some(&0) {
+                    label.pop();
+                }
+                return Ok(label);
+            }
+            let err = io::Error::last_os_error();
+            // The kernel reports the required length when the buffer is too small.
+            if err.raw_os_error() == Some(libc::ERANGE) && len as usize > label.capacity() {
+                label.reserve(len as usize);
+            } else {
+                return Err(err);
+            }
+        }
+    }
+
     #[cfg(target_os = "netbsd")]
     pub fn set_passcred(&self, passcred: bool) -> io::Result<()> {
         setsockopt(self, 0 as libc::c_int, libc::LOCAL_CREDS, passcred as libc::c_int)
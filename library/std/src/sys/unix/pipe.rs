@@ -53,6 +53,10 @@ pub fn read_buf(&self, buf: BorrowedCursor<'_>) -> io::Result<()> {
         self.0.read_buf(buf)
     }
 
+    pub fn read_buf_vectored(&self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        self.0.read_buf_vectored(cursors)
+    }
+
     pub fn read_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.0.read_vectored(bufs)
     }
@@ -0,0 +1,81 @@
+//! Operations on pidfds, the file descriptors referring to a process used by
+//! `std::os::linux::process::PidFd`.
+//!
+//! Unlike a PID, a pidfd keeps referring to the same process after it exits, so none of these
+//! operations can affect an unrelated process which reused the PID.
+
+use crate::io;
+use crate::mem;
+use crate::os::unix::io::{AsRawFd, BorrowedFd};
+use crate::ptr;
+use crate::sys::cvt;
+use crate::sys::process::ExitStatus;
+use crate::time::{Duration, Instant};
+
+pub fn send_signal(pidfd: BorrowedFd<'_>, signal: libc::c_int) -> io::Result<()> {
+    cvt(unsafe {
+        libc::syscall(
+            libc::SYS_pidfd_send_signal,
+            pidfd.as_raw_fd(),
+            signal,
+            ptr::null::<libc::siginfo_t>(),
+            0,
+        )
+    })
+    .map(drop)
+}
+
+/// Reaps the process once it exits. When not `blocking`, returns `None` right away if the
+/// process is still running.
+pub fn wait(pidfd: BorrowedFd<'_>, blocking: bool) -> io::Result<Option<ExitStatus>> {
+    let flags = if blocking { libc::WEXITED } else { libc::WEXITED | libc::WNOHANG };
+    let mut siginfo: libc::siginfo_t = unsafe { mem::zeroed() };
+    loop {
+        let result = cvt(unsafe {
+            libc::waitid(libc::P_PIDFD, pidfd.as_raw_fd() as libc::id_t, &mut siginfo, flags)
+        });
+        match result {
+            Ok(_) => break,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+    }
+    // With `WNOHANG`, `waitid` leaves `siginfo` zeroed if the process is still running.
+    if unsafe { siginfo.si_pid() } == 0 {
+        return Ok(None);
+    }
+    ExitStatus::from_waitid_siginfo(siginfo).map(Some)
+}
+
+/// Whether `wait` failed because the kernel can't wait on pidfds: `waitid` only accepts
+/// `P_PIDFD` since Linux 5.4, while pidfds can be created since Linux 5.3. Seccomp filters
+/// may also reject the call with `ENOSYS`.
+pub fn is_wait_unsupported(err: &io::Error) -> bool {
+    matches!(err.raw_os_error(), Some(libc::EINVAL | libc::ENOSYS))
+}
+
+/// Waits until the process exits, without reaping it, or until `timeout` elapses. Returns
+/// whether the process exited.
+pub fn poll_exit(pidfd: BorrowedFd<'_>, timeout: Option<Duration>) -> io::Result<bool> {
+    // A pidfd becomes readable when its process exits.
+    let mut pollfd = libc::pollfd { fd: pidfd.as_raw_fd(), events: libc::POLLIN, revents: 0 };
+    // A deadline too far away to be represented is as good as no deadline.
+    let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+    loop {
+        let timeout_ms = match deadline {
+            None => -1,
+            Some(deadline) => {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                // Round up, to not return before the deadline.
+                let ms = remaining.as_nanos().div_ceil(1_000_000);
+                ms.try_into().unwrap_or(libc::c_int::MAX)
+            }
+        };
+        match cvt(unsafe { libc::poll(&mut pollfd, 1, timeout_ms) }) {
+            Ok(0) => return Ok(false),
+            Ok(_) => return Ok(true),
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+    }
+}
@@ -107,6 +107,15 @@ pub struct Command {
     #[cfg(target_os = "linux")]
     create_pidfd: bool,
     pgroup: Option<pid_t>,
+    setsid: bool,
+    controlling_terminal: bool,
+    signal_mask: Option<Box<[c_int]>>,
+    default_signals: Vec<c_int>,
+    /// The resource, soft limit and hard limit of each `setrlimit` call.
+    rlimits: Vec<(c_int, u64, u64)>,
+    /// The file descriptors to keep open in addition to the standard streams, sorted. If set, all
+    /// the other file descriptors are closed.
+    close_fds_except: Option<Box<[c_int]>>,
 }
 
 // Create a new type for argv, so that we can make it `Send` and `Sync`
@@ -197,6 +206,12 @@ pub fn new(program: &OsStr) -> Command {
             stdout: None,
             stderr: None,
             pgroup: None,
+            setsid: false,
+            controlling_terminal: false,
+            signal_mask: None,
+            default_signals: Vec::new(),
+            rlimits: Vec::new(),
+            close_fds_except: None,
         }
     }
 
@@ -222,6 +237,12 @@ pub fn new(program: &OsStr) -> Command {
             stderr: None,
             create_pidfd: false,
             pgroup: None,
+            setsid: false,
+            controlling_terminal: false,
+            signal_mask: None,
+            default_signals: Vec::new(),
+            rlimits: Vec::new(),
+            close_fds_except: None,
         }
     }
 
@@ -260,6 +281,27 @@ pub fn groups(&mut self, groups: &[gid_t]) {
     pub fn pgroup(&mut self, pgroup: pid_t) {
         self.pgroup = Some(pgroup);
     }
+    pub fn setsid(&mut self, setsid: bool) {
+        self.setsid = setsid;
+    }
+    pub fn controlling_terminal(&mut self, controlling_terminal: bool) {
+        self.controlling_terminal = controlling_terminal;
+    }
+    pub fn signal_mask(&mut self, signals: &[c_int]) {
+        self.signal_mask = Some(Box::from(signals));
+    }
+    pub fn default_signals(&mut self, signals: &[c_int]) {
+        self.default_signals.extend_from_slice(signals);
+    }
+    pub fn rlimit(&mut self, resource: c_int, soft: u64, hard: u64) {
+        self.rlimits.push((resource, soft, hard));
+    }
+    pub fn close_fds_except(&mut self, keep: &[c_int]) {
+        let mut keep = keep.to_vec();
+        keep.sort_unstable();
+        keep.dedup();
+        self.close_fds_except = Some(keep.into_boxed_slice());
+    }
 
     #[cfg(target_os = "linux")]
     pub fn create_pidfd(&mut self, val: bool) {
@@ -332,6 +374,30 @@ pub fn get_groups(&self) -> Option<&[gid_t]> {
     pub fn get_pgroup(&self) -> Option<pid_t> {
         self.pgroup
     }
+    #[allow(dead_code)]
+    pub fn get_setsid(&self) -> bool {
+        self.setsid
+    }
+    #[allow(dead_code)]
+    pub fn get_controlling_terminal(&self) -> bool {
+        self.controlling_terminal
+    }
+    #[allow(dead_code)]
+    pub fn get_signal_mask(&self) -> Option<&[c_int]> {
+        self.signal_mask.as_deref()
+    }
+    #[allow(dead_code)]
+    pub fn get_default_signals(&self) -> &[c_int] {
+        &self.default_signals
+    }
+    #[allow(dead_code)]
+    pub fn get_rlimits(&self) -> &[(c_int, u64, u64)] {
+        &self.rlimits
+    }
+    #[allow(dead_code)]
+    pub fn get_close_fds_except(&self) -> Option<&[c_int]> {
+        self.close_fds_except.as_deref()
+    }
 
     pub fn get_closures(&mut self) -> &mut Vec<Box<dyn FnMut() -> io::Result<()> + Send + Sync>> {
         &mut self.closures
@@ -547,6 +613,24 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             if self.pgroup.is_some() {
                 debug_command.field("pgroup", &self.pgroup);
             }
+            if self.setsid {
+                debug_command.field("setsid", &self.setsid);
+            }
+            if self.controlling_terminal {
+                debug_command.field("controlling_terminal", &self.controlling_terminal);
+            }
+            if self.signal_mask.is_some() {
+                debug_command.field("signal_mask", &self.signal_mask);
+            }
+            if !self.default_signals.is_empty() {
+                debug_command.field("default_signals", &self.default_signals);
+            }
+            if !self.rlimits.is_empty() {
+                debug_command.field("rlimits", &self.rlimits);
+            }
+            if self.close_fds_except.is_some() {
+                debug_command.field("close_fds_except", &self.close_fds_except);
+            }
 
             #[cfg(target_os = "linux")]
             {
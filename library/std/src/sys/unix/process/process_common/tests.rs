@@ -2,6 +2,7 @@
 
 use crate::ffi::OsStr;
 use crate::mem;
+use crate::os::fd::{FromRawFd, OwnedFd};
 use crate::ptr;
 use crate::sys::{cvt, cvt_nz};
 
@@ -136,6 +137,63 @@ fn test_process_group_no_posix_spawn() {
     }
 }
 
+#[test]
+fn test_setsid() {
+    fn test_inner(mut cmd: Command) {
+        unsafe {
+            cmd.setsid(true);
+            cmd.stdin(Stdio::MakePipe);
+            cmd.stdout(Stdio::MakePipe);
+            let (mut cat, pipes) = t!(cmd.spawn(Stdio::Null, true));
+
+            // The child process leads a new session.
+            let pid = cat.id() as libc::pid_t;
+            assert_eq!(t!(cvt(libc::getsid(pid))), pid);
+
+            drop(pipes);
+            t!(cat.wait());
+        }
+    }
+
+    test_inner(Command::new(OsStr::new("cat")));
+
+    // Specifying `pre_exec` forces the fork/exec path.
+    let mut cmd = Command::new(OsStr::new("cat"));
+    unsafe { cmd.pre_exec(Box::new(|| Ok(()))) };
+    test_inner(cmd);
+}
+
+#[test]
+fn test_setsid_with_process_group() {
+    let mut cmd = Command::new(OsStr::new("true"));
+    cmd.setsid(true);
+    cmd.pgroup(0);
+    let err = cmd.spawn(Stdio::Null, true).err().unwrap();
+    assert_eq!(err.kind(), crate::io::ErrorKind::InvalidInput);
+}
+
+#[test]
+fn test_rlimit_and_close_fds_except() {
+    // An inheritable descriptor above the limit of the child process, which must be closed anyway.
+    let fd = t!(cvt(unsafe { libc::fcntl(libc::STDERR_FILENO, libc::F_DUPFD, 100) }));
+    let _fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+    let mut cmd = Command::new(OsStr::new("sh"));
+    cmd.arg(OsStr::new("-c"));
+    cmd.arg(OsStr::new(&format!(
+        "ulimit -n; if [ -e /dev/fd/{fd} ]; then echo open; else echo closed; fi"
+    )));
+    cmd.rlimit(libc::RLIMIT_NOFILE as libc::c_int, 64, 64);
+    cmd.close_fds_except(&[]);
+    cmd.stdout(Stdio::MakePipe);
+    let (mut sh, mut pipes) = t!(cmd.spawn(Stdio::Null, true));
+
+    let mut output = Vec::new();
+    t!(pipes.stdout.take().unwrap().read_to_end(&mut output));
+    assert_eq!(output, b"64\nclosed\n");
+    assert!(t!(sh.wait()).exit_ok().is_ok());
+}
+
 #[test]
 fn test_program_kind() {
     let vectors = &[
@@ -9,6 +9,8 @@
 
 #[cfg(target_os = "linux")]
 use crate::os::linux::process::PidFd;
+#[cfg(target_os = "linux")]
+use crate::os::unix::io::AsFd;
 
 #[cfg(target_os = "linux")]
 use crate::sys::weak::raw_syscall;
@@ -85,6 +87,12 @@ pub fn spawn(
                 "nul byte found in provided data",
             ));
         }
+        if self.get_setsid() && self.get_pgroup().is_some() {
+            return Err(io::const_io_error!(
+                ErrorKind::InvalidInput,
+                "setsid can't be combined with a process group",
+            ));
+        }
 
         let (ours, theirs) = self.setup_io(default, needs_stdin)?;
 
@@ -320,6 +328,12 @@ pub fn exec(&mut self, default: Stdio) -> io::Error {
         if self.saw_nul() {
             return io::const_io_error!(ErrorKind::InvalidInput, "nul byte found in provided data",);
         }
+        if self.get_setsid() && self.get_pgroup().is_some() {
+            return io::const_io_error!(
+                ErrorKind::InvalidInput,
+                "setsid can't be combined with a process group",
+            );
+        }
 
         match self.setup_io(default, true) {
             Ok((_, theirs)) => {
@@ -373,7 +387,9 @@ unsafe fn do_exec(
         stdio: ChildPipes,
         maybe_envp: Option<&CStringArray>,
     ) -> Result<!, io::Error> {
-        use crate::sys::{self, cvt_r};
+        use crate::mem::MaybeUninit;
+        use crate::ptr;
+        use crate::sys::{self, cvt_nz, cvt_r};
 
         if let Some(fd) = stdio.stdin.fd() {
             cvt_r(|| libc::dup2(fd, libc::STDIN_FILENO))?;
@@ -385,6 +401,17 @@ unsafe fn do_exec(
             cvt_r(|| libc::dup2(fd, libc::STDERR_FILENO))?;
         }
 
+        // Lowering `RLIMIT_NOFILE` below doesn't close the descriptors above the new limit, so find
+        // the ones to close before that.
+        let open_max = if self.get_close_fds_except().is_some() { open_max() } else { 0 };
+
+        // Set the limits before dropping privileges, which may be needed to raise them.
+        for &(resource, soft, hard) in self.get_rlimits() {
+            let rlim = |limit| if limit == u64::MAX { libc::RLIM_INFINITY } else { limit as _ };
+            let limit = libc::rlimit { rlim_cur: rlim(soft), rlim_max: rlim(hard) };
+            cvt(libc::setrlimit(resource as _, &limit))?;
+        }
+
         #[cfg(not(target_os = "l4re"))]
         {
             if let Some(_g) = self.get_groups() {
@@ -414,6 +441,37 @@ unsafe fn do_exec(
             cvt(libc::chdir(cwd.as_ptr()))?;
         }
 
+        if self.get_setsid() {
+            cvt(libc::setsid())?;
+        }
+        if self.get_controlling_terminal() {
+            #[cfg(any(
+                target_os = "android",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "ios",
+                target_os = "linux",
+                target_os = "macos",
+                target_os = "netbsd",
+                target_os = "openbsd",
+            ))]
+            cvt(libc::ioctl(libc::STDIN_FILENO, libc::TIOCSCTTY as _, 0))?;
+            #[cfg(not(any(
+                target_os = "android",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "ios",
+                target_os = "linux",
+                target_os = "macos",
+                target_os = "netbsd",
+                target_os = "openbsd",
+            )))]
+            return Err(io::const_io_error!(
+                ErrorKind::Unsupported,
+                "setting a controlling terminal is not supported on this platform",
+            ));
+        }
+
         if let Some(pgroup) = self.get_pgroup() {
             cvt(libc::setpgid(0, pgroup))?;
         }
@@ -422,7 +480,20 @@ unsafe fn do_exec(
         #[cfg(not(target_os = "emscripten"))]
         {
             // Inherit the signal mask from the parent rather than resetting it (i.e. do not call
-            // pthread_sigmask).
+            // pthread_sigmask), unless another mask was requested.
+            if let Some(signals) = self.get_signal_mask() {
+                let mut set = MaybeUninit::<libc::sigset_t>::uninit();
+                cvt(sigemptyset(set.as_mut_ptr()))?;
+                for &signal in signals {
+                    cvt(sigaddset(set.as_mut_ptr(), signal))?;
+                }
+                cvt_nz(libc::pthread_sigmask(libc::SIG_SETMASK, set.as_ptr(), ptr::null_mut()))?;
+            }
+            for &signal in self.get_default_signals() {
+                let mut action: libc::sigaction = mem::zeroed();
+                action.sa_sigaction = libc::SIG_DFL;
+                cvt(libc::sigaction(signal, &action, ptr::null_mut()))?;
+            }
 
             // If #[unix_sigpipe] is specified, don't reset SIGPIPE to SIG_DFL.
             // If #[unix_sigpipe] is not specified, reset SIGPIPE to SIG_DFL for backward compatibility.
@@ -449,6 +520,10 @@ unsafe fn do_exec(
             callback()?;
         }
 
+        if let Some(keep) = self.get_close_fds_except() {
+            close_fds_on_exec_except(keep, open_max)?;
+        }
+
         // Although we're performing an exec here we may also return with an
         // error from this function (without actually exec'ing) in which case we
         // want to be sure to restore the global environment back to what it
@@ -526,10 +601,25 @@ fn posix_spawn(
             || !self.get_closures().is_empty()
             || self.get_groups().is_some()
             || self.get_create_pidfd()
+            || self.get_controlling_terminal()
+            || !self.get_rlimits().is_empty()
+            || self.get_close_fds_except().is_some()
         {
             return Ok(None);
         }
 
+        // Only glibc 2.26+ supports `POSIX_SPAWN_SETSID`.
+        #[cfg(all(target_os = "linux", target_env = "gnu"))]
+        const POSIX_SPAWN_SETSID: libc::c_int = 0x80;
+        if self.get_setsid() {
+            #[cfg(all(target_os = "linux", target_env = "gnu"))]
+            if sys::os::glibc_version().map_or(true, |version| version < (2, 26)) {
+                return Ok(None);
+            }
+            #[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+            return Ok(None);
+        }
+
         // Only glibc 2.24+ posix_spawn() supports returning ENOENT directly.
         #[cfg(all(target_os = "linux", target_env = "gnu"))]
         {
@@ -677,17 +767,37 @@ fn drop(&mut self) {
                 cvt_nz(libc::posix_spawnattr_setpgroup(attrs.0.as_mut_ptr(), pgroup))?;
             }
 
+            #[cfg(all(target_os = "linux", target_env = "gnu"))]
+            if self.get_setsid() {
+                flags |= POSIX_SPAWN_SETSID;
+            }
+
             // Inherit the signal mask from this process rather than resetting it (i.e. do not call
-            // posix_spawnattr_setsigmask).
+            // posix_spawnattr_setsigmask), unless another mask was requested.
+            if let Some(signals) = self.get_signal_mask() {
+                let mut set = MaybeUninit::<libc::sigset_t>::uninit();
+                cvt(sigemptyset(set.as_mut_ptr()))?;
+                for &signal in signals {
+                    cvt(sigaddset(set.as_mut_ptr(), signal))?;
+                }
+                cvt_nz(libc::posix_spawnattr_setsigmask(attrs.0.as_mut_ptr(), set.as_ptr()))?;
+                flags |= libc::POSIX_SPAWN_SETSIGMASK;
+            }
 
             // If #[unix_sigpipe] is specified, don't reset SIGPIPE to SIG_DFL.
             // If #[unix_sigpipe] is not specified, reset SIGPIPE to SIG_DFL for backward compatibility.
             //
             // #[unix_sigpipe] is an opportunity to change the default here.
-            if !unix_sigpipe_attr_specified() {
+            let reset_sigpipe = !unix_sigpipe_attr_specified();
+            if reset_sigpipe || !self.get_default_signals().is_empty() {
                 let mut default_set = MaybeUninit::<libc::sigset_t>::uninit();
                 cvt(sigemptyset(default_set.as_mut_ptr()))?;
-                cvt(sigaddset(default_set.as_mut_ptr(), libc::SIGPIPE))?;
+                if reset_sigpipe {
+                    cvt(sigaddset(default_set.as_mut_ptr(), libc::SIGPIPE))?;
+                }
+                for &signal in self.get_default_signals() {
+                    cvt(sigaddset(default_set.as_mut_ptr(), signal))?;
+                }
                 cvt_nz(libc::posix_spawnattr_setsigdefault(
                     attrs.0.as_mut_ptr(),
                     default_set.as_ptr(),
@@ -724,6 +834,65 @@ fn drop(&mut self) {
     }
 }
 
+/// Returns a bound for the open file descriptors, which must be computed before `RLIMIT_NOFILE`
+/// is lowered.
+#[cfg(not(any(target_os = "tvos", target_os = "watchos")))]
+unsafe fn open_max() -> c_int {
+    match libc::sysconf(libc::_SC_OPEN_MAX) {
+        n if n > 0 => n.min(c_int::MAX as libc::c_long) as c_int,
+        _ => 1024,
+    }
+}
+
+/// Marks all the file descriptors above the standard streams as close-on-exec, except the ones in
+/// `keep` (sorted), which are made inheritable instead. `open_max` bounds the open descriptors
+/// when they have to be marked one at a time.
+///
+/// The descriptors are only closed by `exec`, which keeps the pipe reporting `exec` failures to
+/// the parent open until then. Runs in the child after `fork`, so it must not allocate.
+#[cfg(not(any(target_os = "tvos", target_os = "watchos")))]
+unsafe fn close_fds_on_exec_except(keep: &[c_int], open_max: c_int) -> io::Result<()> {
+    let mut first = libc::STDERR_FILENO + 1;
+    for &fd in keep.iter().chain(crate::iter::once(&c_int::MAX)) {
+        // The standard streams are always inherited.
+        if fd < first {
+            continue;
+        }
+        if fd > first {
+            set_cloexec_range(first, fd - 1, open_max);
+        }
+        if fd != c_int::MAX {
+            cvt(libc::fcntl(fd, libc::F_SETFD, 0))?;
+        }
+        first = fd.saturating_add(1);
+    }
+    Ok(())
+}
+
+/// Marks the open file descriptors from `first` to `last` (inclusive) as close-on-exec.
+#[cfg(not(any(target_os = "tvos", target_os = "watchos")))]
+unsafe fn set_cloexec_range(first: c_int, last: c_int, open_max: c_int) {
+    #[cfg(target_os = "linux")]
+    {
+        const CLOSE_RANGE_CLOEXEC: libc::c_uint = 1 << 2;
+        let ret = libc::syscall(
+            libc::SYS_close_range,
+            first as libc::c_uint,
+            last as libc::c_uint,
+            CLOSE_RANGE_CLOEXEC,
+        );
+        if ret == 0 {
+            return;
+        }
+        // `close_range` or `CLOSE_RANGE_CLOEXEC` needs Linux 5.11, fall back to one call per
+        // descriptor.
+    }
+    for fd in first..=last.min(open_max - 1) {
+        // Fails with `EBADF` for the descriptors which aren't open.
+        libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Processes
 ////////////////////////////////////////////////////////////////////////////////
@@ -764,10 +933,13 @@ pub fn kill(&mut self) -> io::Result<()> {
         // and used for another process, and we probably shouldn't be killing
         // random processes, so return Ok because the process has exited already.
         if self.status.is_some() {
-            Ok(())
-        } else {
-            cvt(unsafe { libc::kill(self.pid, libc::SIGKILL) }).map(drop)
+            return Ok(());
         }
+        #[cfg(target_os = "linux")]
+        if let Some(pidfd) = &self.pidfd {
+            return sys::process::pidfd::send_signal(pidfd.as_fd(), libc::SIGKILL);
+        }
+        cvt(unsafe { libc::kill(self.pid, libc::SIGKILL) }).map(drop)
     }
 
     pub fn wait(&mut self) -> io::Result<ExitStatus> {
@@ -775,6 +947,20 @@ pub fn wait(&mut self) -> io::Result<ExitStatus> {
         if let Some(status) = self.status {
             return Ok(status);
         }
+        #[cfg(target_os = "linux")]
+        if let Some(pidfd) = &self.pidfd {
+            match sys::process::pidfd::wait(pidfd.as_fd(), true) {
+                Ok(status) => {
+                    let status = status
+                        .expect("waitid() without WNOHANG returned before the process exited");
+                    self.status = Some(status);
+                    return Ok(status);
+                }
+                // Fall back to `waitpid` on kernels which can't wait on pidfds.
+                Err(err) if sys::process::pidfd::is_wait_unsupported(&err) => {}
+                Err(err) => return Err(err),
+            }
+        }
         let mut status = 0 as c_int;
         cvt_r(|| unsafe { libc::waitpid(self.pid, &mut status, 0) })?;
         self.status = Some(ExitStatus::new(status));
@@ -785,6 +971,18 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         if let Some(status) = self.status {
             return Ok(Some(status));
         }
+        #[cfg(target_os = "linux")]
+        if let Some(pidfd) = &self.pidfd {
+            match sys::process::pidfd::wait(pidfd.as_fd(), false) {
+                Ok(status) => {
+                    self.status = status;
+                    return Ok(status);
+                }
+                // Fall back to `waitpid` on kernels which can't wait on pidfds.
+                Err(err) if sys::process::pidfd::is_wait_unsupported(&err) => {}
+                Err(err) => return Err(err),
+            }
+        }
         let mut status = 0 as c_int;
         let pid = cvt(unsafe { libc::waitpid(self.pid, &mut status, libc::WNOHANG) })?;
         if pid == 0 {
@@ -814,6 +1012,23 @@ pub fn new(status: c_int) -> ExitStatus {
         ExitStatus(status)
     }
 
+    /// Converts the `siginfo_t` filled by `waitid` to the wait status `waitpid` would return.
+    #[cfg(target_os = "linux")]
+    pub fn from_waitid_siginfo(siginfo: libc::siginfo_t) -> io::Result<ExitStatus> {
+        let status = unsafe { siginfo.si_status() };
+        match siginfo.si_code {
+            libc::CLD_EXITED => Ok(ExitStatus((status & 0xff) << 8)),
+            libc::CLD_KILLED => Ok(ExitStatus(status)),
+            libc::CLD_DUMPED => Ok(ExitStatus(status | 0x80)),
+            libc::CLD_CONTINUED => Ok(ExitStatus(0xffff)),
+            libc::CLD_STOPPED | libc::CLD_TRAPPED => Ok(ExitStatus(((status & 0xff) << 8) | 0x7f)),
+            _ => Err(io::const_io_error!(
+                io::ErrorKind::InvalidData,
+                "waitid() returned an unknown si_code",
+            )),
+        }
+    }
+
     fn exited(&self) -> bool {
         libc::WIFEXITED(self.0)
     }
@@ -1015,6 +1230,13 @@ fn take_pidfd(&mut self) -> io::Result<PidFd> {
             .take()
             .ok_or_else(|| Error::new(ErrorKind::Uncategorized, "No pidfd was created."))
     }
+
+    fn into_pidfd(mut self) -> Result<PidFd, Self> {
+        match self.handle.pidfd.take() {
+            Some(pidfd) => Ok(pidfd),
+            None => Err(self),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -218,6 +218,10 @@ pub fn read_buf(&self, _cursor: BorrowedCursor<'_>) -> io::Result<()> {
         self.0
     }
 
+    pub fn read_buf_vectored(&self, _cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        self.0
+    }
+
     pub fn write(&self, _buf: &[u8]) -> io::Result<usize> {
         self.0
     }
@@ -246,6 +250,26 @@ pub fn set_permissions(&self, _perm: FilePermissions) -> io::Result<()> {
         self.0
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.0
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.0
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        self.0
+    }
+
     pub fn set_times(&self, _times: FileTimes) -> io::Result<()> {
         self.0
     }
@@ -1,4 +1,6 @@
 use crate::cell::Cell;
+use crate::sync::RwLockPolicy;
+use crate::time::Duration;
 
 pub struct RwLock {
     // This platform has no threads, so we can use a Cell here.
@@ -15,6 +17,12 @@ pub const fn new() -> RwLock {
         RwLock { mode: Cell::new(0) }
     }
 
+    // Without threads, there is never anyone waiting to choose between.
+    #[inline]
+    pub const fn with_policy(_policy: RwLockPolicy) -> RwLock {
+        RwLock::new()
+    }
+
     #[inline]
     pub fn read(&self) {
         let m = self.mode.get();
@@ -62,4 +70,34 @@ pub unsafe fn read_unlock(&self) {
     pub unsafe fn write_unlock(&self) {
         assert_eq!(self.mode.replace(0), -1);
     }
+
+    // There is no other thread that could unlock it while we wait.
+    #[inline]
+    pub fn read_timeout(&self, _timeout: Duration) -> bool {
+        self.try_read()
+    }
+
+    #[inline]
+    pub fn write_timeout(&self, _timeout: Duration) -> bool {
+        self.try_write()
+    }
+
+    // Without other threads, an upgradable read lock might as well be a write lock.
+    #[inline]
+    pub fn upgradable_read(&self) {
+        self.write()
+    }
+
+    #[inline]
+    pub fn try_upgradable_read(&self) -> bool {
+        self.try_write()
+    }
+
+    #[inline]
+    pub unsafe fn upgradable_read_unlock(&self) {
+        unsafe { self.write_unlock() }
+    }
+
+    #[inline]
+    pub unsafe fn upgrade(&self) {}
 }
@@ -43,6 +43,10 @@ pub fn read_buf(&self, _buf: BorrowedCursor<'_>) -> io::Result<()> {
         self.0
     }
 
+    pub fn read_buf_vectored(&self, _cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        self.0
+    }
+
     pub fn read_vectored(&self, _: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.0
     }
@@ -95,6 +99,46 @@ pub fn nodelay(&self) -> io::Result<bool> {
         self.0
     }
 
+    pub fn set_keepalive(&self, _: Option<Duration>) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+        self.0
+    }
+
+    pub fn set_keepalive_interval(&self, _: Duration) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn keepalive_interval(&self) -> io::Result<Duration> {
+        self.0
+    }
+
+    pub fn set_keepalive_retries(&self, _: u32) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn keepalive_retries(&self) -> io::Result<u32> {
+        self.0
+    }
+
+    pub fn set_send_buffer_size(&self, _: usize) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.0
+    }
+
+    pub fn set_recv_buffer_size(&self, _: usize) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.0
+    }
+
     pub fn set_ttl(&self, _: u32) -> io::Result<()> {
         self.0
     }
@@ -168,6 +212,60 @@ fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct TcpBuilder(());
+
+impl TcpBuilder {
+    pub fn new() -> TcpBuilder {
+        TcpBuilder(())
+    }
+
+    pub fn reuse_address(&mut self, _: bool) {}
+
+    pub fn reuse_port(&mut self, _: bool) {}
+
+    pub fn only_v6(&mut self, _: bool) {}
+
+    pub fn send_buffer_size(&mut self, _: usize) {}
+
+    pub fn recv_buffer_size(&mut self, _: usize) {}
+
+    pub fn backlog(&mut self, _: u32) {}
+
+    pub fn bind(&mut self, _: SocketAddr) {}
+
+    pub fn listen(&self, _: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
+        unsupported()
+    }
+
+    pub fn connect(&self, _: io::Result<&SocketAddr>) -> io::Result<TcpStream> {
+        unsupported()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct UdpBuilder(());
+
+impl UdpBuilder {
+    pub fn new() -> UdpBuilder {
+        UdpBuilder(())
+    }
+
+    pub fn reuse_address(&mut self, _: bool) {}
+
+    pub fn reuse_port(&mut self, _: bool) {}
+
+    pub fn only_v6(&mut self, _: bool) {}
+
+    pub fn send_buffer_size(&mut self, _: usize) {}
+
+    pub fn recv_buffer_size(&mut self, _: usize) {}
+
+    pub fn bind(&self, _: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
+        unsupported()
+    }
+}
+
 pub struct UdpSocket(!);
 
 impl UdpSocket {
@@ -223,6 +321,22 @@ pub fn broadcast(&self) -> io::Result<bool> {
         self.0
     }
 
+    pub fn set_send_buffer_size(&self, _: usize) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.0
+    }
+
+    pub fn set_recv_buffer_size(&self, _: usize) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.0
+    }
+
     pub fn set_multicast_loop_v4(&self, _: bool) -> io::Result<()> {
         self.0
     }
@@ -11,6 +11,10 @@ pub fn read_buf(&self, _buf: BorrowedCursor<'_>) -> io::Result<()> {
         self.0
     }
 
+    pub fn read_buf_vectored(&self, _cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        self.0
+    }
+
     pub fn read_vectored(&self, _bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.0
     }
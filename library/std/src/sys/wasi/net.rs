@@ -97,6 +97,10 @@ pub fn read_buf(&self, buf: BorrowedCursor<'_>) -> io::Result<()> {
         self.socket().as_inner().read_buf(buf)
     }
 
+    pub fn read_buf_vectored(&self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        io::default_read_buf_vectored(|cursor| self.read_buf(cursor), cursors)
+    }
+
     pub fn read_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.socket().as_inner().read(bufs)
     }
@@ -155,6 +159,46 @@ pub fn nodelay(&self) -> io::Result<bool> {
         unsupported()
     }
 
+    pub fn set_keepalive(&self, _: Option<Duration>) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+        unsupported()
+    }
+
+    pub fn set_keepalive_interval(&self, _: Duration) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn keepalive_interval(&self) -> io::Result<Duration> {
+        unsupported()
+    }
+
+    pub fn set_keepalive_retries(&self, _: u32) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn keepalive_retries(&self) -> io::Result<u32> {
+        unsupported()
+    }
+
+    pub fn set_send_buffer_size(&self, _: usize) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        unsupported()
+    }
+
+    pub fn set_recv_buffer_size(&self, _: usize) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        unsupported()
+    }
+
     pub fn set_ttl(&self, _: u32) -> io::Result<()> {
         unsupported()
     }
@@ -312,6 +356,60 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct TcpBuilder(());
+
+impl TcpBuilder {
+    pub fn new() -> TcpBuilder {
+        TcpBuilder(())
+    }
+
+    pub fn reuse_address(&mut self, _: bool) {}
+
+    pub fn reuse_port(&mut self, _: bool) {}
+
+    pub fn only_v6(&mut self, _: bool) {}
+
+    pub fn send_buffer_size(&mut self, _: usize) {}
+
+    pub fn recv_buffer_size(&mut self, _: usize) {}
+
+    pub fn backlog(&mut self, _: u32) {}
+
+    pub fn bind(&mut self, _: SocketAddr) {}
+
+    pub fn listen(&self, _: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
+        unsupported()
+    }
+
+    pub fn connect(&self, _: io::Result<&SocketAddr>) -> io::Result<TcpStream> {
+        unsupported()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct UdpBuilder(());
+
+impl UdpBuilder {
+    pub fn new() -> UdpBuilder {
+        UdpBuilder(())
+    }
+
+    pub fn reuse_address(&mut self, _: bool) {}
+
+    pub fn reuse_port(&mut self, _: bool) {}
+
+    pub fn only_v6(&mut self, _: bool) {}
+
+    pub fn send_buffer_size(&mut self, _: usize) {}
+
+    pub fn recv_buffer_size(&mut self, _: usize) {}
+
+    pub fn bind(&self, _: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
+        unsupported()
+    }
+}
+
 pub struct UdpSocket {
     inner: Socket,
 }
@@ -369,6 +467,22 @@ pub fn broadcast(&self) -> io::Result<bool> {
         unsupported()
     }
 
+    pub fn set_send_buffer_size(&self, _: usize) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        unsupported()
+    }
+
+    pub fn set_recv_buffer_size(&self, _: usize) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        unsupported()
+    }
+
     pub fn set_multicast_loop_v4(&self, _: bool) -> io::Result<()> {
         unsupported()
     }
@@ -351,6 +351,7 @@ pub fn WaitOnAddress(
         dwmilliseconds: u32
     ) -> BOOL;
     pub fn WakeByAddressSingle(address: *const ::core::ffi::c_void);
+    pub fn WakeByAddressAll(address: *const ::core::ffi::c_void);
 }
 
 compat_fn_with_fallback! {
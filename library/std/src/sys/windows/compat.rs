@@ -51,7 +51,7 @@
 /// must be preloaded or any functions where lazier loading demonstrates a
 /// negative performance impact in practical situations.
 ///
-/// Currently we only preload `WaitOnAddress` and `WakeByAddressSingle`.
+/// Currently we only preload `WaitOnAddress`, `WakeByAddressSingle` and `WakeByAddressAll`.
 unsafe extern "C" fn init() {
     // In an exe this code is executed before main() so is single threaded.
     // In a DLL the system's loader lock will be held thereby synchronizing
@@ -231,15 +231,18 @@ fn try_load() -> Option<()> {
         const MODULE_NAME: &CStr = ansi_str!("api-ms-win-core-synch-l1-2-0");
         const WAIT_ON_ADDRESS: &CStr = ansi_str!("WaitOnAddress");
         const WAKE_BY_ADDRESS_SINGLE: &CStr = ansi_str!("WakeByAddressSingle");
+        const WAKE_BY_ADDRESS_ALL: &CStr = ansi_str!("WakeByAddressAll");
 
         // Try loading the library and all the required functions.
         // If any step fails, then they all fail.
         let library = unsafe { Module::new(MODULE_NAME) }?;
         let wait_on_address = library.proc_address(WAIT_ON_ADDRESS)?;
         let wake_by_address_single = library.proc_address(WAKE_BY_ADDRESS_SINGLE)?;
+        let wake_by_address_all = library.proc_address(WAKE_BY_ADDRESS_ALL)?;
 
         c::WaitOnAddress::PTR.store(wait_on_address.as_ptr(), Ordering::Relaxed);
         c::WakeByAddressSingle::PTR.store(wake_by_address_single.as_ptr(), Ordering::Relaxed);
+        c::WakeByAddressAll::PTR.store(wake_by_address_all.as_ptr(), Ordering::Relaxed);
         Some(())
     }
 
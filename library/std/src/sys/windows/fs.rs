@@ -441,6 +441,10 @@ pub fn read_buf(&self, cursor: BorrowedCursor<'_>) -> io::Result<()> {
         self.handle.read_buf(cursor)
     }
 
+    pub fn read_buf_vectored(&self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        io::default_read_buf_vectored(|cursor| self.read_buf(cursor), cursors)
+    }
+
     pub fn write(&self, buf: &[u8]) -> io::Result<usize> {
         self.handle.write(buf)
     }
@@ -582,6 +586,62 @@ pub fn set_permissions(&self, perm: FilePermissions) -> io::Result<()> {
         Ok(())
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        self.lock_file(c::LOCKFILE_EXCLUSIVE_LOCK).map(drop)
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.lock_file(0).map(drop)
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.lock_file(c::LOCKFILE_EXCLUSIVE_LOCK | c::LOCKFILE_FAIL_IMMEDIATELY)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.lock_file(c::LOCKFILE_FAIL_IMMEDIATELY)
+    }
+
+    /// Locks the whole file, returning whether the lock was acquired, which is always the case
+    /// without `LOCKFILE_FAIL_IMMEDIATELY`.
+    fn lock_file(&self, flags: c::LOCK_FILE_FLAGS) -> io::Result<bool> {
+        // The offset of the locked range is taken from the `OVERLAPPED`, the whole file is
+        // locked by starting at zero with the maximum length.
+        let mut overlapped: c::OVERLAPPED = unsafe { mem::zeroed() };
+        let mut result = cvt(unsafe {
+            c::LockFileEx(
+                self.handle.as_raw_handle(),
+                flags,
+                0,
+                u32::MAX,
+                u32::MAX,
+                &mut overlapped,
+            )
+        })
+        .map(drop);
+        // A handle opened with `FILE_FLAG_OVERLAPPED` returns before the lock is held and the
+        // kernel keeps a pointer to `overlapped`, so wait for the request to complete before it
+        // goes out of scope. Without an event in `overlapped` the wait is on the handle itself.
+        if matches!(&result, Err(err) if err.raw_os_error() == Some(c::ERROR_IO_PENDING as i32)) {
+            result = self.handle.overlapped_result(&mut overlapped, true).map(drop);
+        }
+        match result {
+            Ok(_) => Ok(true),
+            Err(err)
+                if flags & c::LOCKFILE_FAIL_IMMEDIATELY != 0
+                    && err.raw_os_error() == Some(c::ERROR_LOCK_VIOLATION as i32) =>
+            {
+                Ok(false)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        cvt(unsafe { c::UnlockFile(self.handle.as_raw_handle(), 0, 0, u32::MAX, u32::MAX) })?;
+        Ok(())
+    }
+
     pub fn set_times(&self, times: FileTimes) -> io::Result<()> {
         let is_zero = |t: c::FILETIME| t.dwLowDateTime == 0 && t.dwHighDateTime == 0;
         if times.accessed.map_or(false, is_zero)
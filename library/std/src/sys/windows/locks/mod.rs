@@ -1,6 +1,7 @@
 mod condvar;
 mod mutex;
 mod rwlock;
+mod timed_wait;
 pub use condvar::Condvar;
 pub use mutex::Mutex;
 pub use rwlock::RwLock;
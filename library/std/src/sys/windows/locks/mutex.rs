@@ -14,11 +14,14 @@
 //! 3. While CriticalSection is fair and SRWLock is not, the current Rust policy
 //!    is that there are no guarantees of fairness.
 
+use super::timed_wait::TimedWaiters;
 use crate::cell::UnsafeCell;
 use crate::sys::c;
+use crate::time::Duration;
 
 pub struct Mutex {
     srwlock: UnsafeCell<c::SRWLOCK>,
+    timed_waiters: TimedWaiters,
 }
 
 unsafe impl Send for Mutex {}
@@ -32,7 +35,7 @@ pub unsafe fn raw(m: &Mutex) -> c::PSRWLOCK {
 impl Mutex {
     #[inline]
     pub const fn new() -> Mutex {
-        Mutex { srwlock: UnsafeCell::new(c::SRWLOCK_INIT) }
+        Mutex { srwlock: UnsafeCell::new(c::SRWLOCK_INIT), timed_waiters: TimedWaiters::new() }
     }
 
     #[inline]
@@ -47,8 +50,15 @@ pub fn try_lock(&self) -> bool {
         unsafe { c::TryAcquireSRWLockExclusive(raw(self)) != 0 }
     }
 
+    // A `Condvar` releases the SRW lock while it waits without going through
+    // `unlock`, so timed waiters also check the lock every few milliseconds.
+    pub fn lock_timeout(&self, timeout: Duration) -> bool {
+        self.timed_waiters.lock_timeout(timeout, 10, || self.try_lock())
+    }
+
     #[inline]
     pub unsafe fn unlock(&self) {
         c::ReleaseSRWLockExclusive(raw(self));
+        self.timed_waiters.notify();
     }
 }
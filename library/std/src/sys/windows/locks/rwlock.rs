@@ -1,8 +1,12 @@
+use super::timed_wait::TimedWaiters;
 use crate::cell::UnsafeCell;
+use crate::sync::RwLockPolicy;
 use crate::sys::c;
+use crate::time::Duration;
 
 pub struct RwLock {
     inner: UnsafeCell<c::SRWLOCK>,
+    timed_waiters: TimedWaiters,
 }
 
 unsafe impl Send for RwLock {}
@@ -11,7 +15,13 @@ unsafe impl Sync for RwLock {}
 impl RwLock {
     #[inline]
     pub const fn new() -> RwLock {
-        RwLock { inner: UnsafeCell::new(c::SRWLOCK_INIT) }
+        RwLock { inner: UnsafeCell::new(c::SRWLOCK_INIT), timed_waiters: TimedWaiters::new() }
+    }
+    // SRW locks have no way to choose between readers and writers, so their
+    // own policy is kept.
+    #[inline]
+    pub const fn with_policy(_policy: RwLockPolicy) -> RwLock {
+        RwLock::new()
     }
     #[inline]
     pub fn read(&self) {
@@ -31,10 +41,34 @@ pub fn try_write(&self) -> bool {
     }
     #[inline]
     pub unsafe fn read_unlock(&self) {
-        c::ReleaseSRWLockShared(self.inner.get())
+        c::ReleaseSRWLockShared(self.inner.get());
+        self.timed_waiters.notify();
     }
     #[inline]
     pub unsafe fn write_unlock(&self) {
-        c::ReleaseSRWLockExclusive(self.inner.get())
+        c::ReleaseSRWLockExclusive(self.inner.get());
+        self.timed_waiters.notify();
+    }
+    pub fn read_timeout(&self, timeout: Duration) -> bool {
+        self.timed_waiters.lock_timeout(timeout, c::INFINITE, || self.try_read())
+    }
+    pub fn write_timeout(&self, timeout: Duration) -> bool {
+        self.timed_waiters.lock_timeout(timeout, c::INFINITE, || self.try_write())
+    }
+    // SRW locks cannot atomically turn a shared lock into an exclusive one,
+    // so an upgradable read lock is an exclusive lock that is already upgraded.
+    #[inline]
+    pub fn upgradable_read(&self) {
+        self.write()
     }
+    #[inline]
+    pub fn try_upgradable_read(&self) -> bool {
+        self.try_write()
+    }
+    #[inline]
+    pub unsafe fn upgradable_read_unlock(&self) {
+        self.write_unlock()
+    }
+    #[inline]
+    pub unsafe fn upgrade(&self) {}
 }
@@ -0,0 +1,100 @@
+//! Timed locking for SRW locks.
+//!
+//! SRW locks can only be acquired with an infinite wait, so a thread that
+//! wants to give up after a timeout waits with `WaitOnAddress` on a sequence
+//! number next to the lock instead, which unlocking bumps whenever there are
+//! such threads. Windows 7 has no `WaitOnAddress`, so we poll there.
+
+use crate::sync::atomic::{
+    fence, AtomicU32,
+    Ordering::{Relaxed, SeqCst},
+};
+use crate::sys::c;
+use crate::sys::common::locks;
+use crate::sys::windows::dur2timeout;
+use crate::time::{Duration, Instant};
+
+pub struct TimedWaiters {
+    // Incremented on every unlock while there are threads in `lock_timeout`.
+    seq: AtomicU32,
+    // The number of threads in `lock_timeout`.
+    waiters: AtomicU32,
+}
+
+impl TimedWaiters {
+    #[inline]
+    pub const fn new() -> TimedWaiters {
+        TimedWaiters { seq: AtomicU32::new(0), waiters: AtomicU32::new(0) }
+    }
+
+    /// Calls `try_lock` until it succeeds or `timeout` has elapsed, sleeping
+    /// in between until the next call to `notify`.
+    ///
+    /// No single sleep lasts longer than `max_wait` milliseconds, for locks
+    /// that can also be released without calling `notify`.
+    pub fn lock_timeout(
+        &self,
+        timeout: Duration,
+        max_wait: c::DWORD,
+        try_lock: impl Fn() -> bool,
+    ) -> bool {
+        if try_lock() {
+            return true;
+        }
+
+        let Some(wait_on_address) = c::WaitOnAddress::option() else {
+            return locks::lock_timeout(timeout, try_lock);
+        };
+
+        // Overflows are rounded up to an infinite timeout (None).
+        let deadline = Instant::now().checked_add(timeout);
+        // This pairs with the fence in `notify`: either the unlocking thread
+        // sees us waiting, or our `try_lock` below sees the lock unlocked.
+        self.waiters.fetch_add(1, SeqCst);
+        let locked = loop {
+            // Load the sequence number before trying to lock, so that an
+            // unlock in between makes `WaitOnAddress` return right away.
+            let seq = self.seq.load(SeqCst);
+            if try_lock() {
+                break true;
+            }
+            let wait = match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(remaining) if !remaining.is_zero() => dur2timeout(remaining),
+                    _ => break false,
+                },
+                None => c::INFINITE,
+            };
+            unsafe {
+                wait_on_address(
+                    &self.seq as *const AtomicU32 as c::LPVOID,
+                    &seq as *const u32 as c::LPVOID,
+                    4,
+                    wait.min(max_wait),
+                );
+            }
+        };
+        self.waiters.fetch_sub(1, Relaxed);
+        locked
+    }
+
+    /// Wakes up the threads in `lock_timeout`, if any. This must be called
+    /// after every unlock.
+    #[inline]
+    pub fn notify(&self) {
+        fence(SeqCst);
+        if self.waiters.load(Relaxed) != 0 {
+            self.notify_waiters();
+        }
+    }
+
+    #[cold]
+    fn notify_waiters(&self) {
+        self.seq.fetch_add(1, SeqCst);
+        // `waiters` is only ever nonzero if `WaitOnAddress` and therefore
+        // also `WakeByAddressAll` are available.
+        if let Some(wake_by_address_all) = c::WakeByAddressAll::option() {
+            unsafe { wake_by_address_all(&self.seq as *const AtomicU32 as c::LPVOID) };
+        }
+    }
+}
@@ -29,6 +29,29 @@ pub mod netc {
 
 pub struct Socket(OwnedSocket);
 
+/// Keepalive times are configured in whole seconds and must be positive, so
+/// round up to at least one second.
+fn keepalive_secs(dur: Duration) -> c::DWORD {
+    let secs = dur.as_secs().saturating_add((dur.subsec_nanos() > 0) as u64);
+    secs.clamp(1, c::DWORD::MAX as u64) as c::DWORD
+}
+
+/// The per-socket keepalive options need Windows 10 version 1709 (1703 for
+/// `TCP_KEEPCNT`). Older versions reject them with `WSAENOPROTOOPT`, which is
+/// reported as `Unsupported`.
+fn keepalive_option<T>(res: io::Result<T>) -> io::Result<T> {
+    res.map_err(|err| {
+        if err.raw_os_error() == Some(c::WSAENOPROTOOPT) {
+            io::const_io_error!(
+                io::ErrorKind::Unsupported,
+                "per-socket keepalive options require Windows 10 version 1709 or later",
+            )
+        } else {
+            err
+        }
+    })
+}
+
 static WSA_CLEANUP: OnceLock<unsafe extern "system" fn() -> i32> = OnceLock::new();
 
 /// Checks whether the Windows socket interface has been started already, and
@@ -249,6 +272,10 @@ pub fn read_buf(&self, buf: BorrowedCursor<'_>) -> io::Result<()> {
         self.recv_with_flags(buf, 0)
     }
 
+    pub fn read_buf_vectored(&self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        io::default_read_buf_vectored(|cursor| self.read_buf(cursor), cursors)
+    }
+
     pub fn read_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         // On unix when a socket is shut down all further reads return 0, so we
         // do the same on windows to map a shut down socket to returning EOF.
@@ -427,6 +454,77 @@ pub fn nodelay(&self) -> io::Result<bool> {
         Ok(raw != 0)
     }
 
+    pub fn set_keepalive(&self, keepalive: Option<Duration>) -> io::Result<()> {
+        if let Some(idle) = keepalive {
+            // Where the idle time can't be set per socket, the system-wide one
+            // is used.
+            let res = net::setsockopt(self, c::IPPROTO_TCP, c::TCP_KEEPIDLE, keepalive_secs(idle));
+            match keepalive_option(res) {
+                Err(err) if err.kind() == io::ErrorKind::Unsupported => {}
+                res => res?,
+            }
+        }
+        net::setsockopt(self, c::SOL_SOCKET, c::SO_KEEPALIVE, keepalive.is_some() as c::BOOL)
+    }
+
+    pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+        let raw: c::BOOL = net::getsockopt(self, c::SOL_SOCKET, c::SO_KEEPALIVE)?;
+        if raw == 0 {
+            return Ok(None);
+        }
+        let secs: c::DWORD =
+            keepalive_option(net::getsockopt(self, c::IPPROTO_TCP, c::TCP_KEEPIDLE))?;
+        Ok(Some(Duration::from_secs(secs as u64)))
+    }
+
+    pub fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()> {
+        let secs = keepalive_secs(interval);
+        keepalive_option(net::setsockopt(self, c::IPPROTO_TCP, c::TCP_KEEPINTVL, secs))
+    }
+
+    pub fn keepalive_interval(&self) -> io::Result<Duration> {
+        let secs: c::DWORD =
+            keepalive_option(net::getsockopt(self, c::IPPROTO_TCP, c::TCP_KEEPINTVL))?;
+        Ok(Duration::from_secs(secs as u64))
+    }
+
+    pub fn set_keepalive_retries(&self, retries: u32) -> io::Result<()> {
+        keepalive_option(net::setsockopt(self, c::IPPROTO_TCP, c::TCP_KEEPCNT, retries as c::DWORD))
+    }
+
+    pub fn keepalive_retries(&self) -> io::Result<u32> {
+        let raw: c::DWORD =
+            keepalive_option(net::getsockopt(self, c::IPPROTO_TCP, c::TCP_KEEPCNT))?;
+        Ok(raw as u32)
+    }
+
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        let size = cmp::min(size, c_int::MAX as usize) as c_int;
+        net::setsockopt(self, c::SOL_SOCKET, c::SO_SNDBUF, size)
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        let raw: c_int = net::getsockopt(self, c::SOL_SOCKET, c::SO_SNDBUF)?;
+        Ok(raw as usize)
+    }
+
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        let size = cmp::min(size, c_int::MAX as usize) as c_int;
+        net::setsockopt(self, c::SOL_SOCKET, c::SO_RCVBUF, size)
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        let raw: c_int = net::getsockopt(self, c::SOL_SOCKET, c::SO_RCVBUF)?;
+        Ok(raw as usize)
+    }
+
+    pub fn set_reuse_port(&self, _: bool) -> io::Result<()> {
+        Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "SO_REUSEPORT is not supported on this platform",
+        ))
+    }
+
     pub fn take_error(&self) -> io::Result<Option<io::Error>> {
         let raw: c_int = net::getsockopt(self, c::SOL_SOCKET, c::SO_ERROR)?;
         if raw == 0 { Ok(None) } else { Ok(Some(io::Error::from_raw_os_error(raw as i32))) }
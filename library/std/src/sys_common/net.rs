@@ -276,6 +276,10 @@ pub fn read_buf(&self, buf: BorrowedCursor<'_>) -> io::Result<()> {
         self.inner.read_buf(buf)
     }
 
+    pub fn read_buf_vectored(&self, cursors: &mut [BorrowedCursor<'_>]) -> io::Result<()> {
+        self.inner.read_buf_vectored(cursors)
+    }
+
     pub fn read_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.inner.read_vectored(bufs)
     }
@@ -334,6 +338,46 @@ pub fn nodelay(&self) -> io::Result<bool> {
         self.inner.nodelay()
     }
 
+    pub fn set_keepalive(&self, keepalive: Option<Duration>) -> io::Result<()> {
+        self.inner.set_keepalive(keepalive)
+    }
+
+    pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+        self.inner.keepalive()
+    }
+
+    pub fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()> {
+        self.inner.set_keepalive_interval(interval)
+    }
+
+    pub fn keepalive_interval(&self) -> io::Result<Duration> {
+        self.inner.keepalive_interval()
+    }
+
+    pub fn set_keepalive_retries(&self, retries: u32) -> io::Result<()> {
+        self.inner.set_keepalive_retries(retries)
+    }
+
+    pub fn keepalive_retries(&self) -> io::Result<u32> {
+        self.inner.keepalive_retries()
+    }
+
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.inner.set_send_buffer_size(size)
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.inner.send_buffer_size()
+    }
+
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.inner.set_recv_buffer_size(size)
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.inner.recv_buffer_size()
+    }
+
     pub fn set_ttl(&self, ttl: u32) -> io::Result<()> {
         setsockopt(&self.inner, c::IPPROTO_IP, c::IP_TTL, ttl as c_int)
     }
@@ -392,41 +436,7 @@ pub struct TcpListener {
 
 impl TcpListener {
     pub fn bind(addr: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
-        let addr = addr?;
-
-        init();
-
-        let sock = Socket::new(addr, c::SOCK_STREAM)?;
-
-        // On platforms with Berkeley-derived sockets, this allows to quickly
-        // rebind a socket, without needing to wait for the OS to clean up the
-        // previous one.
-        //
-        // On Windows, this allows rebinding sockets which are actively in use,
-        // which allows “socket hijacking”, so we explicitly don't set it here.
-        // https://docs.microsoft.com/en-us/windows/win32/winsock/using-so-reuseaddr-and-so-exclusiveaddruse
-        #[cfg(not(windows))]
-        setsockopt(&sock, c::SOL_SOCKET, c::SO_REUSEADDR, 1 as c_int)?;
-
-        // Bind our new socket
-        let (addr, len) = addr.into_inner();
-        cvt(unsafe { c::bind(sock.as_raw(), addr.as_ptr(), len as _) })?;
-
-        cfg_if::cfg_if! {
-            if #[cfg(target_os = "horizon")] {
-                // The 3DS doesn't support a big connection backlog. Sometimes
-                // it allows up to about 37, but other times it doesn't even
-                // accept 32. There may be a global limitation causing this.
-                let backlog = 20;
-            } else {
-                // The default for all other platforms
-                let backlog = 128;
-            }
-        }
-
-        // Start listening
-        cvt(unsafe { c::listen(sock.as_raw(), backlog) })?;
-        Ok(TcpListener { inner: sock })
+        TcpBuilder::new().listen(addr)
     }
 
     #[inline]
@@ -500,25 +510,258 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Socket builders
+////////////////////////////////////////////////////////////////////////////////
+
+cfg_if::cfg_if! {
+    if #[cfg(target_os = "horizon")] {
+        // The 3DS doesn't support a big connection backlog. Sometimes
+        // it allows up to about 37, but other times it doesn't even
+        // accept 32. There may be a global limitation causing this.
+        const DEFAULT_BACKLOG: u32 = 20;
+    } else {
+        // The default for all other platforms
+        const DEFAULT_BACKLOG: u32 = 128;
+    }
+}
+
+/// The options shared by `TcpBuilder` and `UdpBuilder`, which only have an
+/// effect if they are set before the socket is bound or connected.
+#[derive(Clone, Default)]
+struct SocketOptions {
+    reuse_address: Option<bool>,
+    reuse_port: bool,
+    only_v6: Option<bool>,
+    send_buffer_size: Option<usize>,
+    recv_buffer_size: Option<usize>,
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    bind_device: Option<Vec<u8>>,
+}
+
+impl SocketOptions {
+    /// Creates a socket of type `ty` for `addr` with these options applied.
+    fn socket(
+        &self,
+        addr: &SocketAddr,
+        ty: c_int,
+        default_reuse_address: bool,
+    ) -> io::Result<Socket> {
+        let sock = Socket::new(addr, ty)?;
+
+        if self.reuse_address.unwrap_or(default_reuse_address) {
+            setsockopt(&sock, c::SOL_SOCKET, c::SO_REUSEADDR, 1 as c_int)?;
+        }
+        if self.reuse_port {
+            sock.set_reuse_port(true)?;
+        }
+        if let (Some(only_v6), SocketAddr::V6(..)) = (self.only_v6, addr) {
+            setsockopt(&sock, c::IPPROTO_IPV6, c::IPV6_V6ONLY, only_v6 as c_int)?;
+        }
+        if let Some(size) = self.send_buffer_size {
+            sock.set_send_buffer_size(size)?;
+        }
+        if let Some(size) = self.recv_buffer_size {
+            sock.set_recv_buffer_size(size)?;
+        }
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        if let Some(interface) = &self.bind_device {
+            sock.set_bind_device(interface)?;
+        }
+
+        Ok(sock)
+    }
+
+    /// Adds the options which were set to `res`.
+    fn debug_fields(&self, res: &mut fmt::DebugStruct<'_, '_>) {
+        if let Some(reuse_address) = self.reuse_address {
+            res.field("reuse_address", &reuse_address);
+        }
+        if self.reuse_port {
+            res.field("reuse_port", &self.reuse_port);
+        }
+        if let Some(only_v6) = self.only_v6 {
+            res.field("only_v6", &only_v6);
+        }
+        if let Some(size) = self.send_buffer_size {
+            res.field("send_buffer_size", &size);
+        }
+        if let Some(size) = self.recv_buffer_size {
+            res.field("recv_buffer_size", &size);
+        }
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        if let Some(interface) = &self.bind_device {
+            res.field("bind_device", &String::from_utf8_lossy(interface));
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct TcpBuilder {
+    options: SocketOptions,
+    backlog: u32,
+    local_addr: Option<SocketAddr>,
+}
+
+impl TcpBuilder {
+    pub fn new() -> TcpBuilder {
+        TcpBuilder { options: SocketOptions::default(), backlog: DEFAULT_BACKLOG, local_addr: None }
+    }
+
+    pub fn reuse_address(&mut self, reuse_address: bool) {
+        self.options.reuse_address = Some(reuse_address);
+    }
+
+    pub fn reuse_port(&mut self, reuse_port: bool) {
+        self.options.reuse_port = reuse_port;
+    }
+
+    pub fn only_v6(&mut self, only_v6: bool) {
+        self.options.only_v6 = Some(only_v6);
+    }
+
+    pub fn send_buffer_size(&mut self, size: usize) {
+        self.options.send_buffer_size = Some(size);
+    }
+
+    pub fn recv_buffer_size(&mut self, size: usize) {
+        self.options.recv_buffer_size = Some(size);
+    }
+
+    pub fn backlog(&mut self, backlog: u32) {
+        self.backlog = backlog;
+    }
+
+    pub fn bind(&mut self, addr: SocketAddr) {
+        self.local_addr = Some(addr);
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    pub fn bind_device(&mut self, interface: &[u8]) {
+        self.options.bind_device = Some(interface.to_vec());
+    }
+
+    pub fn listen(&self, addr: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
+        let addr = addr?;
+
+        init();
+
+        // On platforms with Berkeley-derived sockets, this allows to quickly
+        // rebind a socket, without needing to wait for the OS to clean up the
+        // previous one.
+        //
+        // On Windows, this allows rebinding sockets which are actively in use,
+        // which allows “socket hijacking”, so we don't set it here unless
+        // explicitly asked to.
+        // https://docs.microsoft.com/en-us/windows/win32/winsock/using-so-reuseaddr-and-so-exclusiveaddruse
+        let sock = self.options.socket(addr, c::SOCK_STREAM, cfg!(not(windows)))?;
+
+        // Bind our new socket
+        let (addr, len) = addr.into_inner();
+        cvt(unsafe { c::bind(sock.as_raw(), addr.as_ptr(), len as _) })?;
+
+        // Start listening
+        let backlog = cmp::min(self.backlog, c_int::MAX as u32) as c_int;
+        cvt(unsafe { c::listen(sock.as_raw(), backlog) })?;
+        Ok(TcpListener { inner: sock })
+    }
+
+    pub fn connect(&self, addr: io::Result<&SocketAddr>) -> io::Result<TcpStream> {
+        let addr = addr?;
+
+        init();
+
+        let sock = self.options.socket(addr, c::SOCK_STREAM, false)?;
+
+        if let Some(local_addr) = &self.local_addr {
+            let (local_addr, len) = local_addr.into_inner();
+            cvt(unsafe { c::bind(sock.as_raw(), local_addr.as_ptr(), len as _) })?;
+        }
+
+        let (addr, len) = addr.into_inner();
+        cvt_r(|| unsafe { c::connect(sock.as_raw(), addr.as_ptr(), len) })?;
+        Ok(TcpStream { inner: sock })
+    }
+}
+
+impl fmt::Debug for TcpBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut res = f.debug_struct("TcpBuilder");
+        self.options.debug_fields(&mut res);
+        if let Some(addr) = &self.local_addr {
+            res.field("local_addr", addr);
+        }
+        res.field("backlog", &self.backlog).finish()
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // UDP
 ////////////////////////////////////////////////////////////////////////////////
 
-pub struct UdpSocket {
-    inner: Socket,
+#[derive(Clone)]
+pub struct UdpBuilder {
+    options: SocketOptions,
 }
 
-impl UdpSocket {
-    pub fn bind(addr: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
+impl UdpBuilder {
+    pub fn new() -> UdpBuilder {
+        UdpBuilder { options: SocketOptions::default() }
+    }
+
+    pub fn reuse_address(&mut self, reuse_address: bool) {
+        self.options.reuse_address = Some(reuse_address);
+    }
+
+    pub fn reuse_port(&mut self, reuse_port: bool) {
+        self.options.reuse_port = reuse_port;
+    }
+
+    pub fn only_v6(&mut self, only_v6: bool) {
+        self.options.only_v6 = Some(only_v6);
+    }
+
+    pub fn send_buffer_size(&mut self, size: usize) {
+        self.options.send_buffer_size = Some(size);
+    }
+
+    pub fn recv_buffer_size(&mut self, size: usize) {
+        self.options.recv_buffer_size = Some(size);
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    pub fn bind_device(&mut self, interface: &[u8]) {
+        self.options.bind_device = Some(interface.to_vec());
+    }
+
+    pub fn bind(&self, addr: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
         let addr = addr?;
 
         init();
 
-        let sock = Socket::new(addr, c::SOCK_DGRAM)?;
+        let sock = self.options.socket(addr, c::SOCK_DGRAM, false)?;
         let (addr, len) = addr.into_inner();
         cvt(unsafe { c::bind(sock.as_raw(), addr.as_ptr(), len as _) })?;
         Ok(UdpSocket { inner: sock })
     }
+}
+
+impl fmt::Debug for UdpBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut res = f.debug_struct("UdpBuilder");
+        self.options.debug_fields(&mut res);
+        res.finish()
+    }
+}
+
+pub struct UdpSocket {
+    inner: Socket,
+}
+
+impl UdpSocket {
+    pub fn bind(addr: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
+        UdpBuilder::new().bind(addr)
+    }
 
     #[inline]
     pub fn socket(&self) -> &Socket {
@@ -590,6 +833,22 @@ pub fn broadcast(&self) -> io::Result<bool> {
         Ok(raw != 0)
     }
 
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.inner.set_send_buffer_size(size)
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.inner.send_buffer_size()
+    }
+
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.inner.set_recv_buffer_size(size)
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.inner.recv_buffer_size()
+    }
+
     pub fn set_multicast_loop_v4(&self, multicast_loop_v4: bool) -> io::Result<()> {
         setsockopt(
             &self.inner,
@@ -922,6 +922,13 @@ pub fn rustc_cargo_env(
         cargo.env("CFG_DEFAULT_LINKER", s);
     }
 
+    // Make the self-contained `rust-lld` the default linker on targets supporting it, when we ship
+    // it. This is limited to nightly and dev builds for now, stable and beta keep using the system
+    // linker.
+    if builder.config.lld_enabled && builder.unstable_features() {
+        cargo.env("CFG_USE_SELF_CONTAINED_LINKER", "1");
+    }
+
     if builder.config.rustc_parallel {
         // keep in sync with `bootstrap/lib.rs:Build::rustc_features`
         // `cfg` option for rustc, `features` option for cargo, for conditional compilation
@@ -0,0 +1,19 @@
+// compile-flags: -C no-prepopulate-passes
+// only-x86_64
+
+#![crate_type = "lib"]
+#![feature(target_clones)]
+
+// Checks that each version of a `#[target_clones]` function is emitted with its target features.
+
+#[target_clones("avx2,fma", "sse4.1", "default")]
+pub fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+// CHECK-DAG: define {{.*}}3dot12dot_avx2_fma{{.*}} #[[AVX2_FMA:[0-9]+]]
+// CHECK-DAG: define {{.*}}3dot10dot_sse4_1{{.*}} #[[SSE41:[0-9]+]]
+// CHECK-DAG: define {{.*}}3dot11dot_default
+
+// CHECK-DAG: attributes #[[AVX2_FMA]] = {{.*}}"target-features"="{{.*}}+avx2,{{.*}}+fma
+// CHECK-DAG: attributes #[[SSE41]] = {{.*}}"target-features"="{{.*}}+sse4.1
@@ -0,0 +1,11 @@
+use std::ops::Add;
+
+#[inline(never)]
+fn generic_sum<T: Copy + Add<Output = T>>(values: &[T], zero: T) -> T {
+    values.iter().fold(zero, |sum, &value| sum + value)
+}
+
+fn main() {
+    println!("{}", generic_sum(&[1u32, 2, 3], 0));
+    println!("{}", generic_sum(&[1u64, 2, 3], 0));
+}
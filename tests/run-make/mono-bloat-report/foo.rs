@@ -0,0 +1,11 @@
+pub fn entry() -> u32 {
+    wrapper::<u8>() as u32 + wrapper::<u16>() as u32
+}
+
+fn wrapper<T: Default>() -> T {
+    generic::<T>()
+}
+
+fn generic<T: Default>() -> T {
+    T::default()
+}
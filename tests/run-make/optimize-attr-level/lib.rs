@@ -0,0 +1,74 @@
+#![feature(optimize_attribute)]
+#![crate_type = "rlib"]
+
+// The loops below call `Iterator::next` and the `#[inline]` functions `wrapping_add` and `triple`,
+// which are only inlined by an optimized pipeline.
+
+// NO-OPT-LABEL: define{{.*}}i32 @not_optimized
+// NO-OPT: call {{.*}}next
+// NO-OPT: call {{.*}}wrapping_add
+// NO-OPT: call {{.*}}6triple
+// OPT-LABEL: define{{.*}}i32 @not_optimized
+// OPT-NOT: call
+// OPT: ret i32
+#[no_mangle]
+pub fn not_optimized(n: u32) -> u32 {
+    let mut sum = 0u32;
+    for i in 0..n {
+        sum = sum.wrapping_add(i);
+    }
+    triple(sum)
+}
+
+// The copy of `triple` used by `not_optimized` isn't optimized at `-Copt-level=0`, even though
+// `level_3` inlines its own copy.
+// NO-OPT-INLINE-LABEL: define{{.*}}6triple
+// NO-OPT-INLINE: call {{.*}}wrapping_mul
+#[inline]
+pub fn triple(x: u32) -> u32 {
+    x.wrapping_mul(3)
+}
+
+// LEVEL-3-LABEL: define{{.*}}i32 @level_3
+// LEVEL-3-NOT: call
+// LEVEL-3: ret i32
+// OPT-LABEL: define{{.*}}i32 @level_3
+// OPT-NOT: call
+// OPT: ret i32
+#[optimize(level = "3")]
+#[no_mangle]
+pub fn level_3(n: u32) -> u32 {
+    let mut sum = 0u32;
+    for i in 0..n {
+        sum = sum.wrapping_add(i);
+    }
+    triple(sum)
+}
+
+// LEVEL-0-LABEL: define{{.*}}i32 @level_0
+// LEVEL-0-SAME: [[LEVEL_0_ATTRS:#[0-9]+]]
+// LEVEL-0: call {{.*}}next
+// LEVEL-0: attributes [[LEVEL_0_ATTRS]] = {{.*}}noinline{{.*}}optnone
+#[optimize(level = "0")]
+#[no_mangle]
+pub fn level_0(n: u32) -> u32 {
+    let mut sum = 0u32;
+    for i in 0..n {
+        sum = sum.wrapping_add(i);
+    }
+    sum
+}
+
+// LEVEL-Z-LABEL: define{{.*}}i32 @level_z
+// LEVEL-Z-SAME: [[LEVEL_Z_ATTRS:#[0-9]+]]
+// LEVEL-Z-NOT: call {{.*}}next
+// LEVEL-Z: attributes [[LEVEL_Z_ATTRS]] = {{.*}}minsize{{.*}}optsize
+#[optimize(level = "z")]
+#[no_mangle]
+pub fn level_z(n: u32) -> u32 {
+    let mut sum = 0u32;
+    for i in 0..n {
+        sum = sum.wrapping_add(i);
+    }
+    sum
+}
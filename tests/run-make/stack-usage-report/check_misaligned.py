@@ -0,0 +1,15 @@
+import json
+import sys
+
+with open(sys.argv[1]) as f:
+    report = json.load(f)
+
+functions = {}
+for function in report["functions"]:
+    functions[function["demangled"]] = function
+
+deref = functions["misaligned::stack_usage_report_deref"]
+callees = [reason["function"] for reason in deref["unbounded_reasons"]]
+# The alignment check calls the same panic function as the one codegen uses.
+assert "core::panicking::panic_misaligned_pointer_dereference" in callees, deref
+assert "core::panicking::panic" not in callees, deref
@@ -0,0 +1,44 @@
+import json
+import sys
+
+with open(sys.argv[1]) as f:
+    report = json.load(f)
+
+functions = {}
+for function in report["functions"]:
+    functions[function["demangled"]] = function
+
+
+def get(name):
+    return functions["main::" + name]
+
+
+leaf = get("stack_usage_report_leaf")
+# The leaf copies a 512 byte array on its stack.
+assert leaf["frame"] >= 512, leaf
+assert leaf["stack"] >= leaf["frame"], leaf
+
+# The inlined function isn't in the binary, but the leaf it calls is still accounted for.
+assert "main::stack_usage_report_inlined" not in functions
+caller = get("stack_usage_report_caller")
+assert caller["stack"] >= caller["frame"] + leaf["frame"], (caller, leaf)
+assert caller["deepest_path"][:3] == [
+    "main::stack_usage_report_caller",
+    "main::stack_usage_report_inlined",
+    "main::stack_usage_report_leaf",
+], caller
+assert caller["bounded"] == leaf["bounded"], (caller, leaf)
+assert not leaf["entry_point"], leaf
+
+recursive = get("stack_usage_report_recursive")
+assert not recursive["bounded"], recursive
+assert {"kind": "recursion", "function": "main::stack_usage_report_recursive"} in recursive[
+    "unbounded_reasons"
+], recursive
+assert recursive["stack"] >= recursive["frame"] + leaf["frame"], (recursive, leaf)
+
+indirect = get("stack_usage_report_indirect")
+assert not indirect["bounded"], indirect
+assert {"kind": "indirect_call", "function": "main::stack_usage_report_indirect"} in indirect[
+    "unbounded_reasons"
+], indirect
@@ -0,0 +1,35 @@
+use std::hint::black_box;
+
+#[inline(never)]
+fn stack_usage_report_leaf(x: u64) -> u64 {
+    let buf = black_box([x; 64]);
+    buf.iter().sum()
+}
+
+// Inlined into its caller, so its call to the leaf is made from the caller's frame.
+#[inline(always)]
+fn stack_usage_report_inlined(x: u64) -> u64 {
+    stack_usage_report_leaf(black_box(x)) + 1
+}
+
+#[inline(never)]
+fn stack_usage_report_caller(x: u64) -> u64 {
+    stack_usage_report_inlined(black_box(x)) * 2
+}
+
+#[inline(never)]
+fn stack_usage_report_recursive(n: u64) -> u64 {
+    if n == 0 { stack_usage_report_leaf(n) } else { stack_usage_report_recursive(black_box(n - 1)) }
+}
+
+#[inline(never)]
+fn stack_usage_report_indirect(f: fn(u64) -> u64) -> u64 {
+    f(black_box(3))
+}
+
+fn main() {
+    black_box(stack_usage_report_leaf(black_box(1)));
+    black_box(stack_usage_report_caller(black_box(2)));
+    black_box(stack_usage_report_recursive(black_box(10)));
+    black_box(stack_usage_report_indirect(black_box(stack_usage_report_leaf)));
+}
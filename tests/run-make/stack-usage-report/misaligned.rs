@@ -0,0 +1,13 @@
+use std::hint::black_box;
+
+// With debug assertions, the dereference is checked for alignment and can call the dedicated
+// panic function.
+#[inline(never)]
+fn stack_usage_report_deref(ptr: *const u64) -> u64 {
+    unsafe { *ptr }
+}
+
+fn main() {
+    let x = 1u64;
+    black_box(stack_usage_report_deref(black_box(&x)));
+}
@@ -0,0 +1,3 @@
+int foo_present(void) {
+    return 1;
+}
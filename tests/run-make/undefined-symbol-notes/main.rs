@@ -0,0 +1,17 @@
+#[link(name = "foo", kind = "static")]
+extern "C" {
+    fn foo_present() -> i32;
+    fn foo_missing() -> i32;
+}
+
+extern "C" {
+    fn bar_missing() -> i32;
+}
+
+fn main() {
+    unsafe {
+        foo_present();
+        foo_missing();
+        bar_missing();
+    }
+}
@@ -0,0 +1,14 @@
+#![feature(optimize_attribute)]
+#![crate_type = "rlib"]
+
+#[optimize(level = "2")]
+fn valid() {}
+
+#[optimize(level = "4")] //~ ERROR invalid optimization level
+fn out_of_range() {}
+
+#[optimize(level = 3)] //~ ERROR invalid optimization level
+fn not_a_string() {}
+
+#[optimize(level)] //~ ERROR invalid optimization level
+fn missing_value() {}
@@ -8,7 +8,9 @@
 
 use std::io::Error;
 use std::os::linux::process::{ChildExt, CommandExt};
+use std::os::unix::process::ExitStatusExt;
 use std::process::Command;
+use std::time::Duration;
 
 fn has_clone3() -> bool {
     let res = unsafe { libc::syscall(libc::SYS_clone3, 0, 0) };
@@ -53,4 +55,40 @@ fn main() {
         .spawn()
         .unwrap()
         .pidfd().expect_err("pidfd should not have been created");
+
+    // The process can be waited for through its pidfd.
+    let pidfd = Command::new("true")
+        .create_pidfd(true)
+        .spawn()
+        .unwrap()
+        .into_pidfd()
+        .expect("failed to obtain pidfd");
+    assert!(pidfd.poll_exit(None).unwrap());
+    assert!(pidfd.wait().unwrap().success());
+    // The process was reaped.
+    assert!(pidfd.try_wait().is_err());
+
+    // The process can be killed through its pidfd.
+    let pidfd = Command::new("sleep")
+        .arg("1000")
+        .create_pidfd(true)
+        .spawn()
+        .unwrap()
+        .into_pidfd()
+        .expect("failed to obtain pidfd");
+    assert!(!pidfd.poll_exit(Some(Duration::ZERO)).unwrap());
+    assert_eq!(pidfd.try_wait().unwrap(), None);
+    pidfd.kill().unwrap();
+    assert_eq!(pidfd.wait().unwrap().signal(), Some(libc::SIGKILL));
+
+    // `Child` uses its pidfd to kill and wait for the process.
+    let mut child = Command::new("sleep").arg("1000").create_pidfd(true).spawn().unwrap();
+    assert_eq!(child.try_wait().unwrap(), None);
+    child.kill().unwrap();
+    assert_eq!(child.wait().unwrap().signal(), Some(libc::SIGKILL));
+
+    // Without a pidfd, the child is given back.
+    let child = Command::new("echo").spawn().unwrap();
+    let mut child = child.into_pidfd().expect_err("pidfd should not have been created");
+    child.wait().unwrap();
 }
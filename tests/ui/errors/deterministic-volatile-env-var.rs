@@ -0,0 +1,15 @@
+// compile-flags: -Zdeterministic --remap-path-prefix={{src-base}}=remapped
+// no-remap-src-base: Manually remap, so the remapped path remains in .stderr file.
+// rustc-env:HOME=/home/user
+
+// The remapped paths are not normalized by compiletest.
+// normalize-stderr-test: "\\(errors)" -> "/$1"
+
+// Checks that `-Zdeterministic` rejects embedding environment variables which differ between
+// machines, whether they are set or not, but accepts the other ones.
+
+fn main() {
+    let _ = env!("HOME");
+    let _ = option_env!("HOSTNAME");
+    let _ = option_env!("SOURCE_DATE_EPOCH");
+}
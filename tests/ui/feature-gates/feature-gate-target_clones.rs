@@ -0,0 +1,8 @@
+// only-x86_64
+
+#[target_clones("avx2", "default")] //~ ERROR use of unstable library feature 'target_clones'
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn main() {}
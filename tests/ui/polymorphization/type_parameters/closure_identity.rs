@@ -0,0 +1,49 @@
+// run-pass
+// revisions: legacy v0
+// compile-flags:-Zpolymorphize=on -Cdebuginfo=2
+//[legacy]compile-flags: -Zunstable-options -Csymbol-mangling-version=legacy
+//[v0]compile-flags: -Csymbol-mangling-version=v0
+
+// This test checks that closures are not polymorphized over unused generic parameters of their
+// parent if they are passed to a function or unsized to a trait object which could tell their
+// instantiations apart, and that the remaining instances get distinct symbols and debuginfo.
+
+use std::any::{Any, TypeId};
+
+fn type_id_of_val<T: Any>(_: &T) -> TypeId {
+    TypeId::of::<T>()
+}
+
+fn closure_type_id<T: 'static>() -> TypeId {
+    let closure = || {};
+    closure();
+    type_id_of_val(&closure)
+}
+
+fn nested_closure_type_id<T: 'static>() -> TypeId {
+    let outer = || {
+        let inner = |x: u32| x + 1;
+        type_id_of_val(&inner)
+    };
+    outer()
+}
+
+fn dyn_closure_type_id<T: 'static>() -> TypeId {
+    let closure = || {};
+    let any: &dyn Any = &closure;
+    any.type_id()
+}
+
+fn boxed_closure_type_id<T: 'static>() -> TypeId {
+    let closure: Box<dyn Any> = Box::new(|| {});
+    (*closure).type_id()
+}
+
+fn main() {
+    assert_eq!(closure_type_id::<u8>(), closure_type_id::<u8>());
+    assert_ne!(closure_type_id::<u8>(), closure_type_id::<u16>());
+    assert_ne!(nested_closure_type_id::<u8>(), nested_closure_type_id::<u16>());
+    assert_eq!(dyn_closure_type_id::<u8>(), dyn_closure_type_id::<u8>());
+    assert_ne!(dyn_closure_type_id::<u8>(), dyn_closure_type_id::<u16>());
+    assert_ne!(boxed_closure_type_id::<u8>(), boxed_closure_type_id::<u16>());
+}
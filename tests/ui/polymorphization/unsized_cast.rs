@@ -10,7 +10,7 @@ fn foo<T: Default>() {
     let _: T = Default::default();
     (|| Box::new(|| {}) as Box<dyn Fn()>)();
     //~^ ERROR item has unused generic parameters
-    //~^^ ERROR item has unused generic parameters
+    // The outer closure passes the inner one to `Box::new`, so it uses `T`.
 }
 
 #[rustc_polymorphize_error]
@@ -0,0 +1,54 @@
+// only-x86_64
+// edition:2021
+
+#![feature(target_clones)]
+
+#[target_clones("avx2")]
+//~^ ERROR `#[target_clones]` requires exactly one `"default"` version
+fn no_default() {}
+
+#[target_clones("avx2", "default", "default")]
+//~^ ERROR `#[target_clones]` requires exactly one `"default"` version
+fn two_defaults() {}
+
+#[target_clones("avx2", 2, "default")]
+//~^ ERROR expected a string literal of comma-separated target features, or `"default"`
+fn not_a_string() {}
+
+#[target_clones("avx2,", "default")]
+//~^ ERROR expected a string literal of comma-separated target features, or `"default"`
+fn empty_feature() {}
+
+#[target_clones("avx2", "default")]
+fn generic<T>(_: T) {}
+//~^ ERROR generic functions cannot have multiple versions
+
+#[target_clones("avx2", "default")]
+fn bounded_lifetime<'a, 'b: 'a>(a: &'a u8, _: &'b u8) -> &'a u8 { a }
+//~^ ERROR generic functions cannot have multiple versions
+
+#[target_clones("avx2", "default")]
+fn output_lifetime<'a>() -> &'a u8 { &0 }
+//~^ ERROR generic functions cannot have multiple versions
+
+#[target_clones("avx2", "default")]
+fn impl_trait_argument(_: impl Copy) {}
+//~^ ERROR impl Trait functions cannot have multiple versions
+
+#[target_clones("avx2", "default")]
+fn impl_trait_return() -> Option<impl Copy> { Some(0) }
+//~^ ERROR impl Trait functions cannot have multiple versions
+
+#[target_clones("avx2", "default")]
+async fn asynchronous() {}
+//~^ ERROR async functions cannot have multiple versions
+
+#[target_clones("avx2", "default")]
+const fn constant() {}
+//~^ ERROR const functions cannot have multiple versions
+
+#[target_clones("avx2", "default")]
+struct NotAFunction;
+//~^ ERROR `#[target_clones]` can only be applied to free functions
+
+fn main() {}
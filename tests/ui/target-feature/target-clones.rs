@@ -0,0 +1,34 @@
+// run-pass
+// only-x86_64
+
+#![feature(target_clones)]
+#![deny(unsafe_code)]
+
+#[target_clones("avx2,fma", "sse4.1", "default")]
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+#[target_clones("avx2", "default")]
+fn first(values: &[u32]) -> Option<&u32> {
+    values.first()
+}
+
+#[target_clones("default", "popcnt")]
+fn count_ones((a, b): (u64, u64)) -> u32 {
+    a.count_ones() + b.count_ones()
+}
+
+#[target_clones("avx2", "default")]
+fn longest<'a>(a: &'a str, b: &'a str) -> &'a str {
+    if a.len() >= b.len() { a } else { b }
+}
+
+fn main() {
+    assert_eq!(dot(&[1.0, 2.0], &[3.0, 4.0]), 11.0);
+    assert_eq!(first(&[3, 4]), Some(&3));
+    assert_eq!(count_ones((0b1011, 1)), 4);
+    // The version selected by the first call is reused.
+    assert_eq!(count_ones((u64::MAX, 0)), 64);
+    assert_eq!(longest("ab", "abc"), "abc");
+}
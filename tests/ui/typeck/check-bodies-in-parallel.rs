@@ -0,0 +1,67 @@
+// Type checks and borrow checks many bodies, and checks the coherence of
+// several traits, on multiple threads. The trait imports are only used by
+// method calls inside the bodies, so `unused_imports` relies on the results of
+// type checking done in parallel.
+//
+// check-pass
+// compile-flags: -Z threads=8
+
+#![deny(unused_imports)]
+
+mod traits {
+    pub trait Double {
+        fn double(&self) -> Self;
+    }
+
+    pub trait Describe {
+        fn describe(&self) -> String;
+    }
+
+    impl Double for u32 {
+        fn double(&self) -> u32 {
+            self * 2
+        }
+    }
+
+    impl Double for String {
+        fn double(&self) -> String {
+            self.repeat(2)
+        }
+    }
+
+    impl<T: std::fmt::Debug> Describe for Vec<T> {
+        fn describe(&self) -> String {
+            format!("{self:?}")
+        }
+    }
+}
+
+macro_rules! modules {
+    ($($name:ident)*) => {
+        $(
+            mod $name {
+                use crate::traits::{Describe, Double};
+
+                pub fn run(x: u32) -> String {
+                    let doubled = x.double();
+                    let mut words = vec![doubled.to_string()];
+                    words.push(String::from(stringify!($name)).double());
+                    let closure = |v: &Vec<String>| v.describe();
+                    closure(&words)
+                }
+
+                pub const LEN: usize = stringify!($name).len();
+            }
+        )*
+
+        fn run_all() -> usize {
+            0 $(+ $name::run($name::LEN as u32).len())*
+        }
+    };
+}
+
+modules!(a b c d e f g h i j k l m n o p q r s t u v w x y z);
+
+fn main() {
+    assert!(run_all() > 0);
+}